@@ -1,4 +1,6 @@
 mod definition;
+mod engine;
 mod instance;
 
-pub use definition::NTTDomain;
+pub use definition::{ConfigNTTDomain, NTTDomain};
+pub use engine::NTTTable;
@@ -1,16 +1,45 @@
-use crate::{Field, Polynomial, Vector};
+use crate::{ConfigZZVec, ConfigZZpX, NTTField, NTTTable, Polynomial, Vector, ZZp};
+
+/// Trait definition of NTT domain configurations.
+///
+/// Declaring a config is all it takes to get an [`NTTDomain`] for
+/// `ZZVec<Self>`; the twiddle tables are derived from the root of unity.
+pub trait ConfigNTTDomain: ConfigZZVec {
+    /// Config for the polynomials in the coefficient domain.
+    /// `PolyConfig::DIM` must be a power of two and equal to `MAX_DIM`.
+    type PolyConfig: ConfigZZpX<BaseConfig = Self::BaseConfig>;
+
+    /// A primitive `2*DIM`-th root of unity.
+    /// Defaults to the one derived from the generator of the base field.
+    fn root_of_unity() -> ZZp<Self::BaseConfig> {
+        let dim = <Self::PolyConfig as ConfigZZpX>::DIM as u64;
+        ZZp::get_primitive_2nth_root_of_unity(dim)
+            .unwrap_or_else(|| panic!("no primitive {}-th root of unity", 2 * dim))
+    }
+
+    /// The `DIM`-point NTT table of the domain.
+    ///
+    /// The default looks the table up in a global cache keyed by the config, which
+    /// takes a lock and recomputes the root of unity on every call. Instances should
+    /// override it with a `static` [`OnceLock`](std::sync::OnceLock) of their own.
+    fn ntt_table() -> &'static NTTTable<Self::BaseConfig> {
+        let dim = <Self::PolyConfig as ConfigZZpX>::DIM;
+        debug_assert_eq!(dim, Self::MAX_DIM);
+        NTTTable::get(dim, Self::root_of_unity())
+    }
+}
 
 /// NTT domain
 pub trait NTTDomain<ConfigPoly, ConfigVec>: Vector<ConfigVec> {
-    /// 1/DIM mod q
-    const ONE_OVER_N: <Self::BaseField as Field>::PrimitiveType;
-
     /// Polynomial
     type Polynomial: Polynomial<ConfigPoly>;
 
     /// (Inverse) NTT Table
     type Table;
 
+    /// 1/DIM mod q
+    fn one_over_n() -> Self::BaseField;
+
     /// Get the forward table
     fn table() -> Self::Table;
 
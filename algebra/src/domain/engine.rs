@@ -0,0 +1,351 @@
+//! Generic negacyclic NTT engine.
+//!
+//! The twiddle factors are derived at runtime from a primitive root of unity
+//! of the base field, and cached for the lifetime of the program: per config
+//! through [`ConfigNTTDomain::ntt_table`], or in a global cache otherwise.
+
+use std::any::{Any, TypeId};
+use std::collections::HashMap;
+use std::sync::{OnceLock, RwLock};
+
+use crate::{ConfigNTTDomain, ConfigZZp, Field, NTTDomain, Polynomial, ZZVec, ZZp, ZZpX};
+
+/// Global cache of NTT tables, keyed by (field config, number of points, root of unity).
+type TableCache = HashMap<(TypeId, usize, u64), &'static (dyn Any + Send + Sync)>;
+
+static TABLE_CACHE: OnceLock<RwLock<TableCache>> = OnceLock::new();
+
+/// Bit-reversed twiddle tables for a negacyclic NTT over `ZZp<C>`.
+///
+/// An `n`-point table is built from a primitive `2n`-th root of unity `psi`.
+/// It transforms vectors whose length is `n * b` for some power of two `b`:
+/// the vector is viewed as a polynomial modulo `x^{n*b} + 1`, and reduced
+/// into the `n` residues modulo `x^b - psi^{2 brv(i) + 1}`, each of which is
+/// stored as a contiguous block of `b` coefficients.
+/// When `b = 1` this is the usual (complete) negacyclic NTT.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct NTTTable<C: ConfigZZp> {
+    /// Number of points
+    n: usize,
+    /// `psi^{brv(i)}` for `i` in `[0, n)`
+    forward: Vec<ZZp<C>>,
+    /// `psi^{-brv(i)}` for `i` in `[0, n)`
+    inverse: Vec<ZZp<C>>,
+    /// `1/n mod q`
+    one_over_n: ZZp<C>,
+}
+
+impl<C: ConfigZZp> NTTTable<C> {
+    /// Build an `n`-point table from a primitive `2n`-th root of unity `psi`.
+    ///
+    /// Panics if `n` is not a power of two, or `psi` is not a primitive `2n`-th root of unity.
+    pub fn new(n: usize, psi: ZZp<C>) -> Self {
+        assert!(n.is_power_of_two(), "n = {} is not a power of two", n);
+        assert!(
+            psi.pow_vartime([n as u64]) == -ZZp::one(),
+            "{} is not a primitive {}-th root of unity",
+            psi,
+            2 * n
+        );
+
+        let log_n = n.trailing_zeros();
+        let psi_inv = psi.invert().unwrap();
+
+        let mut psi_powers = Vec::with_capacity(n);
+        let mut psi_inv_powers = Vec::with_capacity(n);
+        let mut cur = ZZp::one();
+        let mut cur_inv = ZZp::one();
+        for _ in 0..n {
+            psi_powers.push(cur);
+            psi_inv_powers.push(cur_inv);
+            cur *= psi;
+            cur_inv *= psi_inv;
+        }
+
+        let forward = (0..n).map(|i| psi_powers[bit_reverse(i, log_n)]).collect();
+        let inverse = (0..n)
+            .map(|i| psi_inv_powers[bit_reverse(i, log_n)])
+            .collect();
+        let one_over_n = ZZp::<C>::from(n as u64).invert().unwrap();
+
+        Self {
+            n,
+            forward,
+            inverse,
+            one_over_n,
+        }
+    }
+
+    /// Get the cached `n`-point table for `psi`, building it on first use.
+    ///
+    /// Prefer a per-config `static` for tables on a hot path, see
+    /// [`ConfigNTTDomain::ntt_table`].
+    pub fn get(n: usize, psi: ZZp<C>) -> &'static Self {
+        let key = (TypeId::of::<C>(), n, u64::from(psi.canonical()));
+        let cache = TABLE_CACHE.get_or_init(|| RwLock::new(HashMap::new()));
+        let cached = cache.read().unwrap().get(&key).copied();
+        let table = match cached {
+            Some(table) => table,
+            None => *cache
+                .write()
+                .unwrap()
+                .entry(key)
+                .or_insert_with(|| Box::leak(Box::new(Self::new(n, psi)))),
+        };
+        table.downcast_ref::<Self>().unwrap()
+    }
+
+    /// Number of points
+    pub fn num_points(&self) -> usize {
+        self.n
+    }
+
+    /// The forward table, `psi^{brv(i)}`
+    pub fn forward_table(&self) -> &[ZZp<C>] {
+        &self.forward
+    }
+
+    /// The inverse table, `psi^{-brv(i)}`
+    pub fn inverse_table(&self) -> &[ZZp<C>] {
+        &self.inverse
+    }
+
+    /// `1/n mod q`
+    pub fn one_over_n(&self) -> ZZp<C> {
+        self.one_over_n
+    }
+
+    /// In-place forward transform (Cooley-Tukey butterflies).
+    /// Outputs are in bit-reversed order.
+    pub fn forward(&self, p: &mut [ZZp<C>]) {
+        let len = p.len();
+        assert!(
+            len.is_multiple_of(self.n) && (len / self.n).is_power_of_two(),
+            "invalid input length {} for a {}-point NTT",
+            len,
+            self.n
+        );
+
+        let mut t = len;
+        let mut m = 1;
+        while m < self.n {
+            let ht = t >> 1;
+            let mut j1 = 0;
+            for i in 0..m {
+                let s = self.forward[m + i];
+                for j in j1..j1 + ht {
+                    let u = p[j];
+                    let v = p[j + ht] * s;
+                    p[j] = u + v;
+                    p[j + ht] = u - v;
+                }
+                j1 += t;
+            }
+            t = ht;
+            m <<= 1;
+        }
+    }
+
+    /// In-place inverse transform (Gentleman-Sande butterflies),
+    /// including the scaling by `1/n`.
+    /// Inputs are in bit-reversed order.
+    pub fn inverse(&self, p: &mut [ZZp<C>]) {
+        let len = p.len();
+        assert!(
+            len.is_multiple_of(self.n) && (len / self.n).is_power_of_two(),
+            "invalid input length {} for a {}-point NTT",
+            len,
+            self.n
+        );
+
+        let mut t = len / self.n;
+        let mut m = self.n;
+        while m > 1 {
+            let hm = m >> 1;
+            let dt = t << 1;
+            let mut j1 = 0;
+            for i in 0..hm {
+                let s = self.inverse[hm + i];
+                for j in j1..j1 + t {
+                    let u = p[j];
+                    let v = p[j + t];
+                    p[j] = u + v;
+                    p[j + t] = (u - v) * s;
+                }
+                j1 += dt;
+            }
+            t = dt;
+            m = hm;
+        }
+
+        for e in p.iter_mut() {
+            *e *= self.one_over_n;
+        }
+    }
+}
+
+impl<C: ConfigNTTDomain> NTTDomain<C::PolyConfig, C> for ZZVec<C> {
+    type Polynomial = ZZpX<C::PolyConfig>;
+
+    type Table = &'static [ZZp<C::BaseConfig>];
+
+    /// 1/DIM mod q
+    fn one_over_n() -> Self::BaseField {
+        C::ntt_table().one_over_n()
+    }
+
+    /// Get the forward table
+    fn table() -> Self::Table {
+        C::ntt_table().forward_table()
+    }
+
+    /// Get the reverse table
+    fn inv_table() -> Self::Table {
+        C::ntt_table().inverse_table()
+    }
+
+    /// convert polynomial to vector
+    fn forward_ntt(poly: &Self::Polynomial) -> Self {
+        let mut coeffs = poly.coeffs.clone();
+        C::ntt_table().forward(&mut coeffs);
+        Self { coeffs }
+    }
+
+    /// convert the vector to polynomial
+    fn reverse_ntt(&self) -> Self::Polynomial {
+        let mut coeffs = self.coeffs.clone();
+        C::ntt_table().inverse(&mut coeffs);
+        Self::Polynomial::from_coefficients_vec_unchecked(coeffs)
+    }
+}
+
+/// Reverse the lowest `bits` bits of `i`.
+pub(crate) fn bit_reverse(i: usize, bits: u32) -> usize {
+    if bits == 0 {
+        0
+    } else {
+        i.reverse_bits() >> (usize::BITS - bits)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::{bit_reverse, NTTTable};
+    use crate::tests::test_rng;
+    use crate::{
+        ConfigNTTDomain, ConfigZZVec, ConfigZZVec12289_512, ConfigZZpGoldilocks, ConfigZZpX, Field,
+        Goldilocks, NTTDomain, NTTField, Polynomial, Vector, ZZVec, ZZpX, F12289, F3329,
+    };
+
+    /// A ring that is only declared through its configs
+    #[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+    struct ConfigZZpXGoldilocks1024;
+
+    impl ConfigZZpX for ConfigZZpXGoldilocks1024 {
+        type BaseConfig = ConfigZZpGoldilocks;
+        const DIM: usize = 1024;
+    }
+
+    #[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+    struct ConfigZZVecGoldilocks1024;
+
+    impl ConfigZZVec for ConfigZZVecGoldilocks1024 {
+        type BaseConfig = ConfigZZpGoldilocks;
+        const MAX_DIM: usize = 1024;
+    }
+
+    impl ConfigNTTDomain for ConfigZZVecGoldilocks1024 {
+        type PolyConfig = ConfigZZpXGoldilocks1024;
+    }
+
+    /// evaluate the polynomial at x
+    fn evaluate<F: Field>(coeffs: &[F], x: F) -> F {
+        coeffs.iter().rev().fold(F::zero(), |acc, c| acc * x + c)
+    }
+
+    #[test]
+    fn test_ntt_evaluation() {
+        let mut rng = test_rng();
+        for log_n in 0..10 {
+            let n = 1 << log_n;
+            let psi = F12289::get_primitive_2nth_root_of_unity(n as u64).unwrap();
+            let table = NTTTable::get(n, psi);
+            let a = (0..n).map(|_| F12289::random(&mut rng)).collect::<Vec<_>>();
+            let mut b = a.clone();
+            table.forward(&mut b);
+            for (i, e) in b.iter().enumerate() {
+                let x = psi.pow([2 * bit_reverse(i, log_n) as u64 + 1]);
+                assert_eq!(*e, evaluate(&a, x));
+            }
+            table.inverse(&mut b);
+            assert_eq!(a, b);
+        }
+    }
+
+    #[test]
+    fn test_incomplete_ntt() {
+        // 128 points over a degree 256 polynomial, as in Kyber
+        let mut rng = test_rng();
+        let psi = F3329::from(17);
+        let table = NTTTable::get(128, psi);
+        let a = (0..256)
+            .map(|_| F3329::random(&mut rng))
+            .collect::<Vec<_>>();
+        let mut b = a.clone();
+        table.forward(&mut b);
+
+        // block i is a(x) mod x^2 - psi^{2brv(i)+1}
+        for i in 0..128 {
+            let zeta = psi.pow([2 * bit_reverse(i, 7) as u64 + 1]);
+            let even = a.iter().step_by(2).cloned().collect::<Vec<_>>();
+            let odd = a.iter().skip(1).step_by(2).cloned().collect::<Vec<_>>();
+            assert_eq!(b[2 * i], evaluate(&even, zeta));
+            assert_eq!(b[2 * i + 1], evaluate(&odd, zeta));
+        }
+        table.inverse(&mut b);
+        assert_eq!(a, b);
+    }
+
+    #[test]
+    fn test_ntt_domain_from_config() {
+        let mut rng = test_rng();
+        let a = ZZpX::<ConfigZZpXGoldilocks1024>::random(&mut rng, None);
+        let b = ZZpX::<ConfigZZpXGoldilocks1024>::random(&mut rng, None);
+
+        let a_ntt: ZZVec<ConfigZZVecGoldilocks1024> = NTTDomain::forward_ntt(&a);
+        let b_ntt: ZZVec<ConfigZZVecGoldilocks1024> = NTTDomain::forward_ntt(&b);
+        assert_eq!(a_ntt.dimension(), 1024);
+        assert_eq!(a_ntt.reverse_ntt(), a);
+
+        // schoolbook negacyclic multiplication
+        let n = ConfigZZpXGoldilocks1024::DIM;
+        let mut c = vec![Goldilocks::zero(); n];
+        for (i, x) in a.coefficients().enumerate() {
+            for (j, y) in b.coefficients().enumerate() {
+                if i + j < n {
+                    c[i + j] += *x * y;
+                } else {
+                    c[i + j - n] -= *x * y;
+                }
+            }
+        }
+        assert_eq!((a_ntt * b_ntt).reverse_ntt().coeffs, c);
+    }
+
+    #[test]
+    fn test_ntt_cache() {
+        let psi = Goldilocks::get_primitive_2nth_root_of_unity(1024).unwrap();
+        let t1 = NTTTable::get(1024, psi);
+        let t2 = NTTTable::get(1024, psi);
+        assert!(std::ptr::eq(t1, t2));
+        assert_eq!(*t1, NTTTable::new(1024, psi));
+        assert_eq!(t1.forward_table()[512], psi);
+        assert_eq!(t1.one_over_n() * Goldilocks::from(1024), Goldilocks::one());
+
+        // instances keep their own table
+        let t3 = ConfigZZVec12289_512::ntt_table();
+        assert!(std::ptr::eq(t3, ConfigZZVec12289_512::ntt_table()));
+        assert_eq!(*t3, NTTTable::new(512, F12289::from(49)));
+    }
+}
@@ -1,2 +1,3 @@
 mod d12289;
+mod d8380417;
 mod goldilocks;
@@ -0,0 +1,31 @@
+use std::sync::OnceLock;
+
+use crate::{
+    ConfigNTTDomain, ConfigZZVec8380417_256, ConfigZZp8380417, ConfigZZpX8380417_256, NTTTable,
+    F8380417,
+};
+
+impl ConfigNTTDomain for ConfigZZVec8380417_256 {
+    type PolyConfig = ConfigZZpX8380417_256;
+
+    /// 1753 is the primitive 512-th root of unity mod 8380417 used in Dilithium
+    fn root_of_unity() -> F8380417 {
+        F8380417::from(1753)
+    }
+
+    fn ntt_table() -> &'static NTTTable<ConfigZZp8380417> {
+        static TABLE: OnceLock<NTTTable<ConfigZZp8380417>> = OnceLock::new();
+        TABLE.get_or_init(|| NTTTable::new(256, Self::root_of_unity()))
+    }
+}
+
+#[test]
+fn test_zetas() {
+    use crate::{NTTDomain, Vec8380417_256};
+
+    // first entries of the twiddle table in the Dilithium reference implementation
+    let table = <Vec8380417_256 as NTTDomain<ConfigZZpX8380417_256, _>>::table();
+    assert_eq!(table[1], F8380417::from(4808194));
+    assert_eq!(table[2], F8380417::from(3765607));
+    assert_eq!(table[3], F8380417::from(3761513));
+}
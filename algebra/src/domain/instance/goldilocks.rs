@@ -1,2140 +1,20 @@
+use std::sync::OnceLock;
+
 use crate::{
-    ConfigZZVec, ConfigZZVecGoldilocks256, ConfigZZpX, ConfigZZpXGoldilocks256, Goldilocks,
-    NTTDomain, Polynomial, ZZVec, ZZp, ZZpX,
+    ConfigNTTDomain, ConfigZZVecGoldilocks256, ConfigZZpGoldilocks, ConfigZZpXGoldilocks256,
+    Goldilocks, NTTTable,
 };
 
-impl NTTDomain<ConfigZZpXGoldilocks256, ConfigZZVecGoldilocks256>
-    for ZZVec<ConfigZZVecGoldilocks256>
-{
-    const ONE_OVER_N: u64 = 18374686475393433601;
-
-    type Polynomial = ZZpX<ConfigZZpXGoldilocks256>;
-
-    type Table = [Goldilocks; ConfigZZVecGoldilocks256::MAX_DIM * 4];
-
-    /// Get the forward table
-    fn table() -> Self::Table {
-        NTT_TABLE
-    }
-
-    /// Get the reverse table
-    fn inv_table() -> Self::Table {
-        INV_NTT_TABLE
-    }
+impl ConfigNTTDomain for ConfigZZVecGoldilocks256 {
+    type PolyConfig = ConfigZZpXGoldilocks256;
 
-    /// convert polynomial to vector
-    fn forward_ntt(poly: &Self::Polynomial) -> Self {
-        let mut p = poly.coeffs.clone();
-        let mut t = ConfigZZpXGoldilocks256::DIM;
-        for l in 0..8 {
-            let m = 1 << l;
-            let ht = t >> 1;
-            let mut i = 0;
-            let mut j1 = 0;
-            while i < m {
-                let s = NTT_TABLE[m + i];
-                let j2 = j1 + ht;
-                let mut j = j1;
-                while j < j2 {
-                    let u = p[j];
-                    let v = (p[j + ht]) * (s);
-                    p[j] = u + v;
-                    p[j + ht] = u - v;
-                    j += 1;
-                }
-                i += 1;
-                j1 += t;
-            }
-            t = ht;
-        }
-        Self { coeffs: p }
+    /// A primitive 512-th root of unity mod 2^64 - 2^32 + 1
+    fn root_of_unity() -> Goldilocks {
+        Goldilocks::from(1691643236322650437)
     }
 
-    /// convert the vector to polynomial
-    fn reverse_ntt(&self) -> Self::Polynomial {
-        let mut p = self.coeffs.clone();
-        let mut t = 1;
-        let mut m = ConfigZZpXGoldilocks256::DIM;
-
-        while m > 1 {
-            let hm = m >> 1;
-            let dt = t << 1;
-            let mut i = 0usize;
-            let mut j1 = 0;
-            while i < hm {
-                let j2 = j1 + t;
-                let s = INV_NTT_TABLE[hm + i];
-                let mut j = j1;
-                while j < j2 {
-                    let u = p[j];
-                    let v = p[j + t];
-                    p[j] = u + v;
-                    p[j + t] = (u - v) * s;
-                    j += 1;
-                }
-                i += 1;
-                j1 += dt;
-            }
-            t = dt;
-            m = hm;
-        }
-        for e in p.iter_mut() {
-            *e *= ZZp(Self::ONE_OVER_N);
-        }
-        Self::Polynomial::from_coefficients_vec_unchecked(p)
+    fn ntt_table() -> &'static NTTTable<ConfigZZpGoldilocks> {
+        static TABLE: OnceLock<NTTTable<ConfigZZpGoldilocks>> = OnceLock::new();
+        TABLE.get_or_init(|| NTTTable::new(256, Self::root_of_unity()))
     }
 }
-
-pub(crate) const NTT_TABLE: [Goldilocks; 1024] = [
-    ZZp(1),
-    ZZp(18446462594437873665),
-    ZZp(18446742969902956801),
-    ZZp(18446744069397807105),
-    ZZp(18442240469788262401),
-    ZZp(18446744000695107585),
-    ZZp(17293822564807737345),
-    ZZp(18446744069414580225),
-    ZZp(18158513693329981441),
-    ZZp(18446739671368073217),
-    ZZp(18446744052234715141),
-    ZZp(18446744069414322177),
-    ZZp(18446673700670423041),
-    ZZp(18446744068340842497),
-    ZZp(18428729670905102337),
-    ZZp(18446744069414584257),
-    ZZp(16140901060737761281),
-    ZZp(18446708885042495489),
-    ZZp(18446743931975630881),
-    ZZp(18446744069412487169),
-    ZZp(18446181119461294081),
-    ZZp(18446744060824649729),
-    ZZp(18302628881338728449),
-    ZZp(18446744069414583809),
-    ZZp(18410715272404008961),
-    ZZp(18446743519658770433),
-    ZZp(9223372032559808513),
-    ZZp(18446744069414551553),
-    ZZp(18446735273321564161),
-    ZZp(18446744069280366593),
-    ZZp(18444492269600899073),
-    ZZp(18446744069414584313),
-    ZZp(18446743794540871745),
-    ZZp(18446743794532483137),
-    ZZp(13834987683316760577),
-    ZZp(4611615648609468416),
-    ZZp(18158513693262873601),
-    ZZp(18158513693262871553),
-    ZZp(18445618152328134657),
-    ZZp(1125882726711296),
-    ZZp(18446744065119682562),
-    ZZp(18446744065119551490),
-    ZZp(18374685375881805825),
-    ZZp(72056494509522944),
-    ZZp(18442240469787213841),
-    ZZp(18442240469787213809),
-    ZZp(18446726476960108545),
-    ZZp(17591917604864),
-    ZZp(18446744035055370249),
-    ZZp(18446744035054321673),
-    ZZp(17870274521152356353),
-    ZZp(576451956076183552),
-    ZZp(18410715272395620481),
-    ZZp(18410715272395620225),
-    ZZp(18446603329778778113),
-    ZZp(140735340838912),
-    ZZp(16140901060200898561),
-    ZZp(16140901060200882177),
-    ZZp(18437736732722987009),
-    ZZp(9007061813690368),
-    ZZp(18446181119461163011),
-    ZZp(18446181119461163007),
-    ZZp(18446741870357774849),
-    ZZp(2198989700608),
-    ZZp(4195631349813649467),
-    ZZp(9171943329124577373),
-    ZZp(15951685255325333175),
-    ZZp(9516004302527281633),
-    ZZp(18182056015521604139),
-    ZZp(18030148548143482816),
-    ZZp(10708950766175242252),
-    ZZp(7059463857684370340),
-    ZZp(1506708620263852673),
-    ZZp(10231374777478672322),
-    ZZp(2843318466875884251),
-    ZZp(9083829225849678056),
-    ZZp(6336932523019185545),
-    ZZp(281721071064741919),
-    ZZp(15155306912120837921),
-    ZZp(8180754653145198927),
-    ZZp(12053668962110821384),
-    ZZp(8064021942171041292),
-    ZZp(4299803665592489687),
-    ZZp(17330401598553671485),
-    ZZp(13801972045324315718),
-    ZZp(2253768568517935352),
-    ZZp(10561990880479197442),
-    ZZp(10105805016917838453),
-    ZZp(16329239638270742865),
-    ZZp(15113979899245772281),
-    ZZp(11884629851743600732),
-    ZZp(1135478653231209757),
-    ZZp(16933017626115159474),
-    ZZp(2341058142559915780),
-    ZZp(18035314424752866021),
-    ZZp(3328437340319972906),
-    ZZp(10832292272906805046),
-    ZZp(6366922389463153702),
-    ZZp(3341893669734556710),
-    ZZp(10467450029535024137),
-    ZZp(912371727122717978),
-    ZZp(4442103655964903148),
-    ZZp(13664737158269917819),
-    ZZp(13797081185216407910),
-    ZZp(3051558327610197629),
-    ZZp(7593472940535036657),
-    ZZp(7546206866789277329),
-    ZZp(16016224591364643153),
-    ZZp(10967010099451201909),
-    ZZp(5834015391316509212),
-    ZZp(1654663398520981866),
-    ZZp(7709569171718681254),
-    ZZp(5965722551466996711),
-    ZZp(5407551316036540293),
-    ZZp(5029422726070465669),
-    ZZp(17449332314429639298),
-    ZZp(13949104517951277988),
-    ZZp(9778634991702905054),
-    ZZp(13237307188167854928),
-    ZZp(6336321165505697069),
-    ZZp(7298973816981743824),
-    ZZp(17090085178304640863),
-    ZZp(17084176919086420947),
-    ZZp(18142929134658341675),
-    ZZp(8288405288461869359),
-    ZZp(9952623958621855812),
-    ZZp(14041890976876060974),
-    ZZp(5575382163818481237),
-    ZZp(1691643236322650437),
-    ZZp(16873708294018933551),
-    ZZp(5464760906092500108),
-    ZZp(8096577031901772269),
-    ZZp(7760115154989660995),
-    ZZp(14780429931651188987),
-    ZZp(13205888274518958430),
-    ZZp(7005074122307514744),
-    ZZp(17032024114559111334),
-    ZZp(5163568085532294797),
-    ZZp(15073366445557045075),
-    ZZp(5602886161730919912),
-    ZZp(17703304740457489134),
-    ZZp(1672096098105064228),
-    ZZp(10006174791165856646),
-    ZZp(2415297291837877958),
-    ZZp(7128984430570800425),
-    ZZp(4415056545429189734),
-    ZZp(9906467147968854674),
-    ZZp(7929601155018190654),
-    ZZp(12499229437757822825),
-    ZZp(13376768784840513824),
-    ZZp(6262422051668515884),
-    ZZp(875634265288439343),
-    ZZp(6740689031673534997),
-    ZZp(7562975036722005970),
-    ZZp(13413385849078745835),
-    ZZp(700360770216364989),
-    ZZp(6824599109910832222),
-    ZZp(9432384046970425189),
-    ZZp(5862457866249378161),
-    ZZp(4913598178833380825),
-    ZZp(3953731985901328040),
-    ZZp(17225392536559506335),
-    ZZp(4641337882585395997),
-    ZZp(13018888299131362939),
-    ZZp(10773575572760153082),
-    ZZp(14276112633913910454),
-    ZZp(14858508306367504656),
-    ZZp(1755078694165381998),
-    ZZp(6979306088310177371),
-    ZZp(9780749169175637327),
-    ZZp(10160584067376497613),
-    ZZp(1644572010096941946),
-    ZZp(1897719374831994672),
-    ZZp(3105368020750933651),
-    ZZp(14067222244347930501),
-    ZZp(5215569874119185934),
-    ZZp(494216498237666005),
-    ZZp(4459017075746761332),
-    ZZp(7497696261353643620),
-    ZZp(13156576080775535568),
-    ZZp(15181754998655957376),
-    ZZp(6396200096592884887),
-    ZZp(1857313538295938082),
-    ZZp(4831070854124318830),
-    ZZp(12401628304422887372),
-    ZZp(3528436654823777706),
-    ZZp(8187602034452531322),
-    ZZp(14040629553323055984),
-    ZZp(237214921853999334),
-    ZZp(11917386045977981907),
-    ZZp(8675931806573960433),
-    ZZp(5263632251618544322),
-    ZZp(16447742384734775766),
-    ZZp(4007052201025272707),
-    ZZp(13787254465881465880),
-    ZZp(8143771702088974879),
-    ZZp(7110695772441637899),
-    ZZp(5175614254872652016),
-    ZZp(13730337689951546503),
-    ZZp(16003277697834987077),
-    ZZp(12362671770314801832),
-    ZZp(17644663131801795567),
-    ZZp(11744640894413513105),
-    ZZp(9638848843637035273),
-    ZZp(15387314553928353233),
-    ZZp(4692554990086031268),
-    ZZp(16643667963227857075),
-    ZZp(17255643403020241594),
-    ZZp(6667653815445493051),
-    ZZp(12030096568512274289),
-    ZZp(1723406808235183235),
-    ZZp(3323814471437944900),
-    ZZp(12418052014939319938),
-    ZZp(646951781859081502),
-    ZZp(4022135219920766353),
-    ZZp(8917938738259842505),
-    ZZp(1545333971289350229),
-    ZZp(4511425900152047486),
-    ZZp(17608981172539450419),
-    ZZp(17345757166192390690),
-    ZZp(18064315379978805435),
-    ZZp(9809941408468046069),
-    ZZp(13609673538787597335),
-    ZZp(15992013477438468440),
-    ZZp(15245928743009060991),
-    ZZp(10094442559346256270),
-    ZZp(502012629086366038),
-    ZZp(10724885506133562476),
-    ZZp(8655137032736432017),
-    ZZp(7433403846946633395),
-    ZZp(7683263524182218559),
-    ZZp(13351287672668691770),
-    ZZp(526448012694119458),
-    ZZp(14569244469219929255),
-    ZZp(12113519742882795430),
-    ZZp(5932183857725394514),
-    ZZp(13682064192112842111),
-    ZZp(3863141824208378587),
-    ZZp(408281368649950045),
-    ZZp(1937996126393065589),
-    ZZp(4211584101552955664),
-    ZZp(5873491337271928114),
-    ZZp(4674437595989441835),
-    ZZp(10563982722973987470),
-    ZZp(17222793189829815283),
-    ZZp(12458390524252444375),
-    ZZp(3266250949199600360),
-    ZZp(15503969011144524712),
-    ZZp(13900864053647703173),
-    ZZp(4126998567329314197),
-    ZZp(6125875985213995509),
-    ZZp(14576581034276612555),
-    ZZp(4016101032690928304),
-    ZZp(12012107771410162524),
-    ZZp(6968564197111712876),
-    ZZp(7159778541829602319),
-    ZZp(3929858786378642316),
-    ZZp(7112675246154377750),
-    ZZp(8362186383759854260),
-    ZZp(4689912295553665450),
-    ZZp(14358435046894549184),
-    ZZp(6820186327231405039),
-    ZZp(6108922642702621141),
-    ZZp(7305983592013185897),
-    ZZp(15049383599936516047),
-    ZZp(12216811346274483113),
-    ZZp(3589423675261482283),
-    ZZp(6414348153479289383),
-    ZZp(224350547607727331),
-    ZZp(5006481804801239664),
-    ZZp(12489358087930152296),
-    ZZp(6743454643571072270),
-    ZZp(9714604383004622450),
-    ZZp(5500770423122943299),
-    ZZp(10268645332677273943),
-    ZZp(14421297089005146422),
-    ZZp(1794804380861818648),
-    ZZp(3158366299580748670),
-    ZZp(7681144356368296763),
-    ZZp(17054149009739409518),
-    ZZp(4187015958668887546),
-    ZZp(17668002479022071670),
-    ZZp(11977893002791800486),
-    ZZp(3107636527861734213),
-    ZZp(11557258861835081117),
-    ZZp(625810225600154958),
-    ZZp(1561169760991269037),
-    ZZp(5454617847800030114),
-    ZZp(10141440556758839363),
-    ZZp(12486396704535672088),
-    ZZp(11984005542840547177),
-    ZZp(10019076149651226019),
-    ZZp(18147478832086943132),
-    ZZp(12577098593386243920),
-    ZZp(9906341360885134636),
-    ZZp(2727123837437860044),
-    ZZp(17740512949860132546),
-    ZZp(11724314991892485077),
-    ZZp(6816548736552749790),
-    ZZp(8515228971291783927),
-    ZZp(10659847895797062167),
-    ZZp(14031575217582598302),
-    ZZp(5919394105455887829),
-    ZZp(15030590866359316324),
-    ZZp(12796895112978970121),
-    ZZp(1560799588066959011),
-    ZZp(17638901753592829678),
-    ZZp(12781599562090518453),
-    ZZp(11491806888718160052),
-    ZZp(1572137324173280490),
-    ZZp(10461664704817933990),
-    ZZp(9564262514387024666),
-    ZZp(16052622170793454809),
-    ZZp(8383068400017029755),
-    ZZp(5463754609422739804),
-    ZZp(3370246630088296031),
-    ZZp(3638323995651455811),
-    ZZp(6365632919551470868),
-    ZZp(7657453289212455099),
-    ZZp(11102195893002206701),
-    ZZp(3885345703086309931),
-    ZZp(13294663425500451833),
-    ZZp(17527399748276289503),
-    ZZp(12316227567088954246),
-    ZZp(15948194847534211277),
-    ZZp(13869829016883058002),
-    ZZp(152897937997792376),
-    ZZp(5164132063260791647),
-    ZZp(6113546424387384073),
-    ZZp(2225341748615664720),
-    ZZp(9785468031858712064),
-    ZZp(16909802868642731951),
-    ZZp(14948939724807468932),
-    ZZp(13475313378280530262),
-    ZZp(2308232038958038546),
-    ZZp(9592291974280344910),
-    ZZp(12014883256269903942),
-    ZZp(17802733988925317760),
-    ZZp(4496767977211359228),
-    ZZp(6151214463239765361),
-    ZZp(8911053381972245530),
-    ZZp(15568786679171320491),
-    ZZp(19112242249724047),
-    ZZp(2951359516584421996),
-    ZZp(16905094363786184290),
-    ZZp(278167718576958090),
-    ZZp(1223183503982339008),
-    ZZp(4419568367257164534),
-    ZZp(11091989500308225777),
-    ZZp(6296100189638712363),
-    ZZp(14123587056930693059),
-    ZZp(5810722514138689194),
-    ZZp(15137851097357772055),
-    ZZp(2849220811487664857),
-    ZZp(4295002282146690441),
-    ZZp(11575701465310827636),
-    ZZp(12582249520745188423),
-    ZZp(5940943517668292086),
-    ZZp(12066191983457963400),
-    ZZp(13315466594398149922),
-    ZZp(12053974342864933269),
-    ZZp(11285503742479007084),
-    ZZp(15919780095311700439),
-    ZZp(3639634848410716242),
-    ZZp(16625729085584007730),
-    ZZp(2975131003309595864),
-    ZZp(16329435310479291959),
-    ZZp(8854965448075557493),
-    ZZp(4198074395846544547),
-    ZZp(16497053662173719388),
-    ZZp(16677776346006097586),
-    ZZp(10670334717871145615),
-    ZZp(3878624198769971593),
-    ZZp(5354303957062182591),
-    ZZp(1508273997932245425),
-    ZZp(15499491376360706981),
-    ZZp(8424275818888585779),
-    ZZp(10634060002517168046),
-    ZZp(4295815520590785595),
-    ZZp(14290012408112277771),
-    ZZp(15913274187758939207),
-    ZZp(371891375413699483),
-    ZZp(4347022422486734535),
-    ZZp(8024399707039913807),
-    ZZp(9233735841019365682),
-    ZZp(11888040935601451494),
-    ZZp(4573069520345433394),
-    ZZp(3192518480993723602),
-    ZZp(7847524879092096009),
-    ZZp(16260897004766174524),
-    ZZp(12458073038457296305),
-    ZZp(12890081553990608899),
-    ZZp(4179502387700367909),
-    ZZp(7679740417818447560),
-    ZZp(4106679476439837717),
-    ZZp(13308480401157259412),
-    ZZp(15975288260888972401),
-    ZZp(1406998020037882997),
-    ZZp(4518113032494938455),
-    ZZp(17783460465441878945),
-    ZZp(14989275032188358951),
-    ZZp(6097691134303827517),
-    ZZp(14406691742104117415),
-    ZZp(14234122862185153691),
-    ZZp(17121841670624273282),
-    ZZp(11255984160303063976),
-    ZZp(17698160190544923319),
-    ZZp(13140475237632941313),
-    ZZp(7439966824493015109),
-    ZZp(959967552227305945),
-    ZZp(7430863960585448835),
-    ZZp(10886932084851949587),
-    ZZp(18137812093348882831),
-    ZZp(7093403778535204495),
-    ZZp(2870607137738690347),
-    ZZp(18363833618917996149),
-    ZZp(345137759837927448),
-    ZZp(1941397000334789249),
-    ZZp(5280444194155204294),
-    ZZp(663576273645521453),
-    ZZp(9115369905823964012),
-    ZZp(6337038648111976301),
-    ZZp(1415419453610921553),
-    ZZp(6715029048772165709),
-    ZZp(11534607820881582817),
-    ZZp(18188848021460212523),
-    ZZp(16799868753440642108),
-    ZZp(5486745524883165993),
-    ZZp(5907035176470557038),
-    ZZp(5575393374484204350),
-    ZZp(13568943604939006010),
-    ZZp(14804671509201811970),
-    ZZp(43142219979740931),
-    ZZp(16383575685779609937),
-    ZZp(5271741541623046617),
-    ZZp(7000476060236159302),
-    ZZp(10362793272935287662),
-    ZZp(7709658857044466158),
-    ZZp(16317828492439126475),
-    ZZp(7756907657126989834),
-    ZZp(17582727038347959133),
-    ZZp(13802821046066641766),
-    ZZp(11323355628887372424),
-    ZZp(16826744251348157030),
-    ZZp(5350065414412465710),
-    ZZp(5308610189164171624),
-    ZZp(15531176002678313992),
-    ZZp(15685641990711164737),
-    ZZp(9546597805441465650),
-    ZZp(17198755642670596954),
-    ZZp(2540820207615693247),
-    ZZp(16121944306381782101),
-    ZZp(3235915244053982668),
-    ZZp(14586854504982200837),
-    ZZp(16434255353882186006),
-    ZZp(4232816070675458120),
-    ZZp(4184390855894463221),
-    ZZp(11221484848131637518),
-    ZZp(327930691828598087),
-    ZZp(12645811551425139186),
-    ZZp(15038540732087693240),
-    ZZp(17233511790631916809),
-    ZZp(12362461035457730117),
-    ZZp(16207038811842065314),
-    ZZp(15028382777741121447),
-    ZZp(15984902507394762860),
-    ZZp(2623445534628784696),
-    ZZp(8932772064328191883),
-    ZZp(9627861440214039994),
-    ZZp(8740885839153244225),
-    ZZp(6665967936588919331),
-    ZZp(529102008834432265),
-    ZZp(7440577883017277023),
-    ZZp(6014371623370100770),
-    ZZp(2346834345155397801),
-    ZZp(15415784495989080639),
-    ZZp(1879817591510961655),
-    ZZp(18295090034566750882),
-    ZZp(8462836655462685385),
-    ZZp(15860937903541196405),
-    ZZp(5828091010015769947),
-    ZZp(4665691128499368837),
-    ZZp(6686338362028015651),
-    ZZp(1913039459307282826),
-    ZZp(12273731855508974132),
-    ZZp(14390139890846703192),
-    ZZp(18290750489319984117),
-    ZZp(16672792867292992783),
-    ZZp(10755587837161802966),
-    ZZp(17078493612874372559),
-    ZZp(8463154943360171265),
-    ZZp(15594331550120231815),
-    ZZp(3650541573257562281),
-    ZZp(11754060979178594938),
-    ZZp(3456327113326256432),
-    ZZp(14383800816696994133),
-    ZZp(12257726419636086444),
-    ZZp(7500740417092890225),
-    ZZp(12365007338637617157),
-    ZZp(14074187984474348594),
-    ZZp(10757588516645913927),
-    ZZp(1798767486355837899),
-    ZZp(9203872837195467135),
-    ZZp(4389942117088447138),
-    ZZp(5956134496998871451),
-    ZZp(4440654710286119610),
-    ZZp(17198795428657782689),
-    ZZp(4255134452441852017),
-    ZZp(16597218757394956566),
-    ZZp(15304315674458262608),
-    ZZp(432040889165782054),
-    ZZp(8715504128117593387),
-    ZZp(18427161551977504986),
-    ZZp(12779284606764309526),
-    ZZp(11898271617552131805),
-    ZZp(3612989372665674350),
-    ZZp(17469458169614681519),
-    ZZp(4515726768105512158),
-    ZZp(10536824377436099819),
-    ZZp(6423015144618618876),
-    ZZp(11240678690464557956),
-    ZZp(12305352117534013297),
-    ZZp(10273973656985352860),
-    ZZp(5244599728470753002),
-    ZZp(5172876677338478359),
-    ZZp(9870391017628146548),
-    ZZp(6217475778674099540),
-    ZZp(1253281115973077440),
-    ZZp(16138453246058126364),
-    ZZp(6209096593199184771),
-    ZZp(8404812978224485596),
-    ZZp(5063309688936855374),
-    ZZp(4489525279878658230),
-    ZZp(5176151863366835100),
-    ZZp(12846318090563627678),
-    ZZp(10026248927784619520),
-    ZZp(10628456871015361905),
-    ZZp(17679070075429512943),
-    ZZp(10507618741830461268),
-    ZZp(14490633018119782366),
-    ZZp(2952452593344132835),
-    ZZp(10457170911910810479),
-    ZZp(10000556507041554603),
-    ZZp(156660139496634680),
-    ZZp(6683006578575260444),
-    ZZp(8489501264908097619),
-    ZZp(7801611539685453628),
-    ZZp(17034774833111285456),
-    ZZp(5640138325558016316),
-    ZZp(8851645897867325754),
-    ZZp(884610217076402339),
-    ZZp(1373253166976353740),
-    ZZp(10480715516835942125),
-    ZZp(13103015381071318626),
-    ZZp(1274821684645996733),
-    ZZp(14101226408828302076),
-    ZZp(1241048665675255525),
-    ZZp(1867689223661794245),
-    ZZp(8372502941095302307),
-    ZZp(15009436637133355288),
-    ZZp(10058747857029199716),
-    ZZp(12590402701497627403),
-    ZZp(10198573477167973864),
-    ZZp(2129346854138910682),
-    ZZp(9928389325402044200),
-    ZZp(14941513789294353960),
-    ZZp(11639791320518665493),
-    ZZp(9395028680579336378),
-    ZZp(8227618465634961886),
-    ZZp(15472934974694853069),
-    ZZp(7076881736611218712),
-    ZZp(10986025335810829920),
-    ZZp(7072660109239876061),
-    ZZp(7150990178988193401),
-    ZZp(12575777911021027989),
-    ZZp(1876179579641669411),
-    ZZp(1767885945671203408),
-    ZZp(18071077078872538029),
-    ZZp(15908314874472904356),
-    ZZp(1820672485525850315),
-    ZZp(6557672668704420404),
-    ZZp(4989896670390824556),
-    ZZp(10794508570603470932),
-    ZZp(8309585810682478985),
-    ZZp(13862681269962050794),
-    ZZp(5758737724964838127),
-    ZZp(8319017950282519771),
-    ZZp(15304657940070294052),
-    ZZp(3561228148463741129),
-    ZZp(5842574657166914234),
-    ZZp(12850800744138205954),
-    ZZp(15982507962945072135),
-    ZZp(220985743208900426),
-    ZZp(9176413660889536374),
-    ZZp(11211911394016405205),
-    ZZp(11756799104074846490),
-    ZZp(10043081118295344711),
-    ZZp(9847109118506145230),
-    ZZp(10572685606032726027),
-    ZZp(17179599287073071154),
-    ZZp(15567893210806194590),
-    ZZp(3025685224297427806),
-    ZZp(12569092287169430172),
-    ZZp(11136454277216078917),
-    ZZp(16586054579295728922),
-    ZZp(14565379884206802520),
-    ZZp(15441408145078213985),
-    ZZp(4303656504044957057),
-    ZZp(17356968649297701751),
-    ZZp(9043932857353334748),
-    ZZp(348054738782289279),
-    ZZp(17910855835361271896),
-    ZZp(5232916707333894067),
-    ZZp(164337577967841399),
-    ZZp(2886892334773811240),
-    ZZp(18054800069836185359),
-    ZZp(2865276019906702510),
-    ZZp(10517604989941849536),
-    ZZp(293668731378076150),
-    ZZp(11809072165811635074),
-    ZZp(3828759212651929535),
-    ZZp(2596641159417173442),
-    ZZp(6962636718761309921),
-    ZZp(14405394679236731517),
-    ZZp(4475464089839035759),
-    ZZp(10353863641876459004),
-    ZZp(2349349851024609200),
-    ZZp(2238856979420158987),
-    ZZp(12183329631800851959),
-    ZZp(2326385205922803215),
-    ZZp(360861541846726405),
-    ZZp(4562693017406346210),
-    ZZp(4969845519841983894),
-    ZZp(1314700623742731192),
-    ZZp(4648394608775905599),
-    ZZp(15311192072787392625),
-    ZZp(2784437910258314232),
-    ZZp(14159638196988084921),
-    ZZp(17011230650582925021),
-    ZZp(8718203360935060560),
-    ZZp(9450510161506158032),
-    ZZp(14214553617249879539),
-    ZZp(3799402574608251843),
-    ZZp(1421463772962432757),
-    ZZp(11747695078904966325),
-    ZZp(11591232188530511557),
-    ZZp(4887333183078474668),
-    ZZp(10426180171989190707),
-    ZZp(13982722273334471960),
-    ZZp(3969097289369366808),
-    ZZp(17641418606389029616),
-    ZZp(3192744508383169692),
-    ZZp(3354091872538521779),
-    ZZp(17186705191937359164),
-    ZZp(5840972102677658767),
-    ZZp(3909903954287168545),
-    ZZp(1181313770188269754),
-    ZZp(13306034245540350143),
-    ZZp(12004140365210146681),
-    ZZp(7095211997650773215),
-    ZZp(8385990910893589911),
-    ZZp(8366433049596783065),
-    ZZp(9834288682592101494),
-    ZZp(12832487564882764039),
-    ZZp(1747840284166808995),
-    ZZp(496137161171170851),
-    ZZp(2205177325798628702),
-    ZZp(9622465098255188372),
-    ZZp(11948476527451430423),
-    ZZp(11371710183699462056),
-    ZZp(3035964521511530386),
-    ZZp(16629639055023657349),
-    ZZp(9817856735118746932),
-    ZZp(1305216586892671150),
-    ZZp(10527951962042655473),
-    ZZp(5802696881289576457),
-    ZZp(12450346304833259231),
-    ZZp(8440064356120562424),
-    ZZp(15058103851566161631),
-    ZZp(18407628346820970629),
-    ZZp(3612168524501464981),
-    ZZp(5208540778693049827),
-    ZZp(4487954890675959692),
-    ZZp(15943337823423308033),
-    ZZp(9706139071804914716),
-    ZZp(2437719014241206828),
-    ZZp(9746885283472616316),
-    ZZp(17293211381910647587),
-    ZZp(10450604126597135527),
-    ZZp(4774838090715229974),
-    ZZp(17456895055993093215),
-    ZZp(16866238170898958338),
-    ZZp(3862136296780980444),
-    ZZp(1055008044515070303),
-    ZZp(4188105990122593244),
-    ZZp(9218482569383090449),
-    ZZp(7369050091593152243),
-    ZZp(12180282640720746429),
-    ZZp(9784366396041787122),
-    ZZp(18133818288665674785),
-    ZZp(10436639418682906500),
-    ZZp(9528086911487443014),
-    ZZp(10441732695141369200),
-    ZZp(13690866466122946149),
-    ZZp(4674892357270816918),
-    ZZp(12219798158457491250),
-    ZZp(12625676904294673039),
-    ZZp(3112214569540444302),
-    ZZp(8548973421900915981),
-    ZZp(930724872182095181),
-    ZZp(6276596720116897127),
-    ZZp(17807992740487013391),
-    ZZp(12178720988635677475),
-    ZZp(4226159611410338621),
-    ZZp(14320564629775145387),
-    ZZp(14460147156879213443),
-    ZZp(14833326890031948693),
-    ZZp(14714291756442592118),
-    ZZp(7303831225866898518),
-    ZZp(14401538314715650705),
-    ZZp(5196047562012498195),
-    ZZp(15362532821868124647),
-    ZZp(3884052621713657170),
-    ZZp(5000712838546201618),
-    ZZp(7986150703768083618),
-    ZZp(7033869635053231018),
-    ZZp(3090417598691435181),
-    ZZp(4531842101237699714),
-    ZZp(13051555166963574885),
-    ZZp(7445798977456761448),
-    ZZp(13319285622106008374),
-    ZZp(13336733437994016881),
-    ZZp(8771694887284462707),
-    ZZp(6450972486908970095),
-    ZZp(5524664920587008395),
-    ZZp(17941093350077217619),
-    ZZp(16213423635749495425),
-    ZZp(17769530920519717576),
-    ZZp(4223672628932077042),
-    ZZp(1886226450296668583),
-    ZZp(15563895064322055255),
-    ZZp(15244519399858269790),
-    ZZp(11593296608228044951),
-    ZZp(16542181931984879121),
-    ZZp(18411848437638567307),
-    ZZp(16418549981370881868),
-    ZZp(4101220150011504024),
-    ZZp(7235231690400957447),
-    ZZp(12060631279848750194),
-    ZZp(10038028402499283386),
-    ZZp(11998590678972281283),
-    ZZp(13805299268663599097),
-    ZZp(18167579015206448209),
-    ZZp(2221191365064964697),
-    ZZp(14363017130677447871),
-    ZZp(2541621314963906613),
-    ZZp(4251329891717079947),
-    ZZp(6517250942335929804),
-    ZZp(3755005084705328659),
-    ZZp(18208673802235871171),
-    ZZp(13830696098088936114),
-    ZZp(11275690782378652394),
-    ZZp(512652518751438003),
-    ZZp(3210246969976942721),
-    ZZp(15342636962042032015),
-    ZZp(15089811602373348664),
-    ZZp(13029038878255650361),
-    ZZp(17866563469320711168),
-    ZZp(1322784422603461084),
-    ZZp(8410323098987698703),
-    ZZp(5543407851311679624),
-    ZZp(10621160777346866042),
-    ZZp(16303353592701025074),
-    ZZp(6852028333173479114),
-    ZZp(8612235856584960181),
-    ZZp(5217761424111190310),
-    ZZp(10396962045648882760),
-    ZZp(14254699726567223913),
-    ZZp(16227516808414506275),
-    ZZp(1895337893653662062),
-    ZZp(4289965165070393837),
-    ZZp(15671503251274391132),
-    ZZp(3305100322189771683),
-    ZZp(7575517300451412229),
-    ZZp(9388720087532724796),
-    ZZp(3357133396050285378),
-    ZZp(692925981413959953),
-    ZZp(15162703149229296496),
-    ZZp(15872977251148566375),
-    ZZp(14691561593707623130),
-    ZZp(7994058508103589143),
-    ZZp(5263906195367544869),
-    ZZp(1299620255706110345),
-    ZZp(17922738526558664270),
-    ZZp(13557654644435928485),
-    ZZp(4848603254060353838),
-    ZZp(7453774671664268350),
-    ZZp(11182309941116591052),
-    ZZp(11942352583657836661),
-    ZZp(7864468688586895649),
-    ZZp(7953933803198495556),
-    ZZp(13264514191871928577),
-    ZZp(7044962897751436027),
-    ZZp(6383570686449705485),
-    ZZp(5460304625472088548),
-    ZZp(8069185337527683703),
-    ZZp(5788845481468626047),
-    ZZp(16083912754532697831),
-    ZZp(17418102780751149294),
-    ZZp(18365771727577980325),
-    ZZp(1551229425700380588),
-    ZZp(14799492472290523529),
-    ZZp(8155767790141882024),
-    ZZp(2720154405660295978),
-    ZZp(378681086732550162),
-    ZZp(7457070538904645404),
-    ZZp(10217613760107104105),
-    ZZp(17798965334721752353),
-    ZZp(12409835405603044704),
-    ZZp(7715475361836682306),
-    ZZp(9905910112891303229),
-    ZZp(3314491175867783503),
-    ZZp(3029448693860401296),
-    ZZp(4316332102993410269),
-    ZZp(6788948864947539742),
-    ZZp(9213250491977716661),
-    ZZp(9417275712919839734),
-    ZZp(17990837619774076722),
-    ZZp(1019470973767735253),
-    ZZp(14175077352768475238),
-    ZZp(13882393187902507011),
-    ZZp(10155505852070372836),
-    ZZp(17126297423062180600),
-    ZZp(595483062947895548),
-    ZZp(12744352334460992620),
-    ZZp(4226351207866543317),
-    ZZp(15028189578366727411),
-    ZZp(8088610310481333334),
-    ZZp(4128408671855034236),
-    ZZp(3653451120972435163),
-    ZZp(2573441405912169612),
-    ZZp(1162225927196972388),
-    ZZp(5963738026918010610),
-    ZZp(12459942909260838580),
-    ZZp(3981810351261817556),
-    ZZp(12232060331654591794),
-    ZZp(1217427889836146430),
-    ZZp(10721609088895500860),
-    ZZp(2140787177882772575),
-    ZZp(9297807417575779104),
-    ZZp(10816416076514916238),
-    ZZp(7445822927013787035),
-    ZZp(13407738740679956127),
-    ZZp(5622762306163812747),
-    ZZp(9739423118689171440),
-    ZZp(11985896433505669596),
-    ZZp(9545052210446313362),
-    ZZp(9368650275606913709),
-    ZZp(14580525305425689567),
-    ZZp(10780864898364896983),
-    ZZp(9721098328615019355),
-    ZZp(15364065593517762215),
-    ZZp(4763864503583164384),
-    ZZp(10563573170819229768),
-    ZZp(6741186299002258406),
-    ZZp(18077733937642822225),
-    ZZp(4430445121502519037),
-    ZZp(14314040479386013058),
-    ZZp(13953797439781588009),
-    ZZp(6557150965560513430),
-    ZZp(1167513954858369106),
-    ZZp(2876791200382297561),
-    ZZp(7599320814121585168),
-    ZZp(13829292268752004458),
-    ZZp(933916833277285500),
-    ZZp(18093940199735785015),
-    ZZp(6847326734942453553),
-    ZZp(12208131279390203983),
-    ZZp(13276839705436394498),
-    ZZp(11285934529805485720),
-    ZZp(5454334304728928381),
-    ZZp(18400617802943114059),
-    ZZp(7471334666218284000),
-    ZZp(15624313111984189873),
-    ZZp(17885125740710459782),
-    ZZp(5431329888048710259),
-    ZZp(13980997296418234379),
-    ZZp(16500499960785548476),
-    ZZp(949915101765198146),
-    ZZp(15563719585654938798),
-    ZZp(9340111638866952848),
-    ZZp(4567585533643796167),
-    ZZp(16996816902605567975),
-    ZZp(3831859418600598538),
-    ZZp(15494663015240487553),
-    ZZp(1410741816225685715),
-    ZZp(13915062534942385413),
-    ZZp(6869257620619214771),
-    ZZp(7609671325445041023),
-    ZZp(6395196541212105788),
-    ZZp(12663015781655112039),
-    ZZp(348454236075571828),
-    ZZp(5194508199062612491),
-    ZZp(4343031367054900242),
-    ZZp(17223015041100044693),
-    ZZp(3854327039422012671),
-    ZZp(407131490544681646),
-    ZZp(1252846450294850673),
-    ZZp(7403619023703433126),
-    ZZp(3464209110453915370),
-    ZZp(15357374123094305961),
-    ZZp(13326457165001965297),
-    ZZp(8656911842898267297),
-    ZZp(12387872245961517047),
-    ZZp(3257051924357453168),
-    ZZp(10022771602358805384),
-    ZZp(3888719981383712045),
-    ZZp(9266928814216738639),
-    ZZp(12178528568266941762),
-    ZZp(14377936972942800771),
-    ZZp(9070405906167974707),
-    ZZp(2787633888604574624),
-    ZZp(4662577453671731286),
-    ZZp(16297506867024617615),
-    ZZp(5537138395316575221),
-    ZZp(14268084190867677662),
-    ZZp(18060572826124549526),
-    ZZp(17806708206363006943),
-    ZZp(4915304713411879889),
-    ZZp(8112902984017932783),
-    ZZp(5196080038890400046),
-    ZZp(7461920806126499500),
-    ZZp(14047927260062866303),
-    ZZp(16219442943590316424),
-    ZZp(7864553521786317047),
-    ZZp(10756417665666053188),
-    ZZp(13623629312123395984),
-    ZZp(5026680502563529160),
-    ZZp(5269335520130514341),
-    ZZp(5881200034287784155),
-    ZZp(507729239523085166),
-    ZZp(16394329856731359975),
-    ZZp(2716957033539337124),
-    ZZp(17461891591102204882),
-    ZZp(16755314149914246267),
-    ZZp(3319955881679064638),
-    ZZp(5261196022214946086),
-    ZZp(10156112135473104598),
-    ZZp(4061833916184681328),
-    ZZp(2027430367948789553),
-    ZZp(3288912198900112671),
-    ZZp(10567924242915548809),
-    ZZp(1702953664015424498),
-    ZZp(628335062820441145),
-    ZZp(7576195966046783413),
-    ZZp(12264365047670088220),
-    ZZp(4675152172294031726),
-    ZZp(4355134240768243037),
-    ZZp(9562991663899709301),
-    ZZp(16017794500948713851),
-    ZZp(9408088714474917596),
-    ZZp(3624569064457204788),
-    ZZp(6661145465491141362),
-    ZZp(2921653299012866111),
-    ZZp(1317347987544807993),
-    ZZp(13601755776048950648),
-    ZZp(15052656207385017564),
-    ZZp(18163730587218757674),
-    ZZp(10523294925209374268),
-    ZZp(3515398404647378385),
-    ZZp(4139305663082739404),
-    ZZp(333881208881678913),
-    ZZp(2038196194897607785),
-    ZZp(2518370442677587894),
-    ZZp(10611491292286094580),
-    ZZp(6624876564286556449),
-    ZZp(10399383124016656860),
-    ZZp(9676443167764442759),
-    ZZp(14667701235247330911),
-    ZZp(2671049671053431304),
-    ZZp(16305569559180862280),
-    ZZp(1700219472006118831),
-    ZZp(11104954060630419356),
-    ZZp(16105524375463282950),
-    ZZp(10538783900358463944),
-    ZZp(16580325861318683579),
-    ZZp(9740785242592634586),
-    ZZp(16182636211847971145),
-    ZZp(16395675585099962254),
-    ZZp(4926482322688344567),
-    ZZp(10549808446243053983),
-    ZZp(16969010631273580837),
-    ZZp(5546559058973214893),
-    ZZp(16230757743186940109),
-    ZZp(3258164444733030596),
-    ZZp(17000864156910156119),
-    ZZp(8445603439748857133),
-    ZZp(17527468733358235970),
-    ZZp(17564833992959377901),
-    ZZp(7682787964479681744),
-    ZZp(5563042130903911203),
-    ZZp(14953354770052042820),
-    ZZp(17344731384525126404),
-    ZZp(12083083921920439270),
-    ZZp(5608339699353530613),
-    ZZp(18144150016204100998),
-    ZZp(5750827676747445000),
-    ZZp(13955101614005933268),
-    ZZp(7610848908402120982),
-    ZZp(8946373743928836634),
-    ZZp(9630642590298920985),
-    ZZp(4430951028290592555),
-    ZZp(7973229455999076262),
-    ZZp(16025991643730717737),
-    ZZp(9113133275150391358),
-    ZZp(960348495559960218),
-    ZZp(12224595309747104101),
-    ZZp(11092541380963797513),
-    ZZp(11391463457772932961),
-    ZZp(6122071507593700989),
-    ZZp(7618571488449660447),
-    ZZp(6879704769379158705),
-    ZZp(718853459593430625),
-    ZZp(10967759736458033819),
-];
-
-pub(crate) const INV_NTT_TABLE: [Goldilocks; 1024] = [
-    ZZp(1),
-    ZZp(281474976710656),
-    ZZp(16777216),
-    ZZp(1099511627520),
-    ZZp(4096),
-    ZZp(1152921504606846976),
-    ZZp(68719476736),
-    ZZp(4503599626321920),
-    ZZp(64),
-    ZZp(18014398509481984),
-    ZZp(1073741824),
-    ZZp(70368744161280),
-    ZZp(262144),
-    ZZp(17179869180),
-    ZZp(4398046511104),
-    ZZp(288230376084602880),
-    ZZp(8),
-    ZZp(2251799813685248),
-    ZZp(134217728),
-    ZZp(8796093020160),
-    ZZp(32768),
-    ZZp(9223372036854775808),
-    ZZp(549755813888),
-    ZZp(36028797010575360),
-    ZZp(512),
-    ZZp(144115188075855872),
-    ZZp(8589934592),
-    ZZp(562949953290240),
-    ZZp(2097152),
-    ZZp(137438953440),
-    ZZp(35184372088832),
-    ZZp(2305843008676823040),
-    ZZp(18446741870424883713),
-    ZZp(2199056809472),
-    ZZp(562949953421314),
-    ZZp(562949953421310),
-    ZZp(18437737007600893953),
-    ZZp(9007336691597312),
-    ZZp(2305843009213702144),
-    ZZp(2305843009213685760),
-    ZZp(18446603334073745409),
-    ZZp(140739635806208),
-    ZZp(36028797018964096),
-    ZZp(36028797018963840),
-    ZZp(17870292113338400769),
-    ZZp(576469548262227968),
-    ZZp(34360262648),
-    ZZp(34359214072),
-    ZZp(18446726477496979457),
-    ZZp(17592454475776),
-    ZZp(4503599627370512),
-    ZZp(4503599627370480),
-    ZZp(18374687574905061377),
-    ZZp(72058693532778496),
-    ZZp(4295032831),
-    ZZp(4294901759),
-    ZZp(18445618186687873025),
-    ZZp(1125917086449664),
-    ZZp(288230376151712768),
-    ZZp(288230376151710720),
-    ZZp(13835128420805115905),
-    ZZp(4611756386097823744),
-    ZZp(274882101184),
-    ZZp(274873712576),
-    ZZp(12871361905596103084),
-    ZZp(4404853092538523347),
-    ZZp(8494120110792728509),
-    ZZp(10158338780952714962),
-    ZZp(303814934756242646),
-    ZZp(1362567150328163374),
-    ZZp(1356658891109943458),
-    ZZp(11147770252432840497),
-    ZZp(12110422903908887252),
-    ZZp(5209436881246729393),
-    ZZp(8668109077711679267),
-    ZZp(4497639551463306333),
-    ZZp(997411754984945023),
-    ZZp(13417321343344118652),
-    ZZp(13039192753378044028),
-    ZZp(12481021517947587610),
-    ZZp(10737174897695903067),
-    ZZp(16792080670893602455),
-    ZZp(12612728678098075109),
-    ZZp(7479733969963382412),
-    ZZp(2430519478049941168),
-    ZZp(10900537202625306992),
-    ZZp(10853271128879547664),
-    ZZp(15395185741804386692),
-    ZZp(4649662884198176411),
-    ZZp(4782006911144666502),
-    ZZp(14004640413449681173),
-    ZZp(17534372342291866343),
-    ZZp(7979294039879560184),
-    ZZp(15104850399680027611),
-    ZZp(12079821679951430619),
-    ZZp(7614451796507779275),
-    ZZp(15118306729094611415),
-    ZZp(411429644661718300),
-    ZZp(16105685926854668541),
-    ZZp(1513726443299424847),
-    ZZp(17311265416183374564),
-    ZZp(6562114217670983589),
-    ZZp(3332764170168812040),
-    ZZp(2117504431143841456),
-    ZZp(8340939052496745868),
-    ZZp(7884753188935386879),
-    ZZp(16192975500896648969),
-    ZZp(4644772024090268603),
-    ZZp(1116342470860912836),
-    ZZp(14146940403822094634),
-    ZZp(10382722127243543029),
-    ZZp(6393075107303762937),
-    ZZp(10265989416269385394),
-    ZZp(3291437157293746400),
-    ZZp(18165022998349842402),
-    ZZp(12109811546395398776),
-    ZZp(9362914843564906265),
-    ZZp(15603425602538700070),
-    ZZp(8215369291935911999),
-    ZZp(16940035449150731648),
-    ZZp(11387280211730213981),
-    ZZp(7737793303239342069),
-    ZZp(416595521271101505),
-    ZZp(264688053892980182),
-    ZZp(8930739766887302688),
-    ZZp(2495058814089251146),
-    ZZp(9274800740290006948),
-    ZZp(14251112719600934854),
-    ZZp(11286965527584982002),
-    ZZp(11478179872302871445),
-    ZZp(6434636298004421797),
-    ZZp(14430643036723656017),
-    ZZp(3870163035137971766),
-    ZZp(12320868084200588812),
-    ZZp(14319745502085270124),
-    ZZp(4545880015766881148),
-    ZZp(2942775058270059609),
-    ZZp(15180493120214983961),
-    ZZp(5988353545162139946),
-    ZZp(1223950879584769038),
-    ZZp(7882761346440596851),
-    ZZp(13772306473425142486),
-    ZZp(12573252732142656207),
-    ZZp(14235159967861628657),
-    ZZp(16508747943021518732),
-    ZZp(18038462700764634276),
-    ZZp(14583602245206205734),
-    ZZp(4764679877301742210),
-    ZZp(12514560211689189807),
-    ZZp(6333224326531788891),
-    ZZp(3877499600194655066),
-    ZZp(17920296056720464863),
-    ZZp(5095456396745892551),
-    ZZp(10763480545232365762),
-    ZZp(11013340222467950926),
-    ZZp(9791607036678152304),
-    ZZp(7721858563281021845),
-    ZZp(17944731440328218283),
-    ZZp(8352301510068328051),
-    ZZp(3200815326405523330),
-    ZZp(2454730591976115881),
-    ZZp(4837070530626986986),
-    ZZp(8636802660946538252),
-    ZZp(382428689435778886),
-    ZZp(1100986903222193631),
-    ZZp(837762896875133902),
-    ZZp(13935318169262536835),
-    ZZp(16901410098125234092),
-    ZZp(9528805331154741816),
-    ZZp(14424608849493817968),
-    ZZp(17799792287555502819),
-    ZZp(6028692054475264383),
-    ZZp(15122929597976639421),
-    ZZp(16723337261179401086),
-    ZZp(6416647500902310032),
-    ZZp(11779090253969091270),
-    ZZp(1191100666394342727),
-    ZZp(1803076106186727246),
-    ZZp(13754189079328553053),
-    ZZp(3059429515486231088),
-    ZZp(8807895225777549048),
-    ZZp(6702103175001071216),
-    ZZp(802080937612788754),
-    ZZp(6084072299099782489),
-    ZZp(2443466371579597244),
-    ZZp(4716406379463037818),
-    ZZp(13271129814541932305),
-    ZZp(11336048296972946422),
-    ZZp(10302972367325609442),
-    ZZp(4659489603533118441),
-    ZZp(14439691868389311614),
-    ZZp(1999001684679808555),
-    ZZp(13183111817796039999),
-    ZZp(9770812262840623888),
-    ZZp(6529358023436602414),
-    ZZp(18209529147560584987),
-    ZZp(4406114516091528337),
-    ZZp(10259142034962052999),
-    ZZp(14918307414590806615),
-    ZZp(6045115764991696949),
-    ZZp(13615673215290265491),
-    ZZp(16589430531118646239),
-    ZZp(12050543972821699434),
-    ZZp(3264989070758626945),
-    ZZp(5290167988639048753),
-    ZZp(10949047808060940701),
-    ZZp(13987726993667822989),
-    ZZp(17952527571176918316),
-    ZZp(13231174195295398387),
-    ZZp(4379521825066653820),
-    ZZp(15341376048663650670),
-    ZZp(16549024694582589649),
-    ZZp(16802172059317642375),
-    ZZp(8286160002038086708),
-    ZZp(8665994900238946994),
-    ZZp(11467437981104406950),
-    ZZp(16691665375249202323),
-    ZZp(3588235763047079665),
-    ZZp(4170631435500673867),
-    ZZp(7673168496654431239),
-    ZZp(5427855770283221382),
-    ZZp(13805406186829188324),
-    ZZp(1221351532855077986),
-    ZZp(14493012083513256281),
-    ZZp(13533145890581203496),
-    ZZp(12584286203165206160),
-    ZZp(9014360022444159132),
-    ZZp(11622144959503752099),
-    ZZp(17746383299198219332),
-    ZZp(5033358220335838486),
-    ZZp(10883769032692578351),
-    ZZp(11706055037741049324),
-    ZZp(17571109804126144978),
-    ZZp(12184322017746068437),
-    ZZp(5069975284574070497),
-    ZZp(5947514631656761496),
-    ZZp(10517142914396393667),
-    ZZp(8540276921445729647),
-    ZZp(14031687523985394587),
-    ZZp(11317759638843783896),
-    ZZp(16031446777576706363),
-    ZZp(8440569278248727675),
-    ZZp(16774647971309520093),
-    ZZp(743439328957095187),
-    ZZp(12843857907683664409),
-    ZZp(3373377623857539246),
-    ZZp(13283175983882289524),
-    ZZp(1414719954855472987),
-    ZZp(11441669947107069577),
-    ZZp(5240855794895625891),
-    ZZp(3666314137763395334),
-    ZZp(10686628914424923326),
-    ZZp(10350167037512812052),
-    ZZp(12981983163322084213),
-    ZZp(1573035775395650770),
-    ZZp(16755100833091933884),
-    ZZp(9731239941296990934),
-    ZZp(18014703180248802267),
-    ZZp(3142428394956321713),
-    ZZp(1849525312019627755),
-    ZZp(14191609616972732304),
-    ZZp(1247948640756801632),
-    ZZp(14006089359128464711),
-    ZZp(12490609572415712870),
-    ZZp(14056801952326137183),
-    ZZp(9242871232219117186),
-    ZZp(16647976583058746422),
-    ZZp(7689155552768670394),
-    ZZp(4372556084940235727),
-    ZZp(6081736730776967164),
-    ZZp(10946003652321694096),
-    ZZp(6189017649778497877),
-    ZZp(4062943252717590188),
-    ZZp(14990416956088327889),
-    ZZp(6692683090235989383),
-    ZZp(14796202496157022040),
-    ZZp(2852412519294352506),
-    ZZp(9983589126054413056),
-    ZZp(1368250456540211762),
-    ZZp(7691156232252781355),
-    ZZp(1773951202121591538),
-    ZZp(155993580094600204),
-    ZZp(4056604178567881129),
-    ZZp(6173012213905610189),
-    ZZp(16533704610107301495),
-    ZZp(11760405707386568670),
-    ZZp(13781052940915215484),
-    ZZp(12618653059398814374),
-    ZZp(2585806165873387916),
-    ZZp(9983907413951898936),
-    ZZp(151654034847833439),
-    ZZp(16566926477903622666),
-    ZZp(3030959573425503682),
-    ZZp(16099909724259186520),
-    ZZp(12432372446044483551),
-    ZZp(11006166186397307298),
-    ZZp(17917642060580152056),
-    ZZp(11780776132825664990),
-    ZZp(9705858230261340096),
-    ZZp(8818882629200544327),
-    ZZp(9513972005086392438),
-    ZZp(15823298534785799625),
-    ZZp(2461841562019821461),
-    ZZp(3418361291673462874),
-    ZZp(2239705257572519007),
-    ZZp(6084283033956854204),
-    ZZp(1213232278782667512),
-    ZZp(3408203337326891081),
-    ZZp(5800932517989445135),
-    ZZp(18118813377585986234),
-    ZZp(7225259221282946803),
-    ZZp(14262353213520121100),
-    ZZp(14213927998739126201),
-    ZZp(2012488715532398315),
-    ZZp(3859889564432383484),
-    ZZp(15210828825360601653),
-    ZZp(2324799763032802220),
-    ZZp(15905923861798891074),
-    ZZp(1247988426743987367),
-    ZZp(8900146263973118671),
-    ZZp(2761102078703419584),
-    ZZp(2915568066736270329),
-    ZZp(13138133880250412697),
-    ZZp(13096678655002118611),
-    ZZp(1619999818066427291),
-    ZZp(7123388440527211897),
-    ZZp(4643923023347942555),
-    ZZp(864017031066625188),
-    ZZp(10689836412287594487),
-    ZZp(2128915576975457846),
-    ZZp(10737085212370118163),
-    ZZp(8083950796479296659),
-    ZZp(11446268009178425019),
-    ZZp(13175002527791537704),
-    ZZp(2063168383634974384),
-    ZZp(18403601849434843390),
-    ZZp(3642072560212772351),
-    ZZp(4877800464475578311),
-    ZZp(12871350694930379971),
-    ZZp(12539708892944027283),
-    ZZp(12959998544531418328),
-    ZZp(1646875315973942213),
-    ZZp(257896047954371798),
-    ZZp(6912136248533001504),
-    ZZp(11731715020642418612),
-    ZZp(17031324615803662768),
-    ZZp(12109705421302608020),
-    ZZp(9331374163590620309),
-    ZZp(17783167795769062868),
-    ZZp(13166299875259380027),
-    ZZp(16505347069079795072),
-    ZZp(18101606309576656873),
-    ZZp(82910450496588172),
-    ZZp(15576136931675893974),
-    ZZp(11353340290879379826),
-    ZZp(308931976065701490),
-    ZZp(7559811984562634734),
-    ZZp(11015880108829135486),
-    ZZp(17486776517187278376),
-    ZZp(11006777244921569212),
-    ZZp(5306268831781643008),
-    ZZp(748583878869661002),
-    ZZp(7190759909111520345),
-    ZZp(1324902398790311039),
-    ZZp(4212621207229430630),
-    ZZp(4040052327310466906),
-    ZZp(12349052935110756804),
-    ZZp(3457469037226225370),
-    ZZp(663283603972705376),
-    ZZp(13928631036919645866),
-    ZZp(17039746049376701324),
-    ZZp(2471455808525611920),
-    ZZp(5138263668257324909),
-    ZZp(14340064592974746604),
-    ZZp(10767003651596136761),
-    ZZp(14267241681714216412),
-    ZZp(5556662515423975422),
-    ZZp(5988671030957288016),
-    ZZp(2185847064648409797),
-    ZZp(10599219190322488312),
-    ZZp(15254225588420860719),
-    ZZp(13873674549069150927),
-    ZZp(6558703133813132827),
-    ZZp(9213008228395218639),
-    ZZp(10422344362374670514),
-    ZZp(14099721646927849786),
-    ZZp(18074852694000884838),
-    ZZp(2533469881655645114),
-    ZZp(4156731661302306550),
-    ZZp(14150928548823798726),
-    ZZp(7812684066897416275),
-    ZZp(10022468250525998542),
-    ZZp(2947252693053877340),
-    ZZp(16938470071482338896),
-    ZZp(13092440112352401730),
-    ZZp(14568119870644612728),
-    ZZp(7776409351543438706),
-    ZZp(1768967723408486735),
-    ZZp(1949690407240864933),
-    ZZp(14248669673568039774),
-    ZZp(9591778621339026828),
-    ZZp(2117308758935292362),
-    ZZp(15471613066104988457),
-    ZZp(1821014983830576591),
-    ZZp(14807109221003868079),
-    ZZp(2526963974102883882),
-    ZZp(7161240326935577237),
-    ZZp(6392769726549651052),
-    ZZp(5131277475016434399),
-    ZZp(6380552085956620921),
-    ZZp(12505800551746292235),
-    ZZp(5864494548669395898),
-    ZZp(6871042604103756685),
-    ZZp(14151741787267893880),
-    ZZp(15597523257926919464),
-    ZZp(3308892972056812266),
-    ZZp(12636021555275895127),
-    ZZp(4323157012483891262),
-    ZZp(12150643879775871958),
-    ZZp(7354754569106358544),
-    ZZp(14027175702157419787),
-    ZZp(17223560565432245313),
-    ZZp(18168576350837626231),
-    ZZp(1541649705628400031),
-    ZZp(15495384552830162325),
-    ZZp(18427631827164860274),
-    ZZp(2877957390243263830),
-    ZZp(9535690687442338791),
-    ZZp(12295529606174818960),
-    ZZp(13949976092203225093),
-    ZZp(644010080489266561),
-    ZZp(6431860813144680379),
-    ZZp(8854452095134239411),
-    ZZp(16138512030456545775),
-    ZZp(4971430691134054059),
-    ZZp(3497804344607115389),
-    ZZp(1536941200771852370),
-    ZZp(8661276037555872257),
-    ZZp(16221402320798919601),
-    ZZp(12333197645027200248),
-    ZZp(13282612006153792674),
-    ZZp(18293846131416791945),
-    ZZp(4576915052531526319),
-    ZZp(2498549221880373044),
-    ZZp(6130516502325630075),
-    ZZp(919344321138294818),
-    ZZp(5152080643914132488),
-    ZZp(14561398366328274390),
-    ZZp(7344548176412377620),
-    ZZp(10789290780202129222),
-    ZZp(12081111149863113453),
-    ZZp(14808420073763128510),
-    ZZp(15076497439326288290),
-    ZZp(12982989459991844517),
-    ZZp(10063675669397554566),
-    ZZp(2394121898621129512),
-    ZZp(8882481555027559655),
-    ZZp(7985079364596650331),
-    ZZp(16874606745241303831),
-    ZZp(6954937180696424269),
-    ZZp(5665144507324065868),
-    ZZp(807842315821754643),
-    ZZp(16885944481347625310),
-    ZZp(5649848956435614200),
-    ZZp(3416153203055267997),
-    ZZp(12527349963958696492),
-    ZZp(4415168851831986019),
-    ZZp(7786896173617522154),
-    ZZp(9931515098122800394),
-    ZZp(11630195332861834531),
-    ZZp(6722429077522099244),
-    ZZp(706231119554451775),
-    ZZp(15719620231976724277),
-    ZZp(8540402708529449685),
-    ZZp(5869645476028340401),
-    ZZp(299265237327641189),
-    ZZp(8427667919763358302),
-    ZZp(6462738526574037144),
-    ZZp(5960347364878912233),
-    ZZp(8305303512655744958),
-    ZZp(12992126221614554207),
-    ZZp(16885574308423315284),
-    ZZp(17820933843814429363),
-    ZZp(6889485207579503204),
-    ZZp(15339107541552850108),
-    ZZp(6468851066622783835),
-    ZZp(778741590392512651),
-    ZZp(14259728110745696775),
-    ZZp(1392595059675174803),
-    ZZp(10765599713046287558),
-    ZZp(15288377769833835651),
-    ZZp(16651939688552765673),
-    ZZp(4025446980409437899),
-    ZZp(8178098736737310378),
-    ZZp(12945973646291641022),
-    ZZp(8732139686409961871),
-    ZZp(11703289425843512051),
-    ZZp(5957385981484432025),
-    ZZp(13440262264613344657),
-    ZZp(18222393521806856990),
-    ZZp(12032395915935294938),
-    ZZp(14857320394153102038),
-    ZZp(6229932723140101208),
-    ZZp(3397360469478068274),
-    ZZp(11140760477401398424),
-    ZZp(12337821426711963180),
-    ZZp(11626557742183179282),
-    ZZp(4088309022520035137),
-    ZZp(13756831773860918871),
-    ZZp(10084557685654730061),
-    ZZp(11334068823260206571),
-    ZZp(14516885283035942005),
-    ZZp(7478984332956550502),
-    ZZp(17727890609821153696),
-    ZZp(11567039300035425616),
-    ZZp(10828172580964923874),
-    ZZp(12324672561820883332),
-    ZZp(7055280611641651360),
-    ZZp(7354202688450786808),
-    ZZp(6222148759667480220),
-    ZZp(17486395573854624103),
-    ZZp(9333610794264192963),
-    ZZp(2420752425683866584),
-    ZZp(10473514613415508059),
-    ZZp(14015793041123991766),
-    ZZp(8816101479115663336),
-    ZZp(9500370325485747687),
-    ZZp(10835895161012463339),
-    ZZp(4491642455408651053),
-    ZZp(12695916392667139321),
-    ZZp(302594053210483323),
-    ZZp(12838404370061053708),
-    ZZp(6363660147494145051),
-    ZZp(1102012684889457917),
-    ZZp(3493389299362541501),
-    ZZp(12883701938510673118),
-    ZZp(10763956104934902577),
-    ZZp(881910076455206420),
-    ZZp(919275336056348351),
-    ZZp(10001140629665727188),
-    ZZp(1445879912504428202),
-    ZZp(15188579624681553725),
-    ZZp(2215986326227644212),
-    ZZp(12900185010441369428),
-    ZZp(1477733438141003484),
-    ZZp(7896935623171530338),
-    ZZp(13520261746726239754),
-    ZZp(2051068484314622067),
-    ZZp(2264107857566613176),
-    ZZp(8705958826821949735),
-    ZZp(1866418208095900742),
-    ZZp(7907960169056120377),
-    ZZp(2341219693951301371),
-    ZZp(7341790008784164965),
-    ZZp(16746524597408465490),
-    ZZp(2141174510233722041),
-    ZZp(15775694398361153017),
-    ZZp(3779042834167253410),
-    ZZp(8770300901650141562),
-    ZZp(8047360945397927461),
-    ZZp(11821867505128027872),
-    ZZp(7835252777128489741),
-    ZZp(15928373626736996427),
-    ZZp(16408547874516976536),
-    ZZp(18112862860532905408),
-    ZZp(14307438406331844917),
-    ZZp(14931345664767205936),
-    ZZp(7923449144205210053),
-    ZZp(283013482195826647),
-    ZZp(3394087862029566757),
-    ZZp(4844988293365633673),
-    ZZp(17129396081869776328),
-    ZZp(15525090770401718210),
-    ZZp(11785598603923442959),
-    ZZp(14822175004957379533),
-    ZZp(9038655354939666725),
-    ZZp(2428949568465870470),
-    ZZp(8883752405514875020),
-    ZZp(14091609828646341284),
-    ZZp(13771591897120552595),
-    ZZp(6182379021744496101),
-    ZZp(10870548103367800908),
-    ZZp(17818409006594143176),
-    ZZp(16743790405399159823),
-    ZZp(7878819826499035512),
-    ZZp(15157831870514471650),
-    ZZp(16419313701465794768),
-    ZZp(14384910153229902993),
-    ZZp(8290631933941479723),
-    ZZp(13185548047199638235),
-    ZZp(15126788187735519683),
-    ZZp(1691429919500338054),
-    ZZp(984852478312379439),
-    ZZp(15729787035875247197),
-    ZZp(2052414212683224346),
-    ZZp(17939014829891499155),
-    ZZp(12565544035126800166),
-    ZZp(13177408549284069980),
-    ZZp(13420063566851055161),
-    ZZp(4823114757291188337),
-    ZZp(7690326403748531133),
-    ZZp(10582190547628267274),
-    ZZp(2227301125824267897),
-    ZZp(4398816809351718018),
-    ZZp(10984823263288084821),
-    ZZp(13250664030524184275),
-    ZZp(10333841085396651538),
-    ZZp(13531439356002704432),
-    ZZp(640035863051577378),
-    ZZp(386171243290034795),
-    ZZp(4178659878546906659),
-    ZZp(12909605674098009100),
-    ZZp(2149237202389966706),
-    ZZp(13784166615742853035),
-    ZZp(15659110180810009697),
-    ZZp(9376338163246609614),
-    ZZp(4068807096471783550),
-    ZZp(6268215501147642559),
-    ZZp(9179815255197845682),
-    ZZp(14558024088030872276),
-    ZZp(8423972467055778937),
-    ZZp(15189692145057131153),
-    ZZp(6058871823453067274),
-    ZZp(9789832226516317024),
-    ZZp(5120286904412619024),
-    ZZp(3089369946320278360),
-    ZZp(14982534958960668951),
-    ZZp(11043125045711151195),
-    ZZp(17193897619119733648),
-    ZZp(18039612578869902675),
-    ZZp(14592417029992571650),
-    ZZp(1223729028314539628),
-    ZZp(14103712702359684079),
-    ZZp(13252235870351971830),
-    ZZp(18098289833339012493),
-    ZZp(5783728287759472282),
-    ZZp(12051547528202478533),
-    ZZp(10837072743969543298),
-    ZZp(11577486448795369550),
-    ZZp(4531681534472198908),
-    ZZp(17036002253188898606),
-    ZZp(2952081054174096768),
-    ZZp(14614884650813985783),
-    ZZp(1449927166809016346),
-    ZZp(13879158535770788154),
-    ZZp(9106632430547631473),
-    ZZp(2883024483759645523),
-    ZZp(17496828967649386175),
-    ZZp(1946244108629035845),
-    ZZp(4465746772996349942),
-    ZZp(13015414181365874062),
-    ZZp(561618328704124539),
-    ZZp(2822430957430394448),
-    ZZp(10975409403196300321),
-    ZZp(46126266471470262),
-    ZZp(12992409764685655940),
-    ZZp(7160809539609098601),
-    ZZp(5169904363978189823),
-    ZZp(6238612790024380338),
-    ZZp(11599417334472130768),
-    ZZp(352803869678799306),
-    ZZp(17512827236137298821),
-    ZZp(4617451800662579863),
-    ZZp(10847423255292999153),
-    ZZp(15569952869032286760),
-    ZZp(17279230114556215215),
-    ZZp(11889593103854070891),
-    ZZp(4492946629632996312),
-    ZZp(4132703590028571263),
-    ZZp(14016298947912065284),
-    ZZp(369010131771762096),
-    ZZp(11705557770412325915),
-    ZZp(7883170898595354553),
-    ZZp(13682879565831419937),
-    ZZp(3082678475896822106),
-    ZZp(8725645740799564966),
-    ZZp(7665879171049687338),
-    ZZp(3866218763988894754),
-    ZZp(9078093793807670612),
-    ZZp(8901691858968270959),
-    ZZp(6460847635908914725),
-    ZZp(8707320950725412881),
-    ZZp(12823981763250771574),
-    ZZp(5039005328734628194),
-    ZZp(11000921142400797286),
-    ZZp(7630327992899668083),
-    ZZp(9148936651838805217),
-    ZZp(16305956891531811746),
-    ZZp(7725134980519083461),
-    ZZp(17229316179578437891),
-    ZZp(6214683737759992527),
-    ZZp(14464933718152766765),
-    ZZp(5986801160153745741),
-    ZZp(12483006042496573711),
-    ZZp(17284518142217611933),
-    ZZp(15873302663502414709),
-    ZZp(14793292948442149158),
-    ZZp(14318335397559550085),
-    ZZp(10358133758933250987),
-    ZZp(3418554491047856910),
-    ZZp(14220392861548041004),
-    ZZp(5702391734953591701),
-    ZZp(17851261006466688773),
-    ZZp(1320446646352403721),
-    ZZp(8291238217344211485),
-    ZZp(4564350881512077310),
-    ZZp(4271666716646109083),
-    ZZp(17427273095646849068),
-    ZZp(455906449640507599),
-    ZZp(9029468356494744587),
-    ZZp(9233493577436867660),
-    ZZp(11657795204467044579),
-    ZZp(14130411966421174052),
-    ZZp(15417295375554183025),
-    ZZp(15132252893546800818),
-    ZZp(8540833956523281092),
-    ZZp(10731268707577902015),
-    ZZp(6036908663811539617),
-    ZZp(647778734692831968),
-    ZZp(8229130309307480216),
-    ZZp(10989673530509938917),
-    ZZp(18068062982682034159),
-    ZZp(15726589663754288343),
-    ZZp(10290976279272702297),
-    ZZp(3647251597124060792),
-    ZZp(16895514643714203733),
-    ZZp(80972341836603996),
-    ZZp(1028641288663435027),
-    ZZp(2362831314881886490),
-    ZZp(12657898587945958274),
-    ZZp(10377558731886900618),
-    ZZp(12986439443942495773),
-    ZZp(12063173382964878836),
-    ZZp(11401781171663148294),
-    ZZp(5182229877542655744),
-    ZZp(10492810266216088765),
-    ZZp(10582275380827688672),
-    ZZp(6504391485756747660),
-    ZZp(7264434128297993269),
-    ZZp(10992969397750315971),
-    ZZp(13598140815354230483),
-    ZZp(4889089424978655836),
-    ZZp(524005542855920051),
-    ZZp(17147123813708473976),
-    ZZp(13182837874047039452),
-    ZZp(10452685561310995178),
-    ZZp(3755182475706961191),
-    ZZp(2573766818266017946),
-    ZZp(3284040920185287825),
-    ZZp(17753818088000624368),
-    ZZp(15089610673364298943),
-    ZZp(9058023981881859525),
-    ZZp(10871226768963172092),
-    ZZp(15141643747224812638),
-    ZZp(2775240818140193189),
-    ZZp(14156778904344190484),
-    ZZp(16551406175760922259),
-    ZZp(2219227261000078046),
-    ZZp(4192044342847360408),
-    ZZp(8049782023765701561),
-    ZZp(13228982645303394011),
-    ZZp(9834508212829624140),
-    ZZp(11594715736241105207),
-    ZZp(2143390476713559247),
-    ZZp(7825583292067718279),
-    ZZp(12903336218102904697),
-    ZZp(10036420970426885618),
-    ZZp(17123959646811123237),
-    ZZp(580180600093873153),
-    ZZp(5417705191158933960),
-    ZZp(3356932467041235657),
-    ZZp(3104107107372552306),
-    ZZp(15236497099437641600),
-    ZZp(17934091550663146318),
-    ZZp(7171053287035931927),
-    ZZp(4616047971325648207),
-    ZZp(238070267178713150),
-    ZZp(14691738984709255662),
-    ZZp(11929493127078654517),
-    ZZp(14195414177697504374),
-    ZZp(15905122754450677708),
-    ZZp(4083726938737136450),
-    ZZp(16225552704349619624),
-    ZZp(279165054208136112),
-    ZZp(4641444800750985224),
-    ZZp(6448153390442303038),
-    ZZp(8408715666915300935),
-    ZZp(6386112789565834127),
-    ZZp(11211512379013626874),
-    ZZp(14345523919403080297),
-    ZZp(2028194088043702453),
-    ZZp(34895631776017014),
-    ZZp(1904562137429705200),
-    ZZp(6853447461186539370),
-    ZZp(3202224669556314531),
-    ZZp(2882849005092529066),
-    ZZp(16560517619117915738),
-    ZZp(14223071440482507279),
-    ZZp(677213148894866745),
-    ZZp(2233320433665088896),
-    ZZp(505650719337366702),
-    ZZp(12922079148827575926),
-    ZZp(11995771582505614226),
-    ZZp(9675049182130121614),
-    ZZp(5110010631420567440),
-    ZZp(5127458447308575947),
-    ZZp(11000945091957822873),
-    ZZp(5395188902451009436),
-    ZZp(13914901968176884607),
-    ZZp(15356326470723149140),
-    ZZp(11412874434361353303),
-    ZZp(10460593365646500703),
-    ZZp(13446031230868382703),
-    ZZp(14562691447700927151),
-    ZZp(3084211247546459674),
-    ZZp(13250696507402086126),
-    ZZp(4045205754698933616),
-    ZZp(11142912843547685803),
-    ZZp(3732452312971992203),
-    ZZp(3613417179382635628),
-    ZZp(3986596912535370878),
-    ZZp(4126179439639438934),
-    ZZp(14220584458004245700),
-    ZZp(6268023080778906846),
-    ZZp(638751328927570930),
-    ZZp(12170147349297687194),
-    ZZp(17516019197232489140),
-    ZZp(9897770647513668340),
-    ZZp(15334529499874140019),
-    ZZp(5821067165119911282),
-    ZZp(6226945910957093071),
-    ZZp(13771851712143767403),
-    ZZp(4755877603291638172),
-    ZZp(8005011374273215121),
-    ZZp(8918657157927141307),
-    ZZp(8010104650731677821),
-    ZZp(312925780748909536),
-    ZZp(8662377673372797199),
-    ZZp(6266461428693837892),
-    ZZp(11077693977821432078),
-    ZZp(9228261500031493872),
-    ZZp(14258638079291991077),
-    ZZp(17391736024899514018),
-    ZZp(14584607772633603877),
-    ZZp(1580505898515625983),
-    ZZp(989849013421491106),
-    ZZp(13671905978699354347),
-    ZZp(7996139942817448794),
-    ZZp(1153532687503936734),
-    ZZp(8699858785941968005),
-    ZZp(16009025055173377493),
-    ZZp(8740604997609669605),
-    ZZp(2503406245991276288),
-    ZZp(13958789178738624629),
-    ZZp(13238203290721534494),
-    ZZp(14834575544913119340),
-    ZZp(39115722593613692),
-    ZZp(3388640217848422690),
-    ZZp(10006679713294021897),
-    ZZp(5996397764581325090),
-    ZZp(12644047188125007864),
-    ZZp(7918792107371928848),
-    ZZp(17141527482521913171),
-    ZZp(8628887334295837389),
-    ZZp(1817105014390926972),
-    ZZp(15410779547903053935),
-    ZZp(7075033885715122265),
-    ZZp(6498267541963153898),
-    ZZp(8824278971159395949),
-    ZZp(16241566743615955619),
-    ZZp(17950606908243413470),
-    ZZp(16698903785247775326),
-    ZZp(5614256504531820282),
-    ZZp(8612455386822482827),
-    ZZp(10080311019817801256),
-    ZZp(10060753158520994410),
-    ZZp(11351532071763811106),
-    ZZp(6442603704204437640),
-    ZZp(5140709823874234178),
-    ZZp(17265430299226314567),
-    ZZp(14536840115127415776),
-    ZZp(12605771966736925554),
-    ZZp(1260038877477225157),
-    ZZp(15092652196876062542),
-    ZZp(15253999561031414629),
-    ZZp(805325463025554705),
-    ZZp(14477646780045217513),
-    ZZp(4464021796080112361),
-    ZZp(8020563897425393614),
-    ZZp(13559410886336109653),
-    ZZp(6855511880884072764),
-    ZZp(6699048990509617996),
-    ZZp(17025280296452151564),
-    ZZp(14647341494806332478),
-    ZZp(4232190452164704782),
-    ZZp(8996233907908426289),
-    ZZp(9728540708479523761),
-    ZZp(1435513418831659300),
-    ZZp(4287105872426499400),
-    ZZp(15662306159156270089),
-    ZZp(3135551996627191696),
-    ZZp(13798349460638678722),
-    ZZp(17132043445671853129),
-    ZZp(13476898549572600427),
-    ZZp(13884051052008238111),
-    ZZp(18085882527567857916),
-    ZZp(16120358863491781106),
-    ZZp(6263414437613732362),
-    ZZp(16207887089994425334),
-    ZZp(16097394218389975121),
-    ZZp(8092880427538125317),
-    ZZp(13971279979575548562),
-    ZZp(4041349390177852804),
-    ZZp(11484107350653274400),
-    ZZp(15850102909997410879),
-    ZZp(14617984856762654786),
-    ZZp(6637671903602949247),
-    ZZp(18153075338036508171),
-    ZZp(7929139079472734785),
-    ZZp(15581468049507881811),
-    ZZp(391943999578398962),
-    ZZp(15559851734640773081),
-    ZZp(18282406491446742922),
-    ZZp(13213827362080690254),
-    ZZp(535888234053312425),
-    ZZp(18098689330632295042),
-    ZZp(9402811212061249573),
-    ZZp(1089775420116882570),
-    ZZp(14143087565369627264),
-    ZZp(3005335924336370336),
-    ZZp(3881364185207781801),
-    ZZp(1860689490118855399),
-    ZZp(7310289792198505404),
-    ZZp(5877651782245154149),
-    ZZp(15421058845117156515),
-    ZZp(2878850858608389731),
-    ZZp(1267144782341513167),
-    ZZp(7874058463381858294),
-    ZZp(8599634950908439091),
-    ZZp(8403662951119239610),
-    ZZp(6689944965339737831),
-    ZZp(7234832675398179116),
-    ZZp(9270330408525047947),
-    ZZp(18225758326205683895),
-    ZZp(2464236106469512186),
-    ZZp(5595943325276378367),
-    ZZp(12604169412247670087),
-    ZZp(14885515920950843192),
-    ZZp(3142086129344290269),
-    ZZp(10127726119132064550),
-    ZZp(12688006344449746194),
-    ZZp(4584062799452533527),
-    ZZp(10137158258732105336),
-    ZZp(7652235498811113389),
-    ZZp(13456847399023759765),
-    ZZp(11889071400710163917),
-    ZZp(16626071583888734006),
-    ZZp(2538429194941679965),
-    ZZp(375666990542046292),
-    ZZp(16678858123743380913),
-    ZZp(16570564489772914910),
-    ZZp(5870966158393556332),
-    ZZp(11295753890426390920),
-    ZZp(11374083960174708260),
-    ZZp(7460718733603754401),
-    ZZp(11369862332803365609),
-    ZZp(2973809094719731252),
-    ZZp(10219125603779622435),
-    ZZp(9051715388835247943),
-    ZZp(6806952748895918828),
-    ZZp(3505230280120230361),
-    ZZp(8518354744012540121),
-    ZZp(16317397215275673639),
-    ZZp(8248170592246610457),
-    ZZp(5856341367916956918),
-    ZZp(8387996212385384605),
-    ZZp(3437307432281229033),
-    ZZp(10074241128319282014),
-    ZZp(16579054845752790076),
-    ZZp(17205695403739328796),
-    ZZp(4345517660586282245),
-    ZZp(17171922384768587588),
-    ZZp(5343728688343265695),
-    ZZp(7966028552578642196),
-    ZZp(17073490902438230581),
-    ZZp(17562133852338181982),
-    ZZp(9595098171547258567),
-    ZZp(12806605743856568005),
-    ZZp(1411969236303298865),
-    ZZp(10645132529729130693),
-    ZZp(9957242804506486702),
-    ZZp(11763737490839323877),
-    ZZp(18290083929917949641),
-    ZZp(8446187562373029718),
-    ZZp(7989573157503773842),
-    ZZp(15494291476070451486),
-    ZZp(3956111051294801955),
-    ZZp(7939125327584123053),
-    ZZp(767673993985071378),
-    ZZp(7818287198399222416),
-    ZZp(8420495141629964801),
-    ZZp(5600425978850956643),
-    ZZp(13270592206047749221),
-    ZZp(13957218789535926091),
-    ZZp(13383434380477728947),
-    ZZp(10041931091190098725),
-    ZZp(12237647476215399550),
-    ZZp(2308290823356457957),
-    ZZp(17193462953441506881),
-    ZZp(12229268290740484781),
-    ZZp(8576353051786437773),
-    ZZp(13273867392076105962),
-    ZZp(13202144340943831319),
-    ZZp(8172770412429231461),
-    ZZp(6141391951880571024),
-    ZZp(7206065378950026365),
-    ZZp(12023728924795965445),
-    ZZp(7909919691978484502),
-    ZZp(13931017301309072163),
-    ZZp(977285899799902802),
-    ZZp(14833754696748909971),
-    ZZp(6548472451862452516),
-    ZZp(5667459462650274795),
-    ZZp(19582517437079335),
-];
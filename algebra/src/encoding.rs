@@ -0,0 +1,235 @@
+//! Kyber-style encoding of polynomials, following Section 4.2.1 of FIPS 203:
+//! the lossy compression `Compress_d`/`Decompress_d` of coefficients, and the
+//! packing `ByteEncode_d`/`ByteDecode_d` of d-bit integers into bytes.
+
+use crate::serialize::{packed_len, read_bits, write_bits, SerializationError, Validate};
+use crate::{ConfigZZpX, PrimeField, ZZp, ZZpX};
+
+/// Bit length of the modulus of `F`
+fn modulus_bits<F: PrimeField>() -> u32 {
+    u64::BITS - F::modulus().leading_zeros()
+}
+
+/// `Compress_d(x) = round(2^d / q * x) mod 2^d`, for `d` less than the bit length of `q`.
+///
+/// The division is by the constant modulus, so for `2^d * q < 2^64` the compiler
+/// turns it into a multiplication and the function runs in constant time.
+pub fn compress<F: PrimeField>(x: &F, d: u32) -> u64 {
+    assert!(d < modulus_bits::<F>(), "d = {} is too large", d);
+    let q = F::modulus();
+    let x: u64 = (*x).into();
+    // q is odd, so (y + (q - 1) / 2) / q rounds y / q to the nearest integer
+    let res = if d + modulus_bits::<F>() <= u64::BITS {
+        ((x << d) + q / 2) / q
+    } else {
+        ((((x as u128) << d) + (q / 2) as u128) / q as u128) as u64
+    };
+    res & ((1 << d) - 1)
+}
+
+/// `Decompress_d(y) = round(q / 2^d * y)`, for `y` in `[0, 2^d)` and `d` less than
+/// the bit length of `q`.
+pub fn decompress<F: PrimeField>(y: u64, d: u32) -> F {
+    assert!(d < modulus_bits::<F>(), "d = {} is too large", d);
+    assert!(y >> d == 0, "y = {} is not a {}-bit integer", y, d);
+    let q = F::modulus() as u128;
+    // ties are rounded up
+    let res = (q * y as u128 + (1 << (d - 1))) >> d;
+    (res as u64).into()
+}
+
+impl<C: ConfigZZpX> ZZpX<C> {
+    /// Apply `Compress_d` to each coefficient.
+    /// The results, in `[0, 2^d)`, are stored as field elements.
+    pub fn compress(&self, d: u32) -> Self {
+        let coeffs = self.coeffs.iter().map(|x| compress(x, d).into()).collect();
+        Self { coeffs }
+    }
+
+    /// Apply `Decompress_d` to each coefficient, which must be in `[0, 2^d)`.
+    pub fn decompress(&self, d: u32) -> Self {
+        let coeffs = self
+            .coeffs
+            .iter()
+            .map(|x| decompress(u64::from(*x), d))
+            .collect();
+        Self { coeffs }
+    }
+
+    /// `ByteEncode_d`: pack the coefficients, which must be in `[0, 2^d)`,
+    /// as d-bit integers into a little-endian bit string.
+    /// `d` is at most the bit length of the modulus.
+    pub fn byte_encode(&self, d: u32) -> Vec<u8> {
+        assert!(
+            d <= modulus_bits::<ZZp<C::BaseConfig>>(),
+            "d = {} is too large",
+            d
+        );
+        let values: Vec<u64> = self.coeffs.iter().map(|x| u64::from(*x)).collect();
+        assert!(
+            values.iter().all(|x| x >> d == 0),
+            "coefficients are not {}-bit integers",
+            d
+        );
+
+        let mut bytes = Vec::with_capacity(packed_len(C::DIM, d));
+        // writing into a vector cannot fail
+        write_bits(&mut bytes, values, d).unwrap();
+        bytes
+    }
+
+    /// `ByteDecode_d`: unpack d-bit integers into coefficients.
+    /// For `d` equal to the bit length of the modulus, the integers are reduced modulo `q`.
+    ///
+    /// Returns an error if the number of bytes is not `DIM * d / 8`.
+    pub fn byte_decode(bytes: &[u8], d: u32) -> Result<Self, SerializationError> {
+        assert!(
+            d <= modulus_bits::<ZZp<C::BaseConfig>>(),
+            "d = {} is too large",
+            d
+        );
+        if bytes.len() != packed_len(C::DIM, d) {
+            return Err(SerializationError::InvalidData);
+        }
+        let q = ZZp::<C::BaseConfig>::modulus();
+        let coeffs = read_bits(bytes, C::DIM, d, Validate::No)?
+            .into_iter()
+            .map(|x| (x % q).into())
+            .collect();
+        Ok(Self { coeffs })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use sha2::{Digest, Sha256};
+
+    use super::{compress, decompress};
+    use crate::tests::test_rng;
+    use crate::{Poly3329_256, Polynomial, PrimeField, F3329};
+
+    #[test]
+    fn test_compress() {
+        let c = |x: u64, d: u32| compress(&F3329::from(x), d);
+        let dc = |y: u64, d: u32| u64::from(decompress::<F3329>(y, d));
+
+        // values from the definitions in FIPS 203, with rounding half up
+        let xs = [0, 832, 833, 1664, 2496, 2497, 3328];
+        assert_eq!(xs.map(|x| c(x, 1)), [0, 0, 1, 1, 1, 0, 0]);
+        assert_eq!([0, 1].map(|y| dc(y, 1)), [0, 1665]);
+        assert_eq!([0, 104, 105, 1000, 3328].map(|x| c(x, 4)), [0, 0, 1, 5, 0]);
+        assert_eq!([0, 1, 15].map(|y| dc(y, 4)), [0, 208, 3121]);
+        assert_eq!([0, 1, 2, 1664, 3328].map(|x| c(x, 10)), [0, 0, 1, 512, 0]);
+        assert_eq!([0, 1, 1023].map(|y| dc(y, 10)), [0, 3, 3326]);
+        assert_eq!(
+            [0, 1, 2, 1664, 3328].map(|x| c(x, 11)),
+            [0, 1, 1, 1024, 2047]
+        );
+        assert_eq!([0, 1, 2047].map(|y| dc(y, 11)), [0, 2, 3327]);
+
+        for d in 1..12 {
+            // Compress_d(Decompress_d(y)) = y
+            for y in 0..1 << d {
+                assert_eq!(c(dc(y, d), d), y);
+            }
+            // |Decompress_d(Compress_d(x)) - x mod+- q| <= round(q / 2^(d+1))
+            let bound = (3329 + (1 << d)) >> (d + 1);
+            for x in 0..3329 {
+                let error = F3329::from(dc(c(x, d), d)) - F3329::from(x);
+                assert!(error.lift().unsigned_abs() <= bound);
+            }
+        }
+    }
+
+    /// `f_i = 17 i + 5 mod q`
+    fn sample_poly() -> Poly3329_256 {
+        let coeffs: Vec<F3329> = (0..256).map(|i| F3329::from((17 * i + 5) % 3329)).collect();
+        Poly3329_256::from_coefficients_vec_unchecked(coeffs)
+    }
+
+    fn sha256(bytes: &[u8]) -> String {
+        Sha256::digest(bytes)
+            .iter()
+            .map(|b| format!("{:02x}", b))
+            .collect()
+    }
+
+    #[test]
+    fn test_byte_encode_regression_digests() {
+        let poly = sample_poly();
+
+        // Regression digests, not NIST known-answer values: SHA-256 of
+        // ByteEncode_d(Compress_d(f)), computed from the definitions in FIPS 203
+        // (Eq. 4.7 and Algorithm 5) by an independent Python 3 script
+        //
+        //     import hashlib
+        //     q = 3329
+        //     f = [(17 * i + 5) % q for i in range(256)]
+        //     def compress(x, d): return ((x << d) + q // 2) // q % (1 << d)
+        //     def byte_encode(a, d):
+        //         return sum(x << (d * i) for i, x in enumerate(a)).to_bytes(32 * d, "little")
+        //     for d in [1, 4, 5, 10, 11]:
+        //         print(d, hashlib.sha256(byte_encode([compress(x, d) for x in f], d)).hexdigest())
+        //     print(12, hashlib.sha256(byte_encode(f, 12)).hexdigest())
+        let expected = [
+            (
+                1,
+                "3ea71c1ea7468ce7a0667ecfe75b5c60872e2520cf50d096320f01e87a426b9d",
+            ),
+            (
+                4,
+                "a5f55d1adccfe93709101d1c66abe9276ce88280c3d51ba264e05b0c9b6cdbf9",
+            ),
+            (
+                5,
+                "6ae0fed3daa149bbc4eddb81f806ec90fa4746ae2c2c28d540c14898d1c5b646",
+            ),
+            (
+                10,
+                "d51015530fce8c4ae21da0e78b4602690f08d8bd8ecaa66ebf34acb42a49f995",
+            ),
+            (
+                11,
+                "d106c24f9f64095246542a734cbacead3a2c56e09056f4629e27b88484647a0b",
+            ),
+        ];
+        for (d, hash) in expected {
+            let bytes = poly.compress(d).byte_encode(d);
+            assert_eq!(sha256(&bytes), hash);
+        }
+        assert_eq!(
+            sha256(&poly.byte_encode(12)),
+            "5650d496f5fa5d32e422d9a61d3e00f2069df3fb6050377aa3b8cf3c205f69bf"
+        );
+    }
+
+    #[test]
+    fn test_byte_encode() {
+        let poly = sample_poly();
+        for d in [1, 4, 5, 10, 11] {
+            let compressed = poly.compress(d);
+            let bytes = compressed.byte_encode(d);
+            assert_eq!(bytes.len(), 32 * d as usize);
+            assert_eq!(Poly3329_256::byte_decode(&bytes, d).unwrap(), compressed);
+        }
+
+        // ByteEncode_12 of the coefficients themselves
+        let bytes = poly.byte_encode(12);
+        assert_eq!(bytes[..6], [0x05, 0x60, 0x01, 0x27, 0x80, 0x03]);
+        assert_eq!(Poly3329_256::byte_decode(&bytes, 12).unwrap(), poly);
+
+        // ByteDecode_12 reduces modulo q
+        let bytes = [0xff; 384];
+        let decoded = Poly3329_256::byte_decode(&bytes, 12).unwrap();
+        assert!(decoded.coefficients().all(|x| u64::from(*x) == 4095 % 3329));
+        assert!(Poly3329_256::byte_decode(&bytes[1..], 12).is_err());
+
+        // random round trips
+        let mut rng = test_rng();
+        for _ in 0..100 {
+            let poly = Poly3329_256::random(&mut rng, None);
+            let bytes = poly.byte_encode(12);
+            assert_eq!(Poly3329_256::byte_decode(&bytes, 12).unwrap(), poly);
+        }
+    }
+}
@@ -0,0 +1,159 @@
+//! Deterministic expansion of public matrices from a seed, so that a seed can be
+//! shipped instead of the matrix.
+//!
+//! The entry `(i, j)` is sampled with rejection from `SHAKE128(seed || j || i)`,
+//! directly in the NTT domain. This matches the `ExpandA` of FIPS 203 (ML-KEM) and
+//! FIPS 204 (ML-DSA), which both put the column index first.
+
+use sha3::digest::{ExtendableOutput, Update, XofReader};
+use sha3::{Shake128, Shake128Reader};
+
+use crate::{ConfigZZVec, PrimeField, UniformParser, ZZVec, ZZp};
+
+/// Length of a seed in bytes
+pub const SEED_LEN: usize = 32;
+
+/// Rate of SHAKE128 in bytes
+const SHAKE128_RATE: usize = 168;
+
+/// An endless stream of bytes squeezed from SHAKE128, one block at a time
+struct Shake128Bytes {
+    reader: Shake128Reader,
+    block: [u8; SHAKE128_RATE],
+    position: usize,
+}
+
+impl Shake128Bytes {
+    fn new(input: &[u8]) -> Self {
+        let mut hasher = Shake128::default();
+        hasher.update(input);
+        Self {
+            reader: hasher.finalize_xof(),
+            block: [0; SHAKE128_RATE],
+            position: SHAKE128_RATE,
+        }
+    }
+}
+
+impl Iterator for Shake128Bytes {
+    type Item = u8;
+
+    fn next(&mut self) -> Option<u8> {
+        if self.position == SHAKE128_RATE {
+            self.reader.read(&mut self.block);
+            self.position = 0;
+        }
+        self.position += 1;
+        Some(self.block[self.position - 1])
+    }
+}
+
+/// `ExpandA(seed, i, j)`: the entry in row `i` and column `j` of the matrix expanded
+/// from `seed`, as a vector in the NTT domain.
+///
+/// The XOF output is cut into chunks of `k` bits rounded up to a multiple of 4,
+/// where `k` is the bit length of the modulus, and the high bits of each chunk are
+/// dropped (see [`UniformParser::with_chunk_bits`]). This gives the 12-bit chunks of
+/// `SampleNTT` in FIPS 203 for `q = 3329`, and the 24-bit chunks of `RejNTTPoly`
+/// in FIPS 204 for `q = 8380417`.
+pub fn expand_a<C: ConfigZZVec>(seed: &[u8; SEED_LEN], i: u8, j: u8) -> ZZVec<C> {
+    let mut input = [0u8; SEED_LEN + 2];
+    input[..SEED_LEN].copy_from_slice(seed);
+    input[SEED_LEN] = j;
+    input[SEED_LEN + 1] = i;
+
+    let k = u64::BITS - ZZp::<C::BaseConfig>::modulus().leading_zeros();
+    let chunk_bits = k.div_ceil(4) * 4;
+    let coeffs = UniformParser::with_chunk_bits(Shake128Bytes::new(&input), chunk_bits)
+        .take(C::MAX_DIM)
+        .collect();
+    ZZVec { coeffs }
+}
+
+/// The `rows x cols` matrix expanded from `seed` with [`expand_a`], in row-major order.
+pub fn expand_matrix<C: ConfigZZVec>(
+    seed: &[u8; SEED_LEN],
+    rows: usize,
+    cols: usize,
+) -> Vec<Vec<ZZVec<C>>> {
+    assert!(rows <= 256 && cols <= 256, "the indices must fit in a byte");
+    (0..rows)
+        .map(|i| {
+            (0..cols)
+                .map(|j| expand_a(seed, i as u8, j as u8))
+                .collect()
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{expand_a, expand_matrix};
+    use crate::{ConfigZZVecGoldilocks256, Vec3329_256, Vec8380417_256, Vector};
+
+    fn hex(bytes: &[u8]) -> String {
+        bytes.iter().map(|b| format!("{:02x}", b)).collect()
+    }
+
+    #[test]
+    fn test_expand_a() {
+        let mut seed = [0u8; 32];
+        for (i, s) in seed.iter_mut().enumerate() {
+            *s = i as u8;
+        }
+
+        // regression digests, not NIST known-answer values, of SampleNTT and RejNTTPoly
+        // over SHAKE128(seed || j || i), computed from FIPS 203 (Algorithm 7) and
+        // FIPS 204 (Algorithm 30) by an independent Python 3 script
+        //
+        //     import hashlib
+        //     def sample_ntt(b):
+        //         a, k = [], 0
+        //         while len(a) < 256:
+        //             d1, d2 = b[k] + 256 * (b[k + 1] % 16), b[k + 1] // 16 + 16 * b[k + 2]
+        //             a, k = a + [d for d in (d1, d2) if d < 3329], k + 3
+        //         return a[:256], 2
+        //     def rej_ntt_poly(b):
+        //         d = [int.from_bytes(b[k:k + 3], "little") & 0x7FFFFF for k in range(0, 4095, 3)]
+        //         return [x for x in d if x < 8380417][:256], 3
+        //     for sample in [sample_ntt, rej_ntt_poly]:
+        //         for i, j in [(0, 0), (1, 2)]:
+        //             xof = hashlib.shake_128(bytes(range(32)) + bytes([j, i])).digest(4096)
+        //             a, width = sample(xof)
+        //             data = b"".join(x.to_bytes(width, "little") for x in a)
+        //             print(a[:4], hashlib.sha256(data).hexdigest())
+        let a: Vec3329_256 = expand_a(&seed, 0, 0);
+        let values: Vec<u64> = a.coefficients().take(4).map(|x| u64::from(*x)).collect();
+        assert_eq!(values, vec![481, 1919, 1434, 2359]);
+        assert_eq!(
+            hex(&a.digest()),
+            "27c37db2d6cb6c3268c5dc7972424fbb2f2497a64324f1dfed07805960e2c720"
+        );
+        let a: Vec3329_256 = expand_a(&seed, 1, 2);
+        assert_eq!(
+            hex(&a.digest()),
+            "dd0253d1c16d1074e4ce9bc9f35fc029b4ec38acea0c8542d34c319e637d60d1"
+        );
+
+        let a: Vec8380417_256 = expand_a(&seed, 0, 0);
+        let values: Vec<u64> = a.coefficients().take(4).map(|x| u64::from(*x)).collect();
+        assert_eq!(values, vec![7905761, 7863978, 1275290, 4366663]);
+        assert_eq!(
+            hex(&a.digest()),
+            "65c8b5c543e20a59c930b2e1ea5984c8f32025ba7912de1d8a483df96a64aa19"
+        );
+        let a: Vec8380417_256 = expand_a(&seed, 1, 2);
+        assert_eq!(
+            hex(&a.digest()),
+            "715d3b2ab1bf7af09f5b7e7b7c9a6ed59c69a1be6e404ea6388bc894fab79c7d"
+        );
+
+        // the matrix is indexed by row then column
+        let matrix = expand_matrix::<ConfigZZVecGoldilocks256>(&seed, 2, 3);
+        assert_eq!(matrix.len(), 2);
+        assert!(matrix.iter().all(|row| row.len() == 3));
+        assert_eq!(matrix[1][2], expand_a(&seed, 1, 2));
+        assert_ne!(matrix[1][2], matrix[1][1]);
+        assert_ne!(matrix[0][1], expand_a(&seed, 1, 0));
+    }
+}
@@ -6,12 +6,22 @@ mod definitions;
 /// Instances
 mod instances;
 
+/// Modular reduction backends
+mod reduction;
+
+/// Uniform sampling
+mod uniform;
+
 /// generic implementation of ZZ mod p
 mod zz_p;
 
-pub use definitions::{ConfigZZp, Field};
+pub use definitions::{ConfigZZp, Field, NTTField, PrimeField};
 pub use instances::{
-    ConfigZZp12289, ConfigZZp3329, ConfigZZp8380417, ConfigZZpGoldilocks, Goldilocks, F12289,
-    F3329, F8380417,
+    ConfigZZp12289, ConfigZZp12289Montgomery, ConfigZZp3329, ConfigZZp3329Montgomery,
+    ConfigZZp8380417, ConfigZZp8380417Montgomery, ConfigZZpGoldilocks, F12289Montgomery,
+    F3329Montgomery, F8380417Montgomery, Goldilocks, F12289, F3329, F8380417,
 };
+pub(crate) use reduction::value_barrier;
+pub(crate) use uniform::random_below;
+pub use uniform::UniformParser;
 pub use zz_p::ZZp;
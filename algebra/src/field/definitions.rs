@@ -5,6 +5,7 @@ use std::fmt::Debug;
 use std::fmt::Display;
 use std::ops::{Add, AddAssign, Mul, MulAssign, Neg, Rem, Sub, SubAssign};
 
+use crate::field::reduction::ct_reduce_once_u64;
 use num::cast::AsPrimitive;
 use num::{FromPrimitive, ToPrimitive};
 use rand::RngCore;
@@ -178,23 +179,39 @@ pub trait Field:
     }
 }
 
-// /// larkwork's prime field.
-// pub trait PrimeField: ff::PrimeField + Field {
-//     /// Normalize self into `[-MODULUS_OVER_2, MODULUS_OVER_2)`
-//     fn lift(&self) -> Self;
+/// larkwork's prime field.
+pub trait PrimeField: Field {
+    /// The modulus of the field
+    fn modulus() -> u64;
 
-//     /// Normalize self into `[0, MODULUS)`
-//     fn normalize(&self) -> Self;
-// }
+    /// Lift self into `[-MODULUS/2, MODULUS/2)`
+    fn lift(&self) -> i64;
 
-// /// larkwork's NTT friendly field.
-// pub trait NTTField: PrimeField {
-//     /// The generator of the multiplicative group of the field
-//     const GENERATOR: Self;
+    /// Normalize self into `[0, MODULUS)`
+    fn normalize(&self) -> Self;
 
-//     /// Returns the root of unity of order n, if one exists.
-//     fn get_root_of_unity(n: u64) -> Option<Self>;
-// }
+    /// Build a field element from a (possibly negative) integer,
+    /// reducing it modulo `MODULUS`
+    fn from_lift(value: i64) -> Self;
+}
+
+/// larkwork's NTT friendly field.
+pub trait NTTField: PrimeField {
+    /// 2-adicity of the field, i.e., the largest `s` such that `2^s | MODULUS - 1`
+    const TWO_ADICITY: u32;
+
+    /// The generator of the multiplicative group of the field
+    const GENERATOR: Self;
+
+    /// Returns a primitive root of unity of order n, if one exists.
+    fn get_root_of_unity(n: u64) -> Option<Self>;
+
+    /// Returns a primitive 2n-th root of unity, if one exists.
+    /// This is the root used by a negacyclic NTT over `x^n + 1`.
+    fn get_primitive_2nth_root_of_unity(n: u64) -> Option<Self> {
+        Self::get_root_of_unity(n.checked_mul(2)?)
+    }
+}
 
 /// Trait definition of configurations
 pub trait ConfigZZp: Copy + Debug + Default + Eq + 'static {
@@ -235,6 +252,15 @@ pub trait ConfigZZp: Copy + Debug + Default + Eq + 'static {
     /// Modulus
     const MODULUS: Self::PrimitiveType;
 
+    /// 2-adicity of the field, i.e., the largest `s` such that `2^s | MODULUS - 1`
+    const TWO_ADICITY: u32;
+
+    /// A fixed quadratic non-residue modulo `MODULUS`, in the internal representation
+    const NON_RESIDUE: Self::PrimitiveType;
+
+    /// A generator of the multiplicative group modulo `MODULUS`, in the internal representation
+    const GENERATOR: Self::PrimitiveType;
+
     /// The place where the multiplication algorithm is actually implemented.
     fn mul_internal(a: &Self::PrimitiveType, b: &Self::PrimitiveType) -> Self::PrimitiveType;
 
@@ -246,4 +272,18 @@ pub trait ConfigZZp: Copy + Debug + Default + Eq + 'static {
 
     /// The place where the equality algorithm is actually implemented.
     fn eq_internal(a: &Self::PrimitiveType, b: &Self::PrimitiveType) -> bool;
+
+    /// Convert an integer in `[0, MODULUS)` into the internal representation.
+    ///
+    /// This is the identity, unless the config stores elements in a
+    /// different form, e.g., the Montgomery form.
+    fn to_internal(a: u64) -> Self::PrimitiveType {
+        Self::PrimitiveType::from_u64(a).unwrap()
+    }
+
+    /// Convert the internal representation into an integer in `[0, MODULUS)`.
+    /// The internal value must be below `2 MODULUS`.
+    fn from_internal(a: &Self::PrimitiveType) -> u64 {
+        ct_reduce_once_u64(a.as_(), Self::MODULUS.as_())
+    }
 }
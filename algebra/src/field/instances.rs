@@ -2,8 +2,13 @@ mod f12289;
 mod f3329;
 mod f8380417;
 mod goldilocks;
+mod montgomery;
 
 pub use f12289::{ConfigZZp12289, F12289};
 pub use f3329::{ConfigZZp3329, F3329};
 pub use f8380417::{ConfigZZp8380417, F8380417};
 pub use goldilocks::{ConfigZZpGoldilocks, Goldilocks};
+pub use montgomery::{
+    ConfigZZp12289Montgomery, ConfigZZp3329Montgomery, ConfigZZp8380417Montgomery,
+    F12289Montgomery, F3329Montgomery, F8380417Montgomery,
+};
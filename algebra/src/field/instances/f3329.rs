@@ -1,5 +1,9 @@
+use crate::field::reduction::{barrett_factor, barrett_reduce, ct_add, ct_reduce_once, ct_sub};
 use crate::{field::zz_p::ZZp, ConfigZZp};
 
+/// Barrett factor floor(4^k / 3329), k being the bit length of the modulus
+const BARRETT_FACTOR: u64 = barrett_factor(3329);
+
 /// Configuration parameter for ZZ mod 3329
 #[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
 pub struct ConfigZZp3329;
@@ -8,33 +12,29 @@ impl ConfigZZp for ConfigZZp3329 {
     type PrimitiveType = u16;
     type ProductType = u32;
     const MODULUS: Self::PrimitiveType = 3329;
+    const TWO_ADICITY: u32 = 8;
+    const NON_RESIDUE: Self::PrimitiveType = 3;
+    const GENERATOR: Self::PrimitiveType = 3;
 
     /// The place where the multiplication algorithm is actually implemented.
     fn mul_internal(a: &Self::PrimitiveType, b: &Self::PrimitiveType) -> Self::PrimitiveType {
-        (*a as Self::ProductType * *b as Self::ProductType % Self::MODULUS as Self::ProductType)
+        barrett_reduce(*a as u64 * *b as u64, Self::MODULUS as u32, BARRETT_FACTOR)
             as Self::PrimitiveType
     }
 
     /// The place where the addition algorithm is actually implemented.
     fn add_internal(a: &Self::PrimitiveType, b: &Self::PrimitiveType) -> Self::PrimitiveType {
-        let mut tmp = a + b;
-        if tmp >= Self::MODULUS {
-            tmp -= Self::MODULUS
-        }
-        tmp
+        ct_add(*a as u32, *b as u32, Self::MODULUS as u32) as Self::PrimitiveType
     }
 
     /// The place where the subtraction algorithm is actually implemented.
     fn sub_internal(a: &Self::PrimitiveType, b: &Self::PrimitiveType) -> Self::PrimitiveType {
-        if a >= b {
-            a - b
-        } else {
-            a + Self::MODULUS - b
-        }
+        ct_sub(*a as u32, *b as u32, Self::MODULUS as u32) as Self::PrimitiveType
     }
 
     fn eq_internal(a: &Self::PrimitiveType, b: &Self::PrimitiveType) -> bool {
-        a % Self::MODULUS == b % Self::MODULUS
+        let modulus = Self::MODULUS as u32;
+        ct_reduce_once(*a as u32, modulus) == ct_reduce_once(*b as u32, modulus)
     }
 }
 
@@ -44,10 +44,33 @@ pub type F3329 = ZZp<ConfigZZp3329>;
 #[cfg(test)]
 mod tests {
     use super::F3329;
-    use crate::tests::field::random_field_tests;
+    use crate::tests::field::{constant_time_tests, random_field_tests, random_ntt_field_tests};
+    use crate::tests::serialize::serialization_tests;
+    use crate::{Field, PrimeField};
 
     #[test]
     fn test_integer() {
         random_field_tests::<F3329>("F3329".to_string());
     }
+
+    #[test]
+    fn test_ntt_field() {
+        random_ntt_field_tests::<F3329>("F3329".to_string());
+    }
+
+    #[test]
+    #[cfg_attr(
+        not(feature = "ct-tests"),
+        ignore = "timing test, needs the ct-tests feature"
+    )]
+    fn test_constant_time() {
+        constant_time_tests::<F3329>("F3329".to_string());
+    }
+
+    #[test]
+    fn test_serialization() {
+        serialization_tests("F3329".to_string(), F3329::modulus(), |rng| {
+            F3329::random(rng)
+        });
+    }
 }
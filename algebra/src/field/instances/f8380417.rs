@@ -1,5 +1,9 @@
+use crate::field::reduction::{barrett_factor, barrett_reduce, ct_add, ct_reduce_once, ct_sub};
 use crate::{field::zz_p::ZZp, ConfigZZp};
 
+/// Barrett factor floor(4^k / 8380417), k being the bit length of the modulus
+const BARRETT_FACTOR: u64 = barrett_factor(8380417);
+
 /// Configuration parameter for ZZ mod 8380417
 #[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
 pub struct ConfigZZp8380417;
@@ -8,33 +12,27 @@ impl ConfigZZp for ConfigZZp8380417 {
     type PrimitiveType = u32;
     type ProductType = u64;
     const MODULUS: Self::PrimitiveType = 8380417;
+    const TWO_ADICITY: u32 = 13;
+    const NON_RESIDUE: Self::PrimitiveType = 10;
+    const GENERATOR: Self::PrimitiveType = 10;
 
     /// The place where the multiplication algorithm is actually implemented.
     fn mul_internal(a: &Self::PrimitiveType, b: &Self::PrimitiveType) -> Self::PrimitiveType {
-        (*a as Self::ProductType * *b as Self::ProductType % Self::MODULUS as Self::ProductType)
-            as Self::PrimitiveType
+        barrett_reduce(*a as u64 * *b as u64, Self::MODULUS, BARRETT_FACTOR)
     }
 
     /// The place where the addition algorithm is actually implemented.
     fn add_internal(a: &Self::PrimitiveType, b: &Self::PrimitiveType) -> Self::PrimitiveType {
-        let mut tmp = a + b;
-        if tmp >= Self::MODULUS {
-            tmp -= Self::MODULUS
-        }
-        tmp
+        ct_add(*a, *b, Self::MODULUS)
     }
 
     /// The place where the subtraction algorithm is actually implemented.
     fn sub_internal(a: &Self::PrimitiveType, b: &Self::PrimitiveType) -> Self::PrimitiveType {
-        if a >= b {
-            a - b
-        } else {
-            a + Self::MODULUS - b
-        }
+        ct_sub(*a, *b, Self::MODULUS)
     }
 
     fn eq_internal(a: &Self::PrimitiveType, b: &Self::PrimitiveType) -> bool {
-        a % Self::MODULUS == b % Self::MODULUS
+        ct_reduce_once(*a, Self::MODULUS) == ct_reduce_once(*b, Self::MODULUS)
     }
 }
 
@@ -44,10 +42,33 @@ pub type F8380417 = ZZp<ConfigZZp8380417>;
 #[cfg(test)]
 mod tests {
     use super::F8380417;
-    use crate::tests::field::random_field_tests;
+    use crate::tests::field::{constant_time_tests, random_field_tests, random_ntt_field_tests};
+    use crate::tests::serialize::serialization_tests;
+    use crate::{Field, PrimeField};
 
     #[test]
     fn test_integer() {
         random_field_tests::<F8380417>("F8380417".to_string());
     }
+
+    #[test]
+    fn test_ntt_field() {
+        random_ntt_field_tests::<F8380417>("F8380417".to_string());
+    }
+
+    #[test]
+    #[cfg_attr(
+        not(feature = "ct-tests"),
+        ignore = "timing test, needs the ct-tests feature"
+    )]
+    fn test_constant_time() {
+        constant_time_tests::<F8380417>("F8380417".to_string());
+    }
+
+    #[test]
+    fn test_serialization() {
+        serialization_tests("F8380417".to_string(), F8380417::modulus(), |rng| {
+            F8380417::random(rng)
+        });
+    }
 }
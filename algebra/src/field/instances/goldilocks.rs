@@ -11,6 +11,9 @@ impl ConfigZZp for ConfigZZpGoldilocks {
     type PrimitiveType = u64;
     type ProductType = u128;
     const MODULUS: Self::PrimitiveType = 0xffffffff00000001;
+    const TWO_ADICITY: u32 = 32;
+    const NON_RESIDUE: Self::PrimitiveType = 7;
+    const GENERATOR: Self::PrimitiveType = 7;
 
     /// The place where the multiplication algorithm is actually implemented.
     fn mul_internal(a: &Self::PrimitiveType, b: &Self::PrimitiveType) -> Self::PrimitiveType {
@@ -20,43 +23,23 @@ impl ConfigZZp for ConfigZZpGoldilocks {
     /// The place where the addition algorithm is actually implemented.
     fn add_internal(a: &Self::PrimitiveType, b: &Self::PrimitiveType) -> Self::PrimitiveType {
         let (sum, over) = a.overflowing_add(*b);
-        let (mut sum, over) = sum.overflowing_add((over as u64) * EPSILON);
-        if over {
-            // NB: self.0 > Self::ORDER && rhs.0 > Self::ORDER is necessary but not sufficient for
-            // double-overflow.
-            // This assume does two things:
-            //  1. If compiler knows that either self.0 or rhs.0 <= ORDER, then it can skip this
-            //     check.
-            //  2. Hints to the compiler how rare this double-overflow is (thus handled better with
-            //     a branch).
-            util::assume(*a > Self::MODULUS && *b > Self::MODULUS);
-            util::branch_hint();
-            sum += EPSILON; // Cannot overflow.
-        }
-        sum
+        let (sum, over) = sum.overflowing_add(util::epsilon_if(over));
+        // A double-overflow is only possible if both a and b are non-canonical,
+        // in which case adding EPSILON again cannot overflow.
+        sum + util::epsilon_if(over)
     }
 
     /// The place where the subtraction algorithm is actually implemented.
     fn sub_internal(a: &Self::PrimitiveType, b: &Self::PrimitiveType) -> Self::PrimitiveType {
         let (diff, under) = a.overflowing_sub(*b);
-        let (mut diff, under) = diff.overflowing_sub((under as u64) * EPSILON);
-        if under {
-            // NB: self.0 < EPSILON - 1 && rhs.0 > Self::ORDER is necessary but not sufficient for
-            // double-underflow.
-            // This assume does two things:
-            //  1. If compiler knows that either self.0 >= EPSILON - 1 or rhs.0 <= ORDER, then it
-            //     can skip this check.
-            //  2. Hints to the compiler how rare this double-underflow is (thus handled better
-            //     with a branch).
-            util::assume(*a < EPSILON - 1 && *b > Self::MODULUS);
-            util::branch_hint();
-            diff -= EPSILON; // Cannot underflow.
-        }
-        diff
+        let (diff, under) = diff.overflowing_sub(util::epsilon_if(under));
+        // A double-underflow is only possible if a < EPSILON - 1 and b is non-canonical,
+        // in which case subtracting EPSILON again cannot underflow.
+        diff - util::epsilon_if(under)
     }
 
     fn eq_internal(a: &Self::PrimitiveType, b: &Self::PrimitiveType) -> bool {
-        let diff = if a > b { a - b } else { b - a };
+        let diff = a.abs_diff(*b);
         diff == 0 || diff == Self::MODULUS
     }
 }
@@ -67,30 +50,12 @@ impl ConfigZZp for ConfigZZpGoldilocks {
 pub type Goldilocks = ZZp<ConfigZZpGoldilocks>;
 
 mod util {
+    use crate::field::reduction::value_barrier;
 
+    /// Returns EPSILON if `flag` is set and 0 otherwise, without branching.
     #[inline(always)]
-    pub fn assume(p: bool) {
-        debug_assert!(p);
-        if !p {
-            unsafe {
-                std::hint::unreachable_unchecked();
-            }
-        }
-    }
-
-    /// Try to force Rust to emit a branch. Example:
-    ///     if x > 2 {
-    ///         y = foo();
-    ///         branch_hint();
-    ///     } else {
-    ///         y = bar();
-    ///     }
-    /// This function has no semantics. It is a hint only.
-    #[inline(always)]
-    pub(crate) fn branch_hint() {
-        unsafe {
-            std::arch::asm!("", options(nomem, nostack, preserves_flags));
-        }
+    pub(crate) fn epsilon_if(flag: bool) -> u64 {
+        value_barrier(0u64.wrapping_sub(flag as u64)) & super::EPSILON
     }
 
     /// Reduces to a 64-bit value. The result might not be in canonical form; it could be in between the
@@ -101,11 +66,9 @@ mod util {
         let x_hi_hi = x_hi >> 32;
         let x_hi_lo = x_hi & super::EPSILON;
 
-        let (mut t0, borrow) = x_lo.overflowing_sub(x_hi_hi);
-        if borrow {
-            branch_hint(); // A borrow is exceedingly rare. It is faster to branch.
-            t0 -= super::EPSILON; // Cannot underflow.
-        }
+        let (t0, borrow) = x_lo.overflowing_sub(x_hi_hi);
+        // Subtract EPSILON on a borrow, without branching. Cannot underflow.
+        let t0 = t0 - epsilon_if(borrow);
         let t1 = x_hi_lo * super::EPSILON;
         unsafe { add_no_canonicalize_trashing_input(t0, t1) }
     }
@@ -140,8 +103,6 @@ mod util {
             inlateout(reg) y => adjustment,
             options(pure, nomem, nostack),
         );
-        assume(x != 0 || (res_wrapped == y && adjustment == 0));
-        assume(y != 0 || (res_wrapped == x && adjustment == 0));
         // Add EPSILON == subtract ORDER.
         // Cannot overflow unless the assumption if x + y < 2**64 + ORDER is incorrect.
         res_wrapped + adjustment
@@ -159,10 +120,33 @@ mod util {
 #[cfg(test)]
 mod tests {
     use super::Goldilocks;
-    use crate::tests::field::random_field_tests;
+    use crate::tests::field::{constant_time_tests, random_field_tests, random_ntt_field_tests};
+    use crate::tests::serialize::serialization_tests;
+    use crate::{Field, PrimeField};
 
     #[test]
     fn test_integer() {
         random_field_tests::<Goldilocks>("Goldilocks".to_string());
     }
+
+    #[test]
+    fn test_ntt_field() {
+        random_ntt_field_tests::<Goldilocks>("Goldilocks".to_string());
+    }
+
+    #[test]
+    #[cfg_attr(
+        not(feature = "ct-tests"),
+        ignore = "timing test, needs the ct-tests feature"
+    )]
+    fn test_constant_time() {
+        constant_time_tests::<Goldilocks>("Goldilocks".to_string());
+    }
+
+    #[test]
+    fn test_serialization() {
+        serialization_tests("Goldilocks".to_string(), Goldilocks::modulus(), |rng| {
+            Goldilocks::random(rng)
+        });
+    }
 }
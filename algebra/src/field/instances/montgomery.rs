@@ -0,0 +1,166 @@
+//! Prime fields whose elements are stored in Montgomery form `a * 2^32 mod q`,
+//! for NTT-friendly moduli `q < 2^31`.
+
+use crate::field::reduction::{
+    ct_add, ct_reduce_once, ct_sub, montgomery_q_inv_neg, montgomery_r2, montgomery_reduce,
+    to_montgomery,
+};
+use crate::{field::zz_p::ZZp, ConfigZZp, F12289, F3329, F8380417};
+
+/// Define the Montgomery configuration of ZZ mod `$modulus`, its field type, and the
+/// conversions from and to the field `$plain` with the same modulus.
+macro_rules! montgomery_field {
+    (
+        $config: ident,
+        $field: ident,
+        $plain: ident,
+        $modulus: literal,
+        $two_adicity: literal,
+        $generator: literal,
+        $tests: ident
+    ) => {
+        #[doc = concat!("Configuration parameter for ZZ mod ", stringify!($modulus), ", where an element `a`")]
+        #[doc = concat!("is stored in its Montgomery form `a * 2^32 mod ", stringify!($modulus), "`.")]
+        #[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+        pub struct $config;
+
+        impl $config {
+            #[doc = concat!("-", stringify!($modulus), "^{-1} mod 2^32")]
+            const Q_INV_NEG: u32 = montgomery_q_inv_neg($modulus);
+            #[doc = concat!("2^64 mod ", stringify!($modulus))]
+            const R2: u32 = montgomery_r2($modulus);
+        }
+
+        impl ConfigZZp for $config {
+            type PrimitiveType = u32;
+            type ProductType = u64;
+            const MODULUS: Self::PrimitiveType = $modulus;
+            const TWO_ADICITY: u32 = $two_adicity;
+            const NON_RESIDUE: Self::PrimitiveType = to_montgomery($generator, $modulus);
+            const GENERATOR: Self::PrimitiveType = to_montgomery($generator, $modulus);
+
+            /// The place where the multiplication algorithm is actually implemented.
+            fn mul_internal(a: &Self::PrimitiveType, b: &Self::PrimitiveType) -> Self::PrimitiveType {
+                montgomery_reduce(*a as u64 * *b as u64, Self::MODULUS, Self::Q_INV_NEG)
+            }
+
+            /// The place where the addition algorithm is actually implemented.
+            fn add_internal(a: &Self::PrimitiveType, b: &Self::PrimitiveType) -> Self::PrimitiveType {
+                ct_add(*a, *b, Self::MODULUS)
+            }
+
+            /// The place where the subtraction algorithm is actually implemented.
+            fn sub_internal(a: &Self::PrimitiveType, b: &Self::PrimitiveType) -> Self::PrimitiveType {
+                ct_sub(*a, *b, Self::MODULUS)
+            }
+
+            fn eq_internal(a: &Self::PrimitiveType, b: &Self::PrimitiveType) -> bool {
+                ct_reduce_once(*a, Self::MODULUS) == ct_reduce_once(*b, Self::MODULUS)
+            }
+
+            /// Convert an integer in `[0, MODULUS)` into the Montgomery form.
+            fn to_internal(a: u64) -> Self::PrimitiveType {
+                montgomery_reduce(a * Self::R2 as u64, Self::MODULUS, Self::Q_INV_NEG)
+            }
+
+            /// Convert the Montgomery form into an integer in `[0, MODULUS)`.
+            fn from_internal(a: &Self::PrimitiveType) -> u64 {
+                montgomery_reduce(*a as u64, Self::MODULUS, Self::Q_INV_NEG) as u64
+            }
+        }
+
+        #[doc = concat!("ZZ mod ", stringify!($modulus), ", stored in Montgomery form")]
+        pub type $field = ZZp<$config>;
+
+        impl From<$plain> for $field {
+            fn from(value: $plain) -> Self {
+                u64::from(value).into()
+            }
+        }
+
+        impl From<$field> for $plain {
+            fn from(value: $field) -> Self {
+                u64::from(value).into()
+            }
+        }
+
+        #[cfg(test)]
+        mod $tests {
+            use super::$field;
+            use crate::tests::field::{
+                constant_time_tests, random_field_tests, random_ntt_field_tests,
+            };
+            use crate::tests::serialize::serialization_tests;
+            use crate::{Field, PrimeField, $plain};
+
+            #[test]
+            fn test_integer() {
+                random_field_tests::<$field>(stringify!($field).to_string());
+            }
+
+            #[test]
+            fn test_ntt_field() {
+                random_ntt_field_tests::<$field>(stringify!($field).to_string());
+            }
+
+            #[test]
+            #[cfg_attr(
+                not(feature = "ct-tests"),
+                ignore = "timing test, needs the ct-tests feature"
+            )]
+            fn test_constant_time() {
+                constant_time_tests::<$field>(stringify!($field).to_string());
+            }
+
+            #[test]
+            fn test_serialization() {
+                serialization_tests(stringify!($field).to_string(), $field::modulus(), |rng| {
+                    $field::random(rng)
+                });
+            }
+
+            #[test]
+            fn test_conversion() {
+                let mut rng = ark_std::test_rng();
+                for _ in 0..10000 {
+                    let a = $plain::random(&mut rng);
+                    let b = $plain::random(&mut rng);
+                    let a_mont = $field::from(a);
+                    let b_mont = $field::from(b);
+                    assert_eq!(u64::from(a), u64::from(a_mont));
+                    assert_eq!($plain::from(a_mont * b_mont), a * b);
+                    assert_eq!($plain::from(a_mont + b_mont), a + b);
+                    assert_eq!($plain::from(a_mont - b_mont), a - b);
+                }
+            }
+        }
+    };
+}
+
+montgomery_field!(
+    ConfigZZp3329Montgomery,
+    F3329Montgomery,
+    F3329,
+    3329,
+    8,
+    3,
+    f3329_tests
+);
+montgomery_field!(
+    ConfigZZp12289Montgomery,
+    F12289Montgomery,
+    F12289,
+    12289,
+    12,
+    11,
+    f12289_tests
+);
+montgomery_field!(
+    ConfigZZp8380417Montgomery,
+    F8380417Montgomery,
+    F8380417,
+    8380417,
+    13,
+    10,
+    f8380417_tests
+);
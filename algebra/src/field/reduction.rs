@@ -0,0 +1,151 @@
+//! Constant time modular reduction backends for moduli below 2^31.
+//!
+//! Conditional corrections are computed with masks that go through
+//! [`value_barrier`], so that the compiler cannot turn them back into branches.
+
+/// Returns `x`, while hiding its value from the optimizer.
+///
+/// Without the barrier, LLVM recognises `mask & y` as a select and may emit a
+/// branch for it. The empty `asm!` keeps `x` in a register, which is much cheaper
+/// than the volatile read behind `subtle::Choice`.
+#[inline(always)]
+pub(crate) fn value_barrier(x: u64) -> u64 {
+    #[cfg(any(target_arch = "x86_64", target_arch = "aarch64"))]
+    {
+        let mut x = x;
+        // SAFETY: the assembly is empty
+        unsafe {
+            core::arch::asm!("/* {0} */", inout(reg) x, options(pure, nomem, nostack, preserves_flags));
+        }
+        x
+    }
+    #[cfg(not(any(target_arch = "x86_64", target_arch = "aarch64")))]
+    {
+        core::hint::black_box(x)
+    }
+}
+
+/// Returns `u32::MAX` if the top bit of `a` is set and 0 otherwise, without branching.
+#[inline(always)]
+fn ct_top_bit_mask(a: u32) -> u32 {
+    value_barrier(0u64.wrapping_sub((a >> 31) as u64)) as u32
+}
+
+/// Returns `a - q` if `a >= q`, and `a` otherwise, without branching.
+/// Requires `q < 2^31` and `a < 2^31`.
+#[inline(always)]
+pub(crate) fn ct_reduce_once(a: u32, q: u32) -> u32 {
+    let r = a.wrapping_sub(q);
+    // the top bit of r is set iff the subtraction underflowed, i.e., a < q
+    r.wrapping_add(q & ct_top_bit_mask(r))
+}
+
+/// Returns `a - q` if `a >= q`, and `a` otherwise, without branching.
+/// Requires `a < 2q`.
+#[inline(always)]
+pub(crate) fn ct_reduce_once_u64(a: u64, q: u64) -> u64 {
+    let (r, borrow) = a.overflowing_sub(q);
+    // all ones iff the subtraction underflowed, i.e., a < q
+    r.wrapping_add(q & value_barrier(0u64.wrapping_sub(borrow as u64)))
+}
+
+/// `a + b mod q` for `a, b` in `[0, q)` and `q < 2^31`, without branching.
+#[inline(always)]
+pub(crate) fn ct_add(a: u32, b: u32, q: u32) -> u32 {
+    ct_reduce_once(a + b, q)
+}
+
+/// `a - b mod q` for `a, b` in `[0, q)` and `q < 2^31`, without branching.
+#[inline(always)]
+pub(crate) fn ct_sub(a: u32, b: u32, q: u32) -> u32 {
+    let r = a.wrapping_sub(b);
+    // the top bit of r is set iff the subtraction underflowed, i.e., a < b
+    r.wrapping_add(q & ct_top_bit_mask(r))
+}
+
+/// Barrett factor `floor(4^k / q)` where `k` is the bit length of `q`.
+pub(crate) const fn barrett_factor(q: u32) -> u64 {
+    let k = u32::BITS - q.leading_zeros();
+    (1u64 << (2 * k)) / q as u64
+}
+
+/// Barrett reduction of `x < 4^k` modulo `q < 2^29`, where `k` is the
+/// bit length of `q` and `factor = barrett_factor(q)`.
+/// In particular, this reduces the product of two elements in `[0, q)`.
+///
+/// This is Algorithm 14.42 of the Handbook of Applied Cryptography.
+#[inline(always)]
+pub(crate) fn barrett_reduce(x: u64, q: u32, factor: u64) -> u32 {
+    let k = u32::BITS - q.leading_zeros();
+    let quotient = ((x >> (k - 1)) * factor) >> (k + 1);
+    // the estimated quotient is off by at most two, so r < 3q
+    let r = (x - quotient * q as u64) as u32;
+    ct_reduce_once(ct_reduce_once(r, q), q)
+}
+
+/// `-q^{-1} mod 2^32` for an odd `q`.
+pub(crate) const fn montgomery_q_inv_neg(q: u32) -> u32 {
+    // Newton iteration; each step doubles the number of correct bits
+    let mut inv: u32 = 1;
+    let mut i = 0;
+    while i < 5 {
+        inv = inv.wrapping_mul(2u32.wrapping_sub(q.wrapping_mul(inv)));
+        i += 1;
+    }
+    inv.wrapping_neg()
+}
+
+/// `2^64 mod q`, used to convert integers into Montgomery form.
+pub(crate) const fn montgomery_r2(q: u32) -> u32 {
+    ((1u128 << 64) % q as u128) as u32
+}
+
+/// `a * 2^32 mod q`, i.e., `a` in Montgomery form.
+pub(crate) const fn to_montgomery(a: u32, q: u32) -> u32 {
+    (((a as u64) << 32) % q as u64) as u32
+}
+
+/// Montgomery reduction: returns `t * 2^{-32} mod q` for `t < q * 2^32` and `q < 2^31`,
+/// where `q_inv_neg = montgomery_q_inv_neg(q)`.
+#[inline(always)]
+pub(crate) fn montgomery_reduce(t: u64, q: u32, q_inv_neg: u32) -> u32 {
+    let m = (t as u32).wrapping_mul(q_inv_neg);
+    // t + m * q is divisible by 2^32, and the quotient is less than 2q
+    let u = ((t + m as u64 * q as u64) >> 32) as u32;
+    ct_reduce_once(u, q)
+}
+
+#[cfg(test)]
+mod tests {
+    use rand::RngCore;
+
+    use super::*;
+    use crate::tests::test_rng;
+
+    #[test]
+    fn test_reductions() {
+        let mut rng = test_rng();
+        for q in [3329u32, 12289, 8380417, (1 << 29) - 3] {
+            let factor = barrett_factor(q);
+            let q_inv_neg = montgomery_q_inv_neg(q);
+            assert_eq!(q.wrapping_mul(q_inv_neg), u32::MAX);
+
+            for x in [0, 1, q as u64 - 1, (q as u64 - 1) * (q as u64 - 1)] {
+                assert_eq!(barrett_reduce(x, q, factor) as u64, x % q as u64);
+            }
+            for _ in 0..100000 {
+                let a = rng.next_u32() % q;
+                let b = rng.next_u32() % q;
+                let x = a as u64 * b as u64;
+                assert_eq!(barrett_reduce(x, q, factor) as u64, x % q as u64);
+
+                let a_mont = montgomery_reduce(a as u64 * montgomery_r2(q) as u64, q, q_inv_neg);
+                assert_eq!(a_mont, to_montgomery(a, q));
+                assert_eq!(montgomery_reduce(a_mont as u64, q, q_inv_neg), a);
+
+                assert_eq!(ct_add(a, b, q), (a + b) % q);
+                assert_eq!(ct_sub(a, b, q), (a + q - b) % q);
+            }
+        }
+    }
+}
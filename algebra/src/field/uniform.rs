@@ -0,0 +1,174 @@
+//! Unbiased sampling of uniform field elements via rejection sampling.
+
+use std::marker::PhantomData;
+
+use rand::RngCore;
+
+use crate::PrimeField;
+
+/// Returns an integer uniformly distributed in `[0, bound)`.
+///
+/// Integers of the bit length of `bound` are drawn until one is below `bound`,
+/// which takes less than two draws on average. The running time only leaks the
+/// number of rejected draws, which is independent of the returned value.
+pub(crate) fn random_below(mut rng: impl RngCore, bound: u64) -> u64 {
+    assert!(bound > 0, "the bound must be positive");
+    // the mask is zero for bound = 1
+    let mask = u64::MAX
+        .checked_shr((bound - 1).leading_zeros())
+        .unwrap_or(0);
+    loop {
+        let candidate = rng.next_u64() & mask;
+        if candidate < bound {
+            return candidate;
+        }
+    }
+}
+
+/// Parses a byte stream into field elements that are uniform in `[0, MODULUS)`.
+///
+/// The stream is read as a little-endian bit string, and cut into chunks of `k`
+/// bits where `k` is the bit length of the modulus. Chunks that are not less than
+/// the modulus are rejected. For `MODULUS = 3329`, this is the parser of
+/// `SampleNTT` from FIPS 203 (Algorithm 7), which turns every 3 bytes into two
+/// 12-bit candidates.
+///
+/// With [`Self::with_chunk_bits`], the chunks can be wider than `k` bits, with their
+/// high bits ignored. For `MODULUS = 8380417`, 24-bit chunks give the parser of
+/// `RejNTTPoly` from FIPS 204 (Algorithm 30), which drops the top bit of every 3 bytes.
+///
+/// The iterator ends when the byte stream is exhausted.
+#[derive(Debug, Clone)]
+pub struct UniformParser<F, I> {
+    bytes: I,
+    buffer: u128,
+    num_bits: u32,
+    chunk_bits: u32,
+    _phantom: PhantomData<F>,
+}
+
+impl<F: PrimeField, I: Iterator<Item = u8>> UniformParser<F, I> {
+    /// Build a parser over a byte stream, e.g., the output of an XOF.
+    pub fn new(bytes: impl IntoIterator<Item = u8, IntoIter = I>) -> Self {
+        Self::with_chunk_bits(bytes, Self::candidate_bits())
+    }
+
+    /// Build a parser that cuts the stream into chunks of `chunk_bits` bits, and keeps
+    /// the low `k` bits of each chunk as a candidate.
+    /// `chunk_bits` must be between `k` and 64.
+    pub fn with_chunk_bits(
+        bytes: impl IntoIterator<Item = u8, IntoIter = I>,
+        chunk_bits: u32,
+    ) -> Self {
+        assert!(
+            (Self::candidate_bits()..=u64::BITS).contains(&chunk_bits),
+            "invalid chunk size {}",
+            chunk_bits
+        );
+        Self {
+            bytes: bytes.into_iter(),
+            buffer: 0,
+            num_bits: 0,
+            chunk_bits,
+            _phantom: PhantomData,
+        }
+    }
+
+    /// Number of bits of a candidate
+    fn candidate_bits() -> u32 {
+        u64::BITS - F::modulus().leading_zeros()
+    }
+}
+
+impl<F: PrimeField, I: Iterator<Item = u8>> Iterator for UniformParser<F, I> {
+    type Item = F;
+
+    fn next(&mut self) -> Option<F> {
+        let k = Self::candidate_bits();
+        let chunk_bits = self.chunk_bits;
+        loop {
+            while self.num_bits < chunk_bits {
+                self.buffer |= (self.bytes.next()? as u128) << self.num_bits;
+                self.num_bits += 8;
+            }
+            let candidate = (self.buffer & ((1u128 << k) - 1)) as u64;
+            self.buffer >>= chunk_bits;
+            self.num_bits -= chunk_bits;
+            if candidate < F::modulus() {
+                return Some(candidate.into());
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rand::RngCore;
+
+    use super::{random_below, UniformParser};
+    use crate::tests::test_rng;
+    use crate::{Field, PrimeField, F3329, F8380417};
+
+    /// SampleNTT from FIPS 203, Algorithm 7, with the XOF output given as bytes
+    fn sample_ntt_reference(bytes: &[u8]) -> Vec<u64> {
+        let mut res = vec![];
+        for c in bytes.chunks_exact(3) {
+            let d1 = c[0] as u64 + 256 * (c[1] as u64 % 16);
+            let d2 = c[1] as u64 / 16 + 16 * c[2] as u64;
+            if d1 < 3329 && res.len() < 256 {
+                res.push(d1);
+            }
+            if d2 < 3329 && res.len() < 256 {
+                res.push(d2);
+            }
+        }
+        res
+    }
+
+    #[test]
+    fn test_sample_ntt() {
+        let mut rng = test_rng();
+        for _ in 0..100 {
+            let mut bytes = [0u8; 504];
+            rng.fill_bytes(&mut bytes);
+            let expected = sample_ntt_reference(&bytes);
+            let parsed: Vec<u64> = UniformParser::<F3329, _>::new(bytes)
+                .take(256)
+                .map(u64::from)
+                .collect();
+            assert_eq!(parsed, expected);
+        }
+
+        // 12-bit chunks 0xfff and 0x000 from [0xff, 0x0f, 0x00]
+        let parsed: Vec<F3329> = UniformParser::new([0xff, 0x0f, 0x00]).collect();
+        assert_eq!(parsed, vec![F3329::zero()]);
+    }
+
+    #[test]
+    fn test_uniformity() {
+        let mut rng = test_rng();
+
+        // chi-square test of the distribution over 16 buckets
+        let bound = 3 << 20;
+        let buckets = 16;
+        let samples = 160000;
+        let mut counts = vec![0f64; buckets];
+        for _ in 0..samples {
+            let x = random_below(&mut rng, bound);
+            assert!(x < bound);
+            counts[(x * buckets as u64 / bound) as usize] += 1.0;
+        }
+        let expected = samples as f64 / buckets as f64;
+        let chi_square: f64 = counts
+            .iter()
+            .map(|c| (c - expected) * (c - expected) / expected)
+            .sum();
+        // 99.9% quantile of chi-square with 15 degrees of freedom
+        assert!(chi_square < 37.7, "chi square: {}", chi_square);
+
+        for _ in 0..10000 {
+            assert!(u64::from(F8380417::random(&mut rng)) < F8380417::modulus());
+        }
+        assert_eq!(random_below(&mut rng, 1), 0);
+    }
+}
@@ -1,13 +1,21 @@
 use core::iter::{Product, Sum};
+use std::io::{Read, Write};
 use std::ops::{Add, AddAssign, Mul, MulAssign, Neg, Sub, SubAssign};
 
-use num::FromPrimitive;
-use num::ToPrimitive;
+use num::cast::AsPrimitive;
+use num::{FromPrimitive, ToPrimitive};
 use rand::RngCore;
 use subtle::{Choice, ConditionallySelectable, ConstantTimeEq, CtOption};
 
+use crate::field::random_below;
+use crate::field::reduction::ct_reduce_once_u64;
+use crate::serialize::{
+    CanonicalDeserialize, CanonicalSerialize, Compress, SerializationError, Validate,
+};
 use crate::ConfigZZp;
 use crate::Field;
+use crate::NTTField;
+use crate::PrimeField;
 
 /// Integers modulo P
 #[derive(Debug, Copy, Clone, Default, Eq, PartialOrd, Ord)]
@@ -15,7 +23,7 @@ pub struct ZZp<C: ConfigZZp>(pub(crate) C::PrimitiveType);
 
 impl<C: ConfigZZp> std::fmt::Display for ZZp<C> {
     fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
-        write!(f, "{}", self.0)
+        write!(f, "{}", C::from_internal(&self.0))
     }
 }
 
@@ -32,11 +40,7 @@ impl<C: ConfigZZp> Neg for ZZp<C> {
     type Output = Self;
 
     fn neg(self) -> Self::Output {
-        if self == Self::zero() {
-            self
-        } else {
-            Self(C::MODULUS - self.0)
-        }
+        Self(C::sub_internal(&Self::zero().0, &self.0))
     }
 }
 
@@ -171,13 +175,128 @@ impl<C: ConfigZZp> From<u64> for ZZp<C> {
             value,
             C::MODULUS
         );
-        Self(C::PrimitiveType::from_u64(value).unwrap())
+        Self(C::to_internal(value))
     }
 }
 
 impl<C: ConfigZZp> From<ZZp<C>> for u64 {
     fn from(value: ZZp<C>) -> Self {
-        value.0.into()
+        C::from_internal(&value.0)
+    }
+}
+
+// ========================
+// serialization
+// ========================
+impl<C: ConfigZZp> ZZp<C> {
+    /// Number of bytes to encode an element, i.e., the byte length of `MODULUS`
+    pub fn encoded_len() -> usize {
+        let modulus: u64 = C::MODULUS.as_();
+        (u64::BITS - modulus.leading_zeros() + 7) as usize / 8
+    }
+
+    /// Canonical encoding of the element: its value in `[0, MODULUS)`,
+    /// in little-endian with [`Self::encoded_len`] bytes
+    pub fn to_le_bytes(&self) -> Vec<u8> {
+        u64::from(*self).to_le_bytes()[..Self::encoded_len()].to_vec()
+    }
+
+    /// Build an element from an integer, which must be less than `MODULUS`
+    /// with [`Validate::Yes`]
+    pub(crate) fn from_u64_with_validation(
+        value: u64,
+        validate: Validate,
+    ) -> Result<Self, SerializationError> {
+        if validate == Validate::Yes && value >= Self::modulus() {
+            return Err(SerializationError::InvalidData);
+        }
+        Ok(Self(C::to_internal(value)))
+    }
+}
+
+impl<C: ConfigZZp> CanonicalSerialize for ZZp<C> {
+    /// A field element has the same compressed and uncompressed encoding
+    fn serialize_with_mode<W: Write>(
+        &self,
+        mut writer: W,
+        _compress: Compress,
+    ) -> Result<(), SerializationError> {
+        writer.write_all(&self.to_le_bytes())?;
+        Ok(())
+    }
+
+    fn serialized_size(&self, _compress: Compress) -> usize {
+        Self::encoded_len()
+    }
+}
+
+impl<C: ConfigZZp> CanonicalDeserialize for ZZp<C> {
+    fn deserialize_with_mode<R: Read>(
+        mut reader: R,
+        _compress: Compress,
+        validate: Validate,
+    ) -> Result<Self, SerializationError> {
+        let mut bytes = [0u8; 8];
+        reader.read_exact(&mut bytes[..Self::encoded_len()])?;
+        Self::from_u64_with_validation(u64::from_le_bytes(bytes), validate)
+    }
+}
+
+// ========================
+// square roots
+// ========================
+impl<C: ConfigZZp> ZZp<C> {
+    /// `(t - 1) / 2` where `MODULUS - 1 = 2^TWO_ADICITY * t` with `t` odd.
+    fn t_minus_one_over_two() -> u64 {
+        let modulus: u64 = C::MODULUS.as_();
+        ((modulus - 1) >> C::TWO_ADICITY) >> 1
+    }
+
+    /// A primitive `2^TWO_ADICITY`-th root of unity, computed as `NON_RESIDUE^t`.
+    /// It is itself a quadratic non-residue.
+    fn two_adic_root_of_unity() -> Self {
+        let modulus: u64 = C::MODULUS.as_();
+        Self::new(&C::NON_RESIDUE).pow_vartime([(modulus - 1) >> C::TWO_ADICITY])
+    }
+
+    /// Constant time Tonelli-Shanks square root.
+    ///
+    /// This is Algorithm 5 from <https://eprint.iacr.org/2012/685.pdf>,
+    /// following the implementation of `ff::helpers::sqrt_tonelli_shanks`.
+    fn sqrt_tonelli_shanks(&self) -> CtOption<Self> {
+        // w = self^((t-1)/2)
+        let w = self.pow([Self::t_minus_one_over_two()]);
+
+        let mut v = C::TWO_ADICITY;
+        let mut x = w * self;
+        let mut b = x * w;
+
+        // Initialize z as the 2^S root of unity.
+        let mut z = Self::two_adic_root_of_unity();
+
+        for max_v in (1..=C::TWO_ADICITY).rev() {
+            let mut k = 1;
+            let mut tmp = b.square();
+            let mut j_less_than_v: Choice = 1.into();
+
+            for j in 2..max_v {
+                let tmp_is_one = tmp.ct_eq(&Self::one());
+                let squared = Self::conditional_select(&tmp, &z, tmp_is_one).square();
+                tmp = Self::conditional_select(&squared, &tmp, tmp_is_one);
+                let new_z = Self::conditional_select(&z, &squared, tmp_is_one);
+                j_less_than_v &= !j.ct_eq(&v);
+                k = u32::conditional_select(&j, &k, tmp_is_one);
+                z = Self::conditional_select(&z, &new_z, j_less_than_v);
+            }
+
+            let result = x * z;
+            x = Self::conditional_select(&result, &x, b.ct_eq(&Self::one()));
+            z = z.square();
+            b *= z;
+            v = k;
+        }
+
+        CtOption::new(x, (x * x).ct_eq(&self.canonical()))
     }
 }
 
@@ -186,7 +305,7 @@ impl<C: ConfigZZp> From<ZZp<C>> for u64 {
 // ========================
 impl<C: ConfigZZp> ConstantTimeEq for ZZp<C> {
     fn ct_eq(&self, other: &Self) -> subtle::Choice {
-        self.canonical().0.ct_eq(&other.0)
+        self.canonical().0.ct_eq(&other.canonical().0)
     }
 }
 
@@ -219,23 +338,23 @@ impl<C: ConfigZZp> Field for ZZp<C> {
     }
 
     /// Returns an element chosen uniformly at random using a user-provided RNG.
-    fn random(mut rng: impl RngCore) -> Self {
-        (rng.next_u64() % C::MODULUS.into()).into()
+    fn random(rng: impl RngCore) -> Self {
+        random_below(rng, C::MODULUS.into()).into()
     }
 
-    /// Convert the element to its canonical encoding
+    /// Convert the element to its canonical encoding.
+    /// The internal value is below `2 MODULUS`, so a conditional subtraction reduces it.
     fn canonical(&self) -> Self {
-        Self(self.0 % C::MODULUS)
+        let value = ct_reduce_once_u64(self.0.as_(), C::MODULUS.as_());
+        Self(C::PrimitiveType::from_u64(value).unwrap())
     }
 
     /// Squares this element.
-    #[must_use]
     fn square(&self) -> Self {
         *self * *self
     }
 
     /// Doubles this element.
-    #[must_use]
     fn double(&self) -> Self {
         *self + *self
     }
@@ -243,7 +362,8 @@ impl<C: ConfigZZp> Field for ZZp<C> {
     /// Computes the multiplicative inverse of this element,
     /// failing if the element is zero.
     fn invert(&self) -> CtOption<Self> {
-        let tmp = self.pow_vartime([C::MODULUS.to_u64().unwrap() - 2u64]);
+        // Fermat's little theorem; `pow` does not branch on `self`
+        let tmp = self.pow([C::MODULUS.to_u64().unwrap() - 2u64]);
         CtOption::new(tmp, !self.ct_eq(&Self::zero()))
     }
 
@@ -265,7 +385,76 @@ impl<C: ConfigZZp> Field for ZZp<C> {
     /// - The choice of root from `sqrt` is unspecified.
     /// - The value of $G_S$ is unspecified, and cannot be assumed to have any specific
     ///   value in a generic context.
-    fn sqrt_ratio(_num: &Self, _div: &Self) -> (Choice, Self) {
-        todo!()
+    fn sqrt_ratio(num: &Self, div: &Self) -> (Choice, Self) {
+        // a = num/div, or 0 if div is zero; b = G_S * a where G_S is the
+        // 2^S-th root of unity, which is a non-square.
+        //
+        // a and b are either both zero (and both square), or exactly one of
+        // them is a square. The num != 0 && div == 0 case is handled
+        // separately for the boolean output.
+        let a = div.invert().unwrap_or(Self::zero()) * num;
+        let b = a * Self::two_adic_root_of_unity();
+        let sqrt_a = a.sqrt_tonelli_shanks();
+        let sqrt_b = b.sqrt_tonelli_shanks();
+
+        let num_is_zero = num.is_zero();
+        let div_is_zero = div.is_zero();
+        let is_square = sqrt_a.is_some();
+
+        (
+            is_square & (num_is_zero | !div_is_zero),
+            CtOption::conditional_select(&sqrt_b, &sqrt_a, is_square).unwrap(),
+        )
+    }
+}
+
+// ========================
+// prime field
+// ========================
+impl<C: ConfigZZp> PrimeField for ZZp<C> {
+    /// The modulus of the field
+    fn modulus() -> u64 {
+        C::MODULUS.as_()
+    }
+
+    /// Lift self into `[-MODULUS/2, MODULUS/2)`
+    fn lift(&self) -> i64 {
+        let modulus = Self::modulus() as i128;
+        let value = u64::from(*self) as i128;
+        // subtract the modulus iff value > (MODULUS-1)/2
+        let is_large = (((modulus - 1) / 2 - value) >> 127) & 1;
+        (value - is_large * modulus) as i64
+    }
+
+    /// Normalize self into `[0, MODULUS)`
+    fn normalize(&self) -> Self {
+        self.canonical()
+    }
+
+    /// Build a field element from a (possibly negative) integer,
+    /// reducing it modulo `MODULUS`
+    fn from_lift(value: i64) -> Self {
+        let modulus = Self::modulus() as i128;
+        ((value as i128).rem_euclid(modulus) as u64).into()
+    }
+}
+
+// ========================
+// NTT friendly field
+// ========================
+impl<C: ConfigZZp> NTTField for ZZp<C> {
+    /// 2-adicity of the field, i.e., the largest `s` such that `2^s | MODULUS - 1`
+    const TWO_ADICITY: u32 = C::TWO_ADICITY;
+
+    /// The generator of the multiplicative group of the field
+    const GENERATOR: Self = ZZp(C::GENERATOR);
+
+    /// Returns a primitive root of unity of order n, if one exists.
+    fn get_root_of_unity(n: u64) -> Option<Self> {
+        let order = Self::modulus() - 1;
+        if n == 0 || order % n != 0 {
+            return None;
+        }
+        Some(Self::GENERATOR.pow_vartime([order / n]))
     }
 }
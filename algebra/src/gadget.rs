@@ -0,0 +1,415 @@
+//! Gadget decomposition, i.e., `G^{-1}`, in an arbitrary base `b`.
+//!
+//! An element `x` of `ZZ_q` is decomposed into `l = ceil(log_b q)` small digits
+//! `x_i` with `sum_i b^i x_i = x`, i.e., `<g, (x_0, ..., x_{l-1})> = x` for the
+//! gadget vector `g = (1, b, ..., b^{l-1})`. Ring elements, vectors and module vectors
+//! are decomposed coefficient-wise, into `l` elements of the same type.
+//!
+//! The decompositions are constant time: the digits are extracted with a Barrett
+//! division by the public base, and the number of digits does not depend on the input.
+
+use std::{array, marker::PhantomData};
+
+use crate::{
+    field::value_barrier, sampler::small_to_field, ConfigNTTRing, ConfigZZVec, ConfigZZpX, Field,
+    ModuleVector, Polynomial, PrimeField, Vector, ZZVec, ZZp, ZZpX,
+};
+
+/// The gadget of base `b` over the ring configured by `C`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Gadget<C: ConfigZZpX> {
+    base: u64,
+    /// `floor(2^64 / b)`, for the Barrett division by `b`
+    reciprocal: u64,
+    length: usize,
+    config: PhantomData<C>,
+}
+
+impl<C: ConfigZZpX> Gadget<C> {
+    /// The gadget of base `b`, with `ceil(log_b q)` digits.
+    /// Panics unless `2 <= b < q`.
+    pub fn new(base: u64) -> Self {
+        let modulus = ZZp::<C::BaseConfig>::modulus();
+        assert!(
+            (2..modulus).contains(&base),
+            "invalid base {} for modulus {}",
+            base,
+            modulus
+        );
+        // the smallest l with b^l >= q
+        let mut length = 0;
+        let mut power = 1u128;
+        while power < modulus as u128 {
+            power *= base as u128;
+            length += 1;
+        }
+        Self {
+            base,
+            reciprocal: ((1u128 << 64) / base as u128) as u64,
+            length,
+            config: PhantomData,
+        }
+    }
+
+    /// The gadget of base `2^log_base`.
+    /// Panics unless `2^log_base < q`.
+    pub fn with_log_base(log_base: u32) -> Self {
+        assert!(log_base < u64::BITS, "invalid log base {}", log_base);
+        Self::new(1 << log_base)
+    }
+
+    /// The base `b`
+    pub fn base(&self) -> u64 {
+        self.base
+    }
+
+    /// Number of digits `l`
+    pub fn length(&self) -> usize {
+        self.length
+    }
+
+    /// The gadget vector `g = (1, b, ..., b^{l-1})`
+    pub fn gadget_vector(&self) -> Vec<ZZp<C::BaseConfig>> {
+        let base = ZZp::from(self.base);
+        let mut power = ZZp::one();
+        (0..self.length)
+            .map(|_| {
+                let res = power;
+                power *= base;
+                res
+            })
+            .collect()
+    }
+
+    /// `(floor(x / b), x mod b)`, in constant time.
+    ///
+    /// The estimate `floor(x floor(2^64 / b) / 2^64)` of the quotient is either exact
+    /// or one too small, since `x < 2^64`, and a single correction fixes it.
+    #[inline(always)]
+    fn div_rem(&self, x: u64) -> (u64, u64) {
+        let quotient = ((x as u128 * self.reciprocal as u128) >> 64) as u64;
+        // r is in [0, 2b), which may not fit in a u64
+        let r = x as u128 - quotient as u128 * self.base as u128;
+        // 1 iff r >= b, i.e., iff r - b does not underflow
+        let carry = value_barrier((r.wrapping_sub(self.base as u128) >> 127) as u64) ^ 1;
+        (quotient + carry, (r - (carry * self.base) as u128) as u64)
+    }
+
+    /// Digits in `[0, b)` of the representatives of `coeffs` in `[0, q)`,
+    /// one vector of coefficients per digit
+    fn decompose_coeffs(&self, coeffs: &[ZZp<C::BaseConfig>]) -> Vec<Vec<ZZp<C::BaseConfig>>> {
+        let mut digits = vec![Vec::with_capacity(coeffs.len()); self.length];
+        for x in coeffs {
+            let mut x = u64::from(*x);
+            for digit in digits.iter_mut() {
+                let (quotient, r) = self.div_rem(x);
+                digit.push(ZZp::from(r));
+                x = quotient;
+            }
+        }
+        digits
+    }
+
+    /// Digits in `[-b/2, b/2]` of the centered lifts of `coeffs`,
+    /// one vector of coefficients per digit
+    fn decompose_coeffs_balanced(
+        &self,
+        coeffs: &[ZZp<C::BaseConfig>],
+    ) -> Vec<Vec<ZZp<C::BaseConfig>>> {
+        let half = self.base / 2;
+        let mut digits = vec![Vec::with_capacity(coeffs.len()); self.length];
+        for x in coeffs {
+            // decompose |x| < 2^63, and negate the digits of a negative x
+            let x = x.lift();
+            let sign = value_barrier((x >> 63) as u64) as i64;
+            let mut x = ((x ^ sign) - sign) as u64;
+            let (last, init) = digits.split_last_mut().expect("at least one digit");
+            for digit in init {
+                let (quotient, r) = self.div_rem(x);
+                // r - b when r > b/2, carrying one into the next digit
+                let carry = value_barrier(((half as u128).wrapping_sub(r as u128) >> 127) as u64);
+                let r = (r as i128 - (carry * self.base) as i128) as i64;
+                digit.push(small_to_field((r ^ sign) - sign));
+                x = quotient + carry;
+            }
+            // |x| <= q / 2 <= b^l / 2, and each digit divides the bound by b,
+            // so the carry left is at most b/2
+            last.push(small_to_field((x as i64 ^ sign) - sign));
+        }
+        digits
+    }
+
+    /// `sum_i b^i digits_i`, coefficient-wise
+    fn recompose_coeffs<'a>(
+        &self,
+        digits: impl IntoIterator<Item = &'a [ZZp<C::BaseConfig>]>,
+    ) -> Vec<ZZp<C::BaseConfig>> {
+        let mut res: Vec<ZZp<C::BaseConfig>> = vec![];
+        for (g, digit) in self.gadget_vector().iter().zip(digits) {
+            res.resize(digit.len(), ZZp::zero());
+            for (x, d) in res.iter_mut().zip(digit.iter()) {
+                *x += *g * d;
+            }
+        }
+        res
+    }
+}
+
+/// Elements with a gadget decomposition
+pub trait GadgetDecomposition<C: ConfigZZpX>: Sized {
+    /// Decompose into `l` elements whose coefficients are in `[0, b)`
+    fn decompose(&self, gadget: &Gadget<C>) -> Vec<Self>;
+
+    /// Decompose into `l` elements whose coefficients are in `[-b/2, b/2]`
+    fn decompose_balanced(&self, gadget: &Gadget<C>) -> Vec<Self>;
+
+    /// Recompose `sum_i b^i digits_i`, for `l` digits
+    fn recompose(gadget: &Gadget<C>, digits: &[Self]) -> Self;
+}
+
+impl<C: ConfigZZpX> GadgetDecomposition<C> for ZZpX<C> {
+    fn decompose(&self, gadget: &Gadget<C>) -> Vec<Self> {
+        gadget
+            .decompose_coeffs(&self.coeffs)
+            .into_iter()
+            .map(Self::from_coefficients_vec_unchecked)
+            .collect()
+    }
+
+    fn decompose_balanced(&self, gadget: &Gadget<C>) -> Vec<Self> {
+        gadget
+            .decompose_coeffs_balanced(&self.coeffs)
+            .into_iter()
+            .map(Self::from_coefficients_vec_unchecked)
+            .collect()
+    }
+
+    fn recompose(gadget: &Gadget<C>, digits: &[Self]) -> Self {
+        assert_eq!(digits.len(), gadget.length(), "wrong number of digits");
+        let coeffs = gadget.recompose_coeffs(digits.iter().map(|x| &x.coeffs[..]));
+        Self::from_coefficients_vec_unchecked(coeffs)
+    }
+}
+
+impl<C, V> GadgetDecomposition<C> for ZZVec<V>
+where
+    C: ConfigZZpX,
+    V: ConfigZZVec<BaseConfig = C::BaseConfig>,
+{
+    fn decompose(&self, gadget: &Gadget<C>) -> Vec<Self> {
+        gadget
+            .decompose_coeffs(&self.coeffs)
+            .into_iter()
+            .map(Self::from_coefficients_vec_unchecked)
+            .collect()
+    }
+
+    fn decompose_balanced(&self, gadget: &Gadget<C>) -> Vec<Self> {
+        gadget
+            .decompose_coeffs_balanced(&self.coeffs)
+            .into_iter()
+            .map(Self::from_coefficients_vec_unchecked)
+            .collect()
+    }
+
+    fn recompose(gadget: &Gadget<C>, digits: &[Self]) -> Self {
+        assert_eq!(digits.len(), gadget.length(), "wrong number of digits");
+        let coeffs = gadget.recompose_coeffs(digits.iter().map(|x| &x.coeffs[..]));
+        Self::from_coefficients_vec_unchecked(coeffs)
+    }
+}
+
+impl<R: ConfigNTTRing, const K: usize> GadgetDecomposition<R> for ModuleVector<R, K> {
+    fn decompose(&self, gadget: &Gadget<R>) -> Vec<Self> {
+        let mut elements: Vec<_> = self.iter().map(|x| x.decompose(gadget)).collect();
+        transpose(gadget, &mut elements)
+    }
+
+    fn decompose_balanced(&self, gadget: &Gadget<R>) -> Vec<Self> {
+        let mut elements: Vec<_> = self.iter().map(|x| x.decompose_balanced(gadget)).collect();
+        transpose(gadget, &mut elements)
+    }
+
+    fn recompose(gadget: &Gadget<R>, digits: &[Self]) -> Self {
+        assert_eq!(digits.len(), gadget.length(), "wrong number of digits");
+        Self::new(array::from_fn(|j| {
+            let element: Vec<ZZpX<R>> = digits.iter().map(|d| d.elements()[j].clone()).collect();
+            ZZpX::recompose(gadget, &element)
+        }))
+    }
+}
+
+/// From the digits of each element of a module vector, to one vector per digit
+fn transpose<R: ConfigNTTRing, const K: usize>(
+    gadget: &Gadget<R>,
+    elements: &mut [Vec<ZZpX<R>>],
+) -> Vec<ModuleVector<R, K>> {
+    (0..gadget.length())
+        .map(|i| ModuleVector::new(array::from_fn(|j| std::mem::take(&mut elements[j][i]))))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use rand_xorshift::XorShiftRng;
+
+    use super::{Gadget, GadgetDecomposition};
+    use crate::tests::test_rng;
+    use crate::tests::timing::assert_constant_time;
+    use crate::{
+        ConfigRing12289_512, ConfigRing8380417_256, ConfigRingGoldilocks256, Field, Goldilocks,
+        ModuleVector, Polynomial, PrimeField, Ring12289_512, RingGoldilock256, Vec8380417_256,
+        Vector, ZZp, F12289,
+    };
+
+    #[test]
+    fn test_gadget_vector() {
+        // 12289 has 14 bits
+        let gadget = Gadget::<ConfigRing12289_512>::with_log_base(4);
+        assert_eq!(gadget.base(), 16);
+        assert_eq!(gadget.length(), 4);
+        let g: Vec<u64> = gadget.gadget_vector().into_iter().map(u64::from).collect();
+        assert_eq!(g, vec![1, 16, 256, 4096]);
+
+        assert_eq!(Gadget::<ConfigRing12289_512>::with_log_base(1).length(), 14);
+        assert_eq!(Gadget::<ConfigRing12289_512>::with_log_base(7).length(), 2);
+        assert_eq!(
+            Gadget::<ConfigRingGoldilocks256>::with_log_base(16).length(),
+            4
+        );
+        assert_eq!(
+            Gadget::<ConfigRingGoldilocks256>::with_log_base(32).length(),
+            2
+        );
+
+        // 3^8 < 12289 <= 3^9 and 10^4 < 12289 <= 10^5
+        let gadget = Gadget::<ConfigRing12289_512>::new(3);
+        assert_eq!(gadget.length(), 9);
+        let g: Vec<u64> = gadget.gadget_vector().into_iter().map(u64::from).collect();
+        assert_eq!(g, vec![1, 3, 9, 27, 81, 243, 729, 2187, 6561]);
+        assert_eq!(Gadget::<ConfigRing12289_512>::new(10).length(), 5);
+        assert_eq!(Gadget::<ConfigRing12289_512>::new(12288).length(), 2);
+    }
+
+    #[test]
+    fn test_decompose_ring() {
+        let mut rng = test_rng();
+        let q = F12289::modulus();
+        // extreme coefficients, then random ones
+        let mut coeffs = vec![0, 1, q - 1, (q - 1) / 2, q.div_ceil(2), 8, 4096 * 3 - 1];
+        coeffs.extend((coeffs.len()..512).map(|_| u64::from(F12289::random(&mut rng))));
+        let a = Ring12289_512::from_coefficients_vec_unchecked(
+            coeffs.into_iter().map(ZZp::from).collect(),
+        );
+
+        // powers of two, then other bases
+        for b in [2, 4, 8, 16, 128, 8192, 3, 5, 10, 111, 12288] {
+            let gadget = Gadget::new(b);
+            let digits = a.decompose(&gadget);
+            assert_eq!(digits.len(), gadget.length());
+            assert!(digits
+                .iter()
+                .all(|d| d.coefficients().all(|x| u64::from(*x) < b)));
+            assert_eq!(Ring12289_512::recompose(&gadget, &digits), a);
+
+            let digits = a.decompose_balanced(&gadget);
+            assert_eq!(digits.len(), gadget.length());
+            assert!(digits.iter().all(|d| d.infinity_norm() <= b / 2));
+            assert_eq!(Ring12289_512::recompose(&gadget, &digits), a);
+        }
+
+        // a 64-bit modulus, whose extreme coefficients overflow an i64 carry
+        let q = Goldilocks::modulus();
+        let mut coeffs = vec![0, 1, q - 1, (q - 1) / 2, q.div_ceil(2)];
+        coeffs.extend((coeffs.len()..256).map(|_| u64::from(Goldilocks::random(&mut rng))));
+        let a = RingGoldilock256::from_coefficients_vec_unchecked(
+            coeffs.into_iter().map(ZZp::from).collect(),
+        );
+        // the Barrett remainder of a base above 2^63 does not fit in a u64
+        for b in [
+            1 << 8,
+            1 << 16,
+            1 << 21,
+            1 << 32,
+            3,
+            1_000_000_007,
+            1 << 63,
+            q - 1,
+        ] {
+            let gadget = Gadget::new(b);
+            let digits = a.decompose_balanced(&gadget);
+            assert!(digits
+                .iter()
+                .all(|d| d.infinity_norm() <= gadget.base() / 2));
+            assert_eq!(RingGoldilock256::recompose(&gadget, &digits), a);
+            let digits = a.decompose(&gadget);
+            assert_eq!(RingGoldilock256::recompose(&gadget, &digits), a);
+        }
+    }
+
+    #[test]
+    fn test_decompose_vectors() {
+        let mut rng = test_rng();
+        let gadget = Gadget::<ConfigRing8380417_256>::with_log_base(6);
+        assert_eq!(gadget.length(), 4);
+
+        let v = Vec8380417_256::random(&mut rng, None);
+        let digits = v.decompose_balanced(&gadget);
+        assert!(digits.iter().all(|d| d.infinity_norm() <= 32));
+        assert_eq!(Vec8380417_256::recompose(&gadget, &digits), v);
+        let digits = v.decompose(&gadget);
+        assert!(digits
+            .iter()
+            .all(|d| d.coefficients().all(|x| u64::from(*x) < 64)));
+        assert_eq!(Vec8380417_256::recompose(&gadget, &digits), v);
+
+        let m = ModuleVector::<ConfigRing8380417_256, 3>::random(&mut rng);
+        let digits = m.decompose_balanced(&gadget);
+        assert_eq!(digits.len(), 4);
+        assert!(digits.iter().all(|d| d.infinity_norm() <= 32));
+        assert_eq!(ModuleVector::recompose(&gadget, &digits), m);
+        // digit i of the module vector holds digit i of each element
+        for (i, d) in digits.iter().enumerate() {
+            for (x, y) in d.iter().zip(m.iter()) {
+                assert_eq!(*x, y.decompose_balanced(&gadget)[i]);
+            }
+        }
+        let digits = m.decompose(&gadget);
+        assert_eq!(ModuleVector::recompose(&gadget, &digits), m);
+    }
+
+    #[test]
+    #[cfg_attr(
+        not(feature = "ct-tests"),
+        ignore = "timing test, needs the ct-tests feature"
+    )]
+    fn test_decompose_constant_time() {
+        let mut rng = test_rng();
+        let gadget = Gadget::<ConfigRingGoldilocks256>::new(1_000_000_007);
+        // zero versus uniform coefficients
+        assert_constant_time(
+            "balanced decomposition",
+            &mut rng,
+            |rng: &mut XorShiftRng, class| {
+                if class {
+                    Goldilocks::random(rng)
+                } else {
+                    Goldilocks::zero()
+                }
+            },
+            |x| gadget.decompose_coeffs_balanced(&[x]),
+        );
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_gadget_base() {
+        Gadget::<ConfigRing12289_512>::with_log_base(14);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_gadget_base_one() {
+        Gadget::<ConfigRing12289_512>::new(1);
+    }
+}
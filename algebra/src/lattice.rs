@@ -1,64 +1,16 @@
-//! Lattice interfaces
-
-use crate::matrix::Matrix;
-
-pub trait Lattice {
-    type Field;
-    type LatticeVector;
-    type Basis: Matrix;
-
-    /// Compute the determinant
-    fn determinant(&self) -> Self::Field;
-
-    /// dimension of the lattice
-    fn dimension(&self) -> usize;
-
-    /// Build a lattice from a basis
-    fn from_bases(basis: &Self::Basis) -> Self;
-
-    /// extract a bases for the lattice
-    fn bases(&self) -> Self::Basis;
-
-    /// Gaussian expected length
-    fn gaussian_length() -> Self::Field;
-
-    /// If a vector is in the lattice
-    fn contains(&self, vec: &Self::LatticeVector) -> bool;
-}
-
-pub trait LatticeReduction: Lattice {
-    type GramSchmidtBasis;
-
-    /// GramSchmidt bases
-    fn gram_schmidt_bases(&self) -> Self::GramSchmidtBasis;
-
-    /// Babai nearest plane algorithm
-    fn babai(&self, vec: &Self::LatticeVector) -> Self::LatticeVector;
-
-    /// LLL lattice reduction
-    /// (prob not going to implement for phase 1)
-    fn lll(base: &Self::Basis) -> Self::Basis;
-
-    /// BKZ lattice reduction
-    /// (prob not going to implement for phase 1)
-    fn bkz(base: &Self::Basis) -> Self::Basis;
-}
-
-pub trait IdealLattice: Lattice {
-    /// Underlying ring for the ideal lattice
-    type Ring;
-
-    /// Build the lattice from the ring
-    fn from_ring_element(elem: &Self::Ring) -> Self;
-}
-
-pub trait ModularLattice: Lattice {
-    /// Underlying ring for the ideal lattice
-    type Ring;
-
-    /// Get the rank of the lattice
-    fn rank(&self) -> usize;
-
-    /// Build the lattice from the ring
-    fn from_ring_elements(elem: &[Self::Ring]) -> Self;
-}
+//! Lattice APIs
+
+mod bkz;
+mod definition;
+mod gram_schmidt;
+mod integer;
+mod linear_algebra;
+mod lll;
+mod structured;
+
+pub use bkz::{bkz_reduce, bkz_reduce_with_observer, BkzParameters, BkzProgress};
+pub use definition::{IdealLattice, Lattice, LatticeReduction, ModularLattice};
+pub use gram_schmidt::GramSchmidtBasis;
+pub use integer::IntegerLattice;
+pub use lll::lll_reduce;
+pub use structured::{ModuleSisLattice, RingIdealLattice};
@@ -0,0 +1,568 @@
+//! BKZ 2.0 lattice reduction.
+//!
+//! Each tour calls an SVP oracle, Schnorr-Euchner enumeration, on the projected
+//! blocks `[k, k + block_size)` of the basis, inserts the vector found, and cleans up
+//! with LLL. Following Chen and Nguyen, the enumeration radius is capped by the
+//! Gaussian heuristic of the block, the enumeration can be pruned, and the reduction
+//! aborts once the Gram-Schmidt profile stops improving.
+
+use std::f64::consts::{LN_2, PI};
+
+use num::{BigInt, BigRational, One, Signed, ToPrimitive, Zero};
+
+use super::{
+    gram_schmidt::Gso,
+    integer::{ln_gamma_half, IntegerLattice},
+    lll::{gsa_slope, lll_in_place},
+};
+use crate::{DenseMatrix, Lattice};
+
+/// Number of tours without improvement of the slope before an auto abort
+const AUTO_ABORT_TOURS: usize = 5;
+
+/// Relative improvement of the slope that resets the auto abort
+const AUTO_ABORT_SCALE: f64 = 1e-4;
+
+/// The Gaussian heuristic is only a good estimate for larger blocks; smaller blocks
+/// ignore [`BkzParameters::gh_factor`]
+const GH_BOUND_MIN_BLOCK_SIZE: usize = 30;
+
+/// Parameters of the BKZ reduction
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct BkzParameters {
+    /// Size of the blocks
+    pub block_size: usize,
+    /// Parameter of LLL, and the improvement required to insert a vector
+    pub delta: f64,
+    /// Stop after this many tours
+    pub max_tours: Option<usize>,
+    /// Stop when the slope of the Gram-Schmidt log norms stops improving
+    pub auto_abort: bool,
+    /// Cap the enumeration radius to this factor times the Gaussian heuristic of the
+    /// block, for blocks of more than 30 vectors
+    pub gh_factor: Option<f64>,
+    /// Prune the enumeration linearly: the partial norm over the last `j` of the
+    /// `block_size` coordinates is bounded by `j / block_size` times the radius
+    pub linear_pruning: bool,
+}
+
+impl BkzParameters {
+    /// BKZ 2.0 defaults for a block size: `delta = 0.99`, auto abort, and an
+    /// enumeration radius of at most `1.1` times the Gaussian heuristic.
+    pub fn new(block_size: usize) -> Self {
+        Self {
+            block_size,
+            delta: 0.99,
+            max_tours: None,
+            auto_abort: true,
+            gh_factor: Some(1.1),
+            linear_pruning: false,
+        }
+    }
+}
+
+/// Statistics reported to the observer after each tour
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct BkzProgress {
+    /// Number of tours done, starting from 1
+    pub tour: usize,
+    /// Number of vectors inserted during the tour
+    pub insertions: usize,
+    /// Norm of the first basis vector
+    pub first_norm: f64,
+    /// Root Hermite factor of the basis
+    pub root_hermite_factor: f64,
+    /// Slope of the Gram-Schmidt log norms
+    pub gsa_slope: f64,
+}
+
+/// Gram-Schmidt coefficients and squared norms of a basis in `f64`, for the
+/// enumeration. The norms are divided by `2^scale`, so that they stay in range; the
+/// enumeration only depends on their ratios.
+struct FloatGso {
+    mu: Vec<Vec<f64>>,
+    norms: Vec<f64>,
+    scale: i64,
+}
+
+impl FloatGso {
+    /// Natural logarithms of the squared norms `|b_i*|^2`
+    fn log_norms(&self) -> Vec<f64> {
+        let shift = self.scale as f64 * LN_2;
+        self.norms.iter().map(|x| x.ln() + shift).collect()
+    }
+}
+
+/// Schnorr-Euchner enumeration of the shortest nonzero vector of the projected
+/// block `[start, start + n)`, given the Gram-Schmidt coefficients of the basis.
+struct Enumeration<'a> {
+    mu: &'a [Vec<f64>],
+    norms: &'a [f64],
+    start: usize,
+    /// Relative bound on the partial norm at each level
+    pruning: Vec<f64>,
+    /// Squared radius, shrunk when a solution is found
+    radius2: f64,
+    x: Vec<i64>,
+    best: Option<Vec<i64>>,
+}
+
+impl<'a> Enumeration<'a> {
+    /// Search the levels `i` and below, with the partial norm of the levels above
+    fn search(&mut self, i: usize, partial: f64) {
+        let n = self.x.len();
+        let center = -(i + 1..n)
+            .map(|j| self.x[j] as f64 * self.mu[self.start + j][self.start + i])
+            .sum::<f64>();
+        // the vectors are enumerated up to sign
+        let top_zero = self.x[i + 1..].iter().all(|&x| x == 0);
+
+        let first = center.round();
+        let up = center >= first;
+        for step in 0.. {
+            let xi = if top_zero {
+                step as f64
+            } else {
+                // zig-zag around the center, by increasing distance
+                let offset = ((step + 1) / 2) as f64;
+                if (step % 2 == 1) == up {
+                    first + offset
+                } else {
+                    first - offset
+                }
+            };
+            let diff = xi - center;
+            let l = partial + diff * diff * self.norms[self.start + i];
+            if l >= self.radius2 * self.pruning[i] {
+                break;
+            }
+            self.x[i] = xi as i64;
+            if i > 0 {
+                self.search(i - 1, l);
+            } else if !(top_zero && xi == 0.0) {
+                self.best = Some(self.x.clone());
+                self.radius2 = l;
+            }
+        }
+        self.x[i] = 0;
+    }
+}
+
+/// Coefficients of a shortest nonzero vector of the projected block `[start, end)`
+/// with squared norm below `radius2`, if any
+fn enumerate(
+    gso: &FloatGso,
+    start: usize,
+    end: usize,
+    radius2: f64,
+    linear_pruning: bool,
+) -> Option<Vec<i64>> {
+    let n = end - start;
+    let mut enumeration = Enumeration {
+        mu: &gso.mu,
+        norms: &gso.norms,
+        start,
+        pruning: (0..n)
+            .map(|i| {
+                if linear_pruning {
+                    (n - i) as f64 / n as f64
+                } else {
+                    1.0
+                }
+            })
+            .collect(),
+        radius2,
+        x: vec![0; n],
+        best: None,
+    };
+    enumeration.search(n - 1, 0.0);
+    enumeration.best
+}
+
+/// Insert `v = sum_i x_i b_{start + i}` at position `start`, keeping a basis.
+///
+/// The coefficients are merged with Euclid's algorithm on pairs of rows, which
+/// preserves both `v` and the lattice. As `v` is primitive, this ends with a single
+/// coefficient `+-1`.
+fn insert(rows: &mut [Vec<BigInt>], start: usize, x: &[i64]) {
+    let mut x: Vec<BigInt> = x.iter().map(|&x| BigInt::from(x)).collect();
+    let mut p = x.iter().position(|x| !x.is_zero()).expect("nonzero vector");
+    for j in p + 1..x.len() {
+        let mut j = j;
+        while !x[j].is_zero() {
+            // x_p b_p + x_j b_j = (x_p - q x_j) b_p + x_j (b_j + q b_p)
+            let q = &x[p] / &x[j];
+            let (b_p, b_j) = (rows[start + p].clone(), &mut rows[start + j]);
+            b_j.iter_mut()
+                .zip(b_p.iter())
+                .for_each(|(y, z)| *y += &q * z);
+            x[p] = &x[p] - &q * &x[j];
+            std::mem::swap(&mut p, &mut j);
+        }
+    }
+    assert!(x[p].abs().is_one(), "the vector is not primitive");
+    rows[start..=start + p].rotate_right(1);
+}
+
+/// Squared Gaussian heuristic of the projected block `[start, end)`
+fn gaussian_heuristic_squared(norms: &[f64], start: usize, end: usize) -> f64 {
+    let n = end - start;
+    let log_volume = norms[start..end].iter().map(|x| x.ln()).sum::<f64>() / 2.0;
+    (2.0 * (ln_gamma_half(n) + log_volume) / n as f64).exp() / PI
+}
+
+/// Gram-Schmidt coefficients of the basis in `f64`. When the inner products are out
+/// of its range, or the precision is exhausted, they are computed exactly, then
+/// rounded.
+fn float_gso(rows: &[Vec<BigInt>]) -> FloatGso {
+    let mut gso = Gso::<f64>::new(rows);
+    if gso.update_all().is_some() {
+        return FloatGso {
+            mu: gso.mu,
+            norms: gso.norms,
+            scale: 0,
+        };
+    }
+
+    let mut gso = Gso::<BigRational>::new(rows);
+    gso.update_all().expect("the basis is linearly dependent");
+    let log2 = |x: &BigRational| x.numer().bits() as i64 - x.denom().bits() as i64;
+    // keep the largest norm around 1 if it is out of the comfortable range of f64
+    let max = gso.norms.iter().map(log2).max().unwrap_or(0);
+    let scale = if max.abs() > 512 { max } else { 0 };
+    let factor = if scale >= 0 {
+        BigRational::from_integer(BigInt::one() << scale)
+    } else {
+        BigRational::new(BigInt::one(), BigInt::one() << -scale)
+    };
+    let to_f64 = |x: &BigRational| x.to_f64().expect("a conversion to f64 does not fail");
+    FloatGso {
+        mu: gso
+            .mu
+            .iter()
+            .map(|row| row.iter().map(to_f64).collect())
+            .collect(),
+        norms: gso.norms.iter().map(|x| to_f64(&(x / &factor))).collect(),
+        scale,
+    }
+}
+
+/// One BKZ tour; returns the number of insertions
+fn tour(rows: &mut [Vec<BigInt>], params: &BkzParameters) -> usize {
+    let n = rows.len();
+    let mut insertions = 0;
+    for k in 0..n - 1 {
+        let end = (k + params.block_size).min(n);
+        let gso = float_gso(rows);
+        let mut radius2 = params.delta * gso.norms[k];
+        match params.gh_factor {
+            Some(factor) if end - k > GH_BOUND_MIN_BLOCK_SIZE => {
+                let gh2 = gaussian_heuristic_squared(&gso.norms, k, end);
+                radius2 = radius2.min(factor * factor * gh2);
+            }
+            _ => (),
+        }
+        if let Some(x) = enumerate(&gso, k, end, radius2, params.linear_pruning) {
+            insert(rows, k, &x);
+            lll_in_place(&mut rows[..end], params.delta);
+            insertions += 1;
+        }
+    }
+    insertions
+}
+
+/// BKZ reduction of the rows of `basis`, which must be linearly independent.
+/// See [`bkz_reduce_with_observer`].
+pub fn bkz_reduce(basis: &DenseMatrix<BigInt>, params: &BkzParameters) -> DenseMatrix<BigInt> {
+    bkz_reduce_with_observer(basis, params, |_| true)
+}
+
+/// BKZ reduction of the rows of `basis`, which must be linearly independent.
+///
+/// Tours run until none inserts a vector, or one of the aborts of `params` triggers.
+/// The observer is called after each tour, and stops the reduction by returning false.
+pub fn bkz_reduce_with_observer(
+    basis: &DenseMatrix<BigInt>,
+    params: &BkzParameters,
+    mut observer: impl FnMut(&BkzProgress) -> bool,
+) -> DenseMatrix<BigInt> {
+    assert!(params.block_size >= 2, "the block size must be at least 2");
+    assert!(
+        params.delta > 0.25 && params.delta < 1.0,
+        "delta must be in (1/4, 1)"
+    );
+    let mut rows = basis.rows.clone();
+    lll_in_place(&mut rows, params.delta);
+
+    let n = rows.len();
+    if n < 2 {
+        return DenseMatrix {
+            rows,
+            num_columns: basis.num_columns,
+        };
+    }
+    let mut best_slope = gsa_slope(&float_gso(&rows).norms);
+    let mut no_improvement = 0;
+    let mut tours = 0;
+    loop {
+        let insertions = tour(&mut rows, params);
+        tours += 1;
+
+        let gso = float_gso(&rows);
+        let slope = gsa_slope(&gso.norms);
+        let log_norms = gso.log_norms();
+        let log_volume = log_norms.iter().sum::<f64>() / 2.0;
+        let log_first_norm = log_norms[0] / 2.0;
+        let progress = BkzProgress {
+            tour: tours,
+            insertions,
+            first_norm: log_first_norm.exp(),
+            root_hermite_factor: ((log_first_norm - log_volume / n as f64) / n as f64).exp(),
+            gsa_slope: slope,
+        };
+        log::debug!("BKZ-{}: {:?}", params.block_size, progress);
+        if !observer(&progress) || insertions == 0 {
+            break;
+        }
+        if params.max_tours.is_some_and(|max| tours >= max) {
+            break;
+        }
+        if slope > best_slope + AUTO_ABORT_SCALE * best_slope.abs() {
+            best_slope = slope;
+            no_improvement = 0;
+        } else {
+            no_improvement += 1;
+            if params.auto_abort && no_improvement >= AUTO_ABORT_TOURS {
+                break;
+            }
+        }
+    }
+    // insertions only clean up to the end of their block
+    lll_in_place(&mut rows, params.delta);
+    DenseMatrix {
+        rows,
+        num_columns: basis.num_columns,
+    }
+}
+
+impl IntegerLattice {
+    /// The same lattice, with a BKZ reduced basis. See [`bkz_reduce`].
+    pub fn bkz(&self, params: &BkzParameters) -> Self {
+        let res = Self::from_bases(&bkz_reduce(&self.basis, params));
+        if let Some(hnf) = self.hnf.get() {
+            res.hnf.get_or_init(|| hnf.clone());
+        }
+        res
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use num::{BigInt, BigRational, Signed, ToPrimitive};
+    use rand::Rng;
+    use rand_xorshift::XorShiftRng;
+
+    use super::{bkz_reduce, bkz_reduce_with_observer, BkzParameters};
+    use crate::lattice::gram_schmidt::Gso;
+    use crate::tests::test_rng;
+    use crate::{
+        lll_reduce, DenseMatrix, Field, Goldilocks, IntegerLattice, Lattice, LatticeReduction,
+        Matrix, F3329,
+    };
+
+    fn squared_norm(v: &[BigInt]) -> BigInt {
+        v.iter().map(|x| x * x).sum()
+    }
+
+    fn random_q_ary(rng: &mut XorShiftRng, n: usize, m: usize) -> IntegerLattice {
+        let a = DenseMatrix::from_row_vectors(
+            (0..n)
+                .map(|_| (0..m).map(|_| F3329::random(&mut *rng)).collect())
+                .collect(),
+        );
+        IntegerLattice::q_ary_orthogonal(&a)
+    }
+
+    #[test]
+    fn test_bkz_q_ary() {
+        let mut rng = test_rng();
+        let lattice = random_q_ary(&mut rng, 12, 32);
+        let lll = lattice.lll(0.99);
+        let bkz = LatticeReduction::bkz(&lattice, &BkzParameters::new(10));
+        assert_eq!(bkz, lattice);
+
+        // BKZ-10 beats LLL, and gets close to the expected 1.0125 root Hermite factor
+        assert!(bkz.root_hermite_factor() < lll.root_hermite_factor());
+        assert!(bkz.root_hermite_factor() < 1.015);
+        assert!(bkz.gsa_slope() > lll.gsa_slope());
+        let b1 = |lattice: &IntegerLattice| squared_norm(lattice.bases().row(0));
+        assert!(b1(&bkz).to_f64().unwrap().sqrt() < 1.1 * bkz.gaussian_length());
+
+        // a single block, with the enumeration radius capped by the Gaussian heuristic
+        let svp = lattice.bkz(&BkzParameters::new(32));
+        assert_eq!(svp, lattice);
+        assert!(b1(&svp) <= b1(&bkz));
+
+        // the output is still LLL reduced
+        let mut gso = Gso::<BigRational>::new(&bkz.bases().rows);
+        gso.update_all().unwrap();
+        let (eta, delta) = (
+            BigRational::new(51.into(), 100.into()),
+            BigRational::new(99.into(), 100.into()),
+        );
+        for k in 1..bkz.dimension() {
+            assert!((0..k).all(|j| gso.mu[k][j].abs() <= eta));
+            let mu = &gso.mu[k][k - 1];
+            assert!(gso.norms[k] >= (&delta - mu * mu) * &gso.norms[k - 1]);
+        }
+    }
+
+    #[test]
+    fn test_bkz_large_entries() {
+        // 2^600 times a lattice: the reduced basis has entries beyond the f64 mantissa,
+        // and squared norms beyond its range; a shortest vector is 2^600 times as long
+        let mut rng = test_rng();
+        let n = 6;
+        let basis: Vec<Vec<BigInt>> = (0..n)
+            .map(|i| {
+                (0..n)
+                    .map(|j| {
+                        let x: i64 = rng.gen_range(-20..=20);
+                        BigInt::from(if i == j { x + 60 } else { x })
+                    })
+                    .collect()
+            })
+            .collect();
+        let scaled: Vec<Vec<BigInt>> = basis
+            .iter()
+            .map(|row| row.iter().map(|x| x << 600).collect())
+            .collect();
+        let mut params = BkzParameters::new(n);
+        params.gh_factor = None;
+        let reduced = bkz_reduce(&DenseMatrix::from_row_vectors(basis), &params);
+
+        let mut progress = vec![];
+        let scaled_reduced =
+            bkz_reduce_with_observer(&DenseMatrix::from_row_vectors(scaled), &params, |p| {
+                progress.push(*p);
+                true
+            });
+        assert!(scaled_reduced.rows[0].iter().any(|x| x.bits() > 53));
+        assert_eq!(
+            squared_norm(scaled_reduced.row(0)),
+            squared_norm(reduced.row(0)) << 1200
+        );
+        assert!(progress
+            .iter()
+            .all(|p| p.root_hermite_factor.is_finite() && p.gsa_slope.is_finite()));
+        // the squared norms overflow f64, but not the statistics
+        let first = progress.last().unwrap().first_norm;
+        let expected = squared_norm(reduced.row(0)).to_f64().unwrap().sqrt() * 2f64.powi(600);
+        assert!((first / expected - 1.0).abs() < 1e-9);
+        let lattice = IntegerLattice::from_bases(&reduced);
+        let rhf = progress.last().unwrap().root_hermite_factor;
+        assert!((rhf / lattice.root_hermite_factor() - 1.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_bkz_goldilocks() {
+        // over the 64-bit Goldilocks modulus, the LLL reduced basis still has entries
+        // of about q^(5/6) = 2^53
+        let mut rng = test_rng();
+        let a = DenseMatrix::from_row_vectors(
+            (0..5)
+                .map(|_| (0..6).map(|_| Goldilocks::random(&mut rng)).collect())
+                .collect(),
+        );
+        let lattice = IntegerLattice::q_ary_orthogonal(&a);
+        let lll = lattice.lll(0.99);
+        assert!(lll.bases().rows.iter().flatten().any(|x| x.bits() > 53));
+        let bkz = lattice.bkz(&BkzParameters::new(6));
+        assert_eq!(bkz, lattice);
+        let b1 = |lattice: &IntegerLattice| squared_norm(lattice.bases().row(0));
+        assert!(b1(&bkz) <= b1(&lll));
+        assert!(b1(&bkz).to_f64().unwrap().sqrt() < 1.1 * bkz.gaussian_length());
+    }
+
+    #[test]
+    fn test_bkz_shortest_vector() {
+        // with a block covering the whole basis, BKZ solves SVP
+        let mut rng = test_rng();
+        let n = 5;
+        let basis = DenseMatrix::from_row_vectors(
+            (0..n)
+                .map(|i| {
+                    (0..n)
+                        .map(|j| {
+                            let x: i64 = rng.gen_range(-20..=20);
+                            BigInt::from(if i == j { x + 60 } else { x })
+                        })
+                        .collect()
+                })
+                .collect(),
+        );
+        let mut params = BkzParameters::new(n);
+        params.gh_factor = None;
+        let reduced = bkz_reduce(&basis, &params);
+        assert_eq!(
+            IntegerLattice::from_bases(&reduced),
+            IntegerLattice::from_bases(&basis)
+        );
+
+        // brute force over small coefficients of an LLL reduced basis
+        let lll = lll_reduce(&basis, 0.99);
+        let bound = 3i64;
+        let width = (2 * bound + 1) as usize;
+        let shortest = (1..width.pow(n as u32))
+            .map(|mut index| {
+                let mut v = vec![BigInt::from(0); n];
+                for row in lll.row_vectors() {
+                    let c = BigInt::from((index % width) as i64 - bound);
+                    index /= width;
+                    v.iter_mut().zip(row.iter()).for_each(|(x, y)| *x += &c * y);
+                }
+                squared_norm(&v)
+            })
+            .filter(|x| *x > BigInt::from(0))
+            .min()
+            .unwrap();
+        assert_eq!(squared_norm(reduced.row(0)), shortest);
+    }
+
+    #[test]
+    fn test_bkz_observer() {
+        let mut rng = test_rng();
+        let lattice = random_q_ary(&mut rng, 8, 24);
+
+        let mut params = BkzParameters::new(6);
+        params.linear_pruning = true;
+        params.max_tours = Some(1);
+        let mut calls = vec![];
+        let reduced = bkz_reduce_with_observer(&lattice.bases(), &params, |progress| {
+            calls.push(*progress);
+            true
+        });
+        assert_eq!(calls.len(), 1);
+        assert_eq!(calls[0].tour, 1);
+        assert_eq!(IntegerLattice::from_bases(&reduced), lattice);
+
+        // returning false stops after the first tour
+        params.max_tours = None;
+        let mut tours = 0;
+        bkz_reduce_with_observer(&lattice.bases(), &params, |_| {
+            tours += 1;
+            false
+        });
+        assert_eq!(tours, 1);
+
+        // without an early stop, the tours run until nothing is inserted
+        let mut last = None;
+        bkz_reduce_with_observer(&lattice.bases(), &BkzParameters::new(6), |progress| {
+            assert!(progress.root_hermite_factor > 1.0);
+            last = Some(*progress);
+            true
+        });
+        let last = last.unwrap();
+        assert!(last.insertions == 0 || last.tour >= super::AUTO_ABORT_TOURS);
+    }
+}
@@ -0,0 +1,75 @@
+//! Lattice interfaces
+
+use super::BkzParameters;
+use crate::matrix::Matrix;
+
+/// larkwork's lattice trait
+///
+/// A lattice is given by a basis, whose rows are linearly independent vectors.
+pub trait Lattice: Sized {
+    /// Integers, for the determinant
+    type Integer;
+
+    /// Vectors of the ambient space
+    type LatticeVector;
+
+    /// Basis of the lattice, one vector per row
+    type Basis: Matrix;
+
+    /// Compute the determinant, i.e., the volume of the fundamental domain
+    fn determinant(&self) -> Self::Integer;
+
+    /// dimension (rank) of the lattice
+    fn dimension(&self) -> usize;
+
+    /// Build a lattice from a basis
+    fn from_bases(basis: &Self::Basis) -> Self;
+
+    /// extract a bases for the lattice
+    fn bases(&self) -> Self::Basis;
+
+    /// Gaussian expected length of the shortest vector
+    fn gaussian_length(&self) -> f64;
+
+    /// If a vector is in the lattice
+    fn contains(&self, vec: &Self::LatticeVector) -> bool;
+}
+
+/// Lattice reduction algorithms
+pub trait LatticeReduction: Lattice {
+    /// Gram-Schmidt orthogonalization of a basis
+    type GramSchmidtBasis;
+
+    /// GramSchmidt bases
+    fn gram_schmidt_bases(&self) -> Self::GramSchmidtBasis;
+
+    /// Babai nearest plane algorithm
+    fn babai(&self, vec: &Self::LatticeVector) -> Self::LatticeVector;
+
+    /// LLL lattice reduction with parameter `delta` in `(1/4, 1)`
+    fn lll(&self, delta: f64) -> Self;
+
+    /// BKZ 2.0 lattice reduction
+    fn bkz(&self, params: &BkzParameters) -> Self;
+}
+
+/// Ideal lattices, built from a ring element
+pub trait IdealLattice: Lattice {
+    /// Underlying ring for the ideal lattice
+    type Ring;
+
+    /// Build the lattice from the ring
+    fn from_ring_element(elem: &Self::Ring) -> Self;
+}
+
+/// Module lattices, built from ring elements
+pub trait ModularLattice: Lattice {
+    /// Underlying ring for the ideal lattice
+    type Ring;
+
+    /// Get the rank of the lattice
+    fn rank(&self) -> usize;
+
+    /// Build the lattice from the ring
+    fn from_ring_elements(elem: &[Self::Ring]) -> Self;
+}
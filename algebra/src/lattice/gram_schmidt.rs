@@ -0,0 +1,547 @@
+//! Gram-Schmidt orthogonalization, and Babai's algorithms for the closest vector
+//! problem.
+
+use std::ops::{Add, Div, Mul, Neg, Sub};
+use std::sync::OnceLock;
+
+use num::{BigInt, BigRational, FromPrimitive, Signed, ToPrimitive, Zero};
+
+use super::{integer::IntegerLattice, linear_algebra::inner_product};
+use crate::{DenseMatrix, Matrix};
+
+/// Scalars for the Gram-Schmidt coefficients
+pub(super) trait GsoScalar:
+    Clone
+    + PartialOrd
+    + Add<Output = Self>
+    + Sub<Output = Self>
+    + Mul<Output = Self>
+    + Div<Output = Self>
+    + Neg<Output = Self>
+{
+    /// Bound on the Gram-Schmidt coefficients of a size reduced basis
+    const ETA: f64;
+
+    /// Convert an integer; None if it cannot be represented exactly
+    fn from_integer(x: &BigInt) -> Option<Self>;
+
+    /// Convert an integer to the closest scalar; None if it is out of range
+    fn from_integer_rounded(x: &BigInt) -> Option<Self>;
+
+    /// Convert a constant
+    fn from_f64(x: f64) -> Self;
+
+    /// Closest integer, as a scalar
+    fn nearest_integer(&self) -> Self;
+
+    /// Closest integer; None if the value is not finite
+    fn round_to_integer(&self) -> Option<BigInt>;
+
+    /// Absolute value
+    fn magnitude(&self) -> Self;
+}
+
+impl GsoScalar for f64 {
+    // slack for rounding errors
+    const ETA: f64 = 0.51;
+
+    fn from_integer(x: &BigInt) -> Option<Self> {
+        if x.bits() <= f64::MANTISSA_DIGITS as u64 {
+            x.to_f64()
+        } else {
+            None
+        }
+    }
+
+    fn from_integer_rounded(x: &BigInt) -> Option<Self> {
+        x.to_f64().filter(|x| x.is_finite())
+    }
+
+    fn from_f64(x: f64) -> Self {
+        x
+    }
+
+    fn nearest_integer(&self) -> Self {
+        self.round()
+    }
+
+    fn round_to_integer(&self) -> Option<BigInt> {
+        BigInt::from_f64(self.round())
+    }
+
+    fn magnitude(&self) -> Self {
+        self.abs()
+    }
+}
+
+impl GsoScalar for BigRational {
+    const ETA: f64 = 0.5;
+
+    fn from_integer(x: &BigInt) -> Option<Self> {
+        Some(BigRational::from_integer(x.clone()))
+    }
+
+    fn from_integer_rounded(x: &BigInt) -> Option<Self> {
+        <Self as GsoScalar>::from_integer(x)
+    }
+
+    fn from_f64(x: f64) -> Self {
+        BigRational::from_float(x).expect("finite constant")
+    }
+
+    fn nearest_integer(&self) -> Self {
+        self.round()
+    }
+
+    fn round_to_integer(&self) -> Option<BigInt> {
+        Some(self.round().to_integer())
+    }
+
+    fn magnitude(&self) -> Self {
+        self.abs()
+    }
+}
+
+/// Gram-Schmidt coefficients `mu` and squared norms `|b_i*|^2` of a basis,
+/// updated one row at a time.
+///
+/// The inner products of the rows are computed exactly over the integers, then
+/// converted, so that large entries only cost the precision of the conversion.
+#[derive(Debug, Clone)]
+pub(super) struct Gso<S> {
+    /// Rows of the basis
+    pub(super) rows: Vec<Vec<BigInt>>,
+    /// `mu[i][j] = <b_i, b_j*> / |b_j*|^2` for `j < i`
+    pub(super) mu: Vec<Vec<S>>,
+    /// `|b_i*|^2`
+    pub(super) norms: Vec<S>,
+}
+
+impl<S: GsoScalar> Gso<S> {
+    /// Copy the basis, without computing the coefficients yet
+    pub(super) fn new(rows: &[Vec<BigInt>]) -> Self {
+        let n = rows.len();
+        Self {
+            rows: rows.to_vec(),
+            mu: vec![vec![S::from_f64(0.0); n]; n],
+            norms: vec![S::from_f64(0.0); n],
+        }
+    }
+
+    /// Update the coefficients of row `k`, assuming the previous rows are up to date.
+    /// Returns None if `b_k*` is not positive, i.e., the rows are dependent or the
+    /// precision is exhausted, or if an inner product is out of range.
+    pub(super) fn update_row(&mut self, k: usize) -> Option<()> {
+        let zero = S::from_f64(0.0);
+        let dot = |a: &[BigInt], b: &[BigInt]| S::from_integer_rounded(&inner_product(a, b));
+        let mut norm = dot(&self.rows[k], &self.rows[k])?;
+        for j in 0..k {
+            let mut s = dot(&self.rows[k], &self.rows[j])?;
+            for i in 0..j {
+                s = s - self.mu[j][i].clone() * self.mu[k][i].clone() * self.norms[i].clone();
+            }
+            let mu = s / self.norms[j].clone();
+            norm = norm - mu.clone() * mu.clone() * self.norms[j].clone();
+            self.mu[k][j] = mu;
+        }
+        if norm > zero {
+            self.norms[k] = norm;
+            Some(())
+        } else {
+            None
+        }
+    }
+
+    /// Compute the coefficients of all rows
+    pub(super) fn update_all(&mut self) -> Option<()> {
+        (0..self.rows.len()).try_for_each(|k| self.update_row(k))
+    }
+}
+
+/// Gram-Schmidt orthogonalization `b_i = b_i* + sum_{j < i} mu_{i,j} b_j*` of a
+/// basis, with the norms of the `b_i*` cached.
+///
+/// The coefficients are computed in `f64`. When the entries of the basis do not fit
+/// in the mantissa, they are computed exactly instead, and the `f64` accessors return their
+/// closest values; Babai's algorithms then run on the exact coefficients.
+#[derive(Debug, Clone)]
+pub struct GramSchmidtBasis {
+    basis: DenseMatrix<BigInt>,
+    vectors: Vec<Vec<f64>>,
+    mu: Vec<Vec<f64>>,
+    squared_norms: Vec<f64>,
+    norms: Vec<f64>,
+    /// Exact coefficients, when the basis or a target does not fit in `f64`
+    exact: OnceLock<Gso<BigRational>>,
+}
+
+impl GramSchmidtBasis {
+    /// Orthogonalize a basis, whose rows must be linearly independent
+    pub fn new(basis: &DenseMatrix<BigInt>) -> Self {
+        let exact = OnceLock::new();
+        let fits = basis
+            .rows
+            .iter()
+            .flatten()
+            .all(|x| f64::from_integer(x).is_some());
+        let float_gso = fits
+            .then(|| Gso::<f64>::new(&basis.rows))
+            .and_then(|mut gso| {
+                gso.update_all()?;
+                Some((gso.mu, gso.norms))
+            });
+        let (mu, squared_norms) = match float_gso {
+            Some(gso) => gso,
+            None => {
+                let gso = exact.get_or_init(|| exact_gso(&basis.rows));
+                (
+                    gso.mu
+                        .iter()
+                        .map(|row| row.iter().map(to_f64).collect())
+                        .collect(),
+                    gso.norms.iter().map(to_f64).collect(),
+                )
+            }
+        };
+
+        let mut vectors: Vec<Vec<f64>> = Vec::with_capacity(basis.rows.len());
+        for (i, row) in basis.rows.iter().enumerate() {
+            let mut v: Vec<f64> = row.iter().map(to_f64).collect();
+            for (j, b_star) in vectors.iter().enumerate() {
+                v.iter_mut()
+                    .zip(b_star.iter())
+                    .for_each(|(x, y)| *x -= mu[i][j] * y);
+            }
+            vectors.push(v);
+        }
+        let norms = squared_norms.iter().map(|x| x.sqrt()).collect();
+
+        Self {
+            basis: basis.clone(),
+            vectors,
+            mu,
+            squared_norms,
+            norms,
+            exact,
+        }
+    }
+
+    /// Number of vectors
+    pub fn dimension(&self) -> usize {
+        self.vectors.len()
+    }
+
+    /// The original basis
+    pub fn basis(&self) -> &DenseMatrix<BigInt> {
+        &self.basis
+    }
+
+    /// The orthogonal vectors `b_i*`
+    pub fn vectors(&self) -> &[Vec<f64>] {
+        &self.vectors
+    }
+
+    /// The coefficients `mu[i][j] = <b_i, b_j*> / |b_j*|^2`, for `j < i`
+    pub fn coefficients(&self) -> &[Vec<f64>] {
+        &self.mu
+    }
+
+    /// Squared norms `|b_i*|^2`
+    pub fn squared_norms(&self) -> &[f64] {
+        &self.squared_norms
+    }
+
+    /// Norms `|b_i*|`
+    pub fn norms(&self) -> &[f64] {
+        &self.norms
+    }
+
+    /// Coordinates `<t, b_i*> / |b_i*|^2` of the projection of `target` onto the
+    /// span of the basis, in the orthogonal basis.
+    ///
+    /// The inner products with the basis are exact, so that the rounding errors do
+    /// not grow with the norm of the target.
+    pub fn coordinates(&self, target: &[BigInt]) -> Vec<f64> {
+        self.float_coordinates(target)
+            .unwrap_or_else(|| self.exact_coordinates(target).iter().map(to_f64).collect())
+    }
+
+    /// Babai's nearest plane algorithm: a lattice vector `v` such that `target - v`
+    /// is in the fundamental parallelepiped `{ sum_i x_i b_i* : |x_i| <= 1/2 }`.
+    pub fn nearest_plane(&self, target: &[BigInt]) -> Vec<BigInt> {
+        let c = self
+            .float_coordinates(target)
+            .and_then(|y| nearest_plane_with(&self.mu, y))
+            .unwrap_or_else(|| {
+                let y = self.exact_coordinates(target);
+                nearest_plane_with(&self.exact().mu, y).expect("exact")
+            });
+        self.combination(&c)
+    }
+
+    /// Babai's rounding-off algorithm: round the coordinates of `target` in the basis.
+    /// It is simpler than [`Self::nearest_plane`], but only decodes within the
+    /// parallelepiped of the basis itself, so it needs a better basis.
+    pub fn round_off(&self, target: &[BigInt]) -> Vec<BigInt> {
+        let c = self
+            .float_coordinates(target)
+            .and_then(|y| round_off_with(&self.mu, y))
+            .unwrap_or_else(|| {
+                let y = self.exact_coordinates(target);
+                round_off_with(&self.exact().mu, y).expect("exact")
+            });
+        self.combination(&c)
+    }
+
+    /// The exact coefficients, computed on first use
+    fn exact(&self) -> &Gso<BigRational> {
+        self.exact.get_or_init(|| exact_gso(&self.basis.rows))
+    }
+
+    /// Coordinates in `f64`; None if the coefficients are exact, or the target does
+    /// not fit
+    fn float_coordinates(&self, target: &[BigInt]) -> Option<Vec<f64>> {
+        assert_eq!(
+            target.len(),
+            self.basis.num_columns,
+            "the target is not in the ambient space"
+        );
+        if self.exact.get().is_some() {
+            return None;
+        }
+        let y = coordinates_with(
+            &self.basis.rows,
+            &self.mu,
+            &self.squared_norms,
+            target,
+            |x| x.to_f64().filter(|x| x.is_finite()),
+        )?;
+        y.iter().all(|x| x.is_finite()).then_some(y)
+    }
+
+    fn exact_coordinates(&self, target: &[BigInt]) -> Vec<BigRational> {
+        let gso = self.exact();
+        coordinates_with(&self.basis.rows, &gso.mu, &gso.norms, target, |x| {
+            Some(BigRational::from_integer(x))
+        })
+        .expect("exact")
+    }
+
+    /// The lattice vector `sum_i c_i b_i`
+    fn combination(&self, c: &[BigInt]) -> Vec<BigInt> {
+        let mut v = vec![BigInt::zero(); self.basis.num_columns];
+        for (ci, row) in c.iter().zip(self.basis.row_vectors()) {
+            if !ci.is_zero() {
+                v.iter_mut().zip(row.iter()).for_each(|(x, y)| *x += ci * y);
+            }
+        }
+        v
+    }
+}
+
+/// Exact Gram-Schmidt coefficients of linearly independent rows
+fn exact_gso(rows: &[Vec<BigInt>]) -> Gso<BigRational> {
+    let mut gso = Gso::<BigRational>::new(rows);
+    gso.update_all().expect("the basis is linearly dependent");
+    gso
+}
+
+/// The closest `f64`, which is infinite beyond its range
+fn to_f64<T: ToPrimitive>(x: &T) -> f64 {
+    x.to_f64().expect("a conversion to f64 does not fail")
+}
+
+/// Coordinates `<t, b_i*> / |b_i*|^2`, with `<t, b_i>` converted by `convert`;
+/// None if a conversion fails
+fn coordinates_with<S: GsoScalar>(
+    rows: &[Vec<BigInt>],
+    mu: &[Vec<S>],
+    norms: &[S],
+    target: &[BigInt],
+    convert: impl Fn(BigInt) -> Option<S>,
+) -> Option<Vec<S>> {
+    // <t, b_i*> = <t, b_i> - sum_{j < i} mu_{i,j} <t, b_j*>
+    let mut projections: Vec<S> = Vec::with_capacity(rows.len());
+    for (i, row) in rows.iter().enumerate() {
+        let p = convert(inner_product(target, row))?;
+        let p = (0..i).fold(p, |p, j| p - mu[i][j].clone() * projections[j].clone());
+        projections.push(p);
+    }
+    Some(
+        projections
+            .into_iter()
+            .zip(norms.iter())
+            .map(|(p, n)| p / n.clone())
+            .collect(),
+    )
+}
+
+/// Coefficients of Babai's nearest plane on coordinates `y`; None if they are not
+/// finite
+fn nearest_plane_with<S: GsoScalar>(mu: &[Vec<S>], mut y: Vec<S>) -> Option<Vec<BigInt>> {
+    let mut c = vec![BigInt::zero(); y.len()];
+    for i in (0..y.len()).rev() {
+        let ci = y[i].nearest_integer();
+        c[i] = ci.round_to_integer()?;
+        for (y, mu) in y[..i].iter_mut().zip(mu[i].iter()) {
+            *y = y.clone() - ci.clone() * mu.clone();
+        }
+    }
+    Some(c)
+}
+
+/// Coefficients of Babai's rounding-off on coordinates `y`; None if they are not
+/// finite
+fn round_off_with<S: GsoScalar>(mu: &[Vec<S>], y: Vec<S>) -> Option<Vec<BigInt>> {
+    // solve x * M = y, for the unit lower triangular M of the coefficients
+    let n = y.len();
+    let mut x: Vec<S> = Vec::with_capacity(n);
+    for j in (0..n).rev() {
+        let xj = (j + 1..n).fold(y[j].clone(), |acc, i| {
+            acc - x[n - 1 - i].clone() * mu[i][j].clone()
+        });
+        x.push(xj);
+    }
+    x.iter().rev().map(|x| x.round_to_integer()).collect()
+}
+
+impl IntegerLattice {
+    /// Gram-Schmidt orthogonalization of the basis
+    pub fn gram_schmidt(&self) -> GramSchmidtBasis {
+        GramSchmidtBasis::new(&self.basis)
+    }
+
+    /// A lattice vector close to `target`, with Babai's nearest plane algorithm.
+    /// The quality of the output depends on the basis: reduce it first, e.g., with
+    /// [`IntegerLattice::lll`].
+    pub fn babai(&self, target: &[BigInt]) -> Vec<BigInt> {
+        self.gram_schmidt().nearest_plane(target)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use num::{BigInt, Signed, Zero};
+    use rand::Rng;
+
+    use super::GramSchmidtBasis;
+    use crate::tests::{int_vec, test_rng};
+    use crate::{DenseMatrix, Field, IntegerLattice, Lattice, Matrix, PrimeField, F3329};
+
+    #[test]
+    fn test_gram_schmidt() {
+        let basis = DenseMatrix::from_row_vectors(vec![int_vec(&[3, 1]), int_vec(&[2, 2])]);
+        let gs = GramSchmidtBasis::new(&basis);
+        assert_eq!(gs.dimension(), 2);
+        assert!((gs.coefficients()[1][0] - 0.8).abs() < 1e-12);
+        assert!((gs.squared_norms()[0] - 10.0).abs() < 1e-12);
+        assert!((gs.squared_norms()[1] - 1.6).abs() < 1e-12);
+        assert!((gs.vectors()[1][0] + 0.4).abs() < 1e-12);
+        assert!((gs.vectors()[1][1] - 1.2).abs() < 1e-12);
+        // the product of the norms is the volume
+        assert!((gs.norms().iter().product::<f64>() - 4.0).abs() < 1e-12);
+
+        let v = int_vec(&[5, 3]);
+        assert_eq!(gs.nearest_plane(&v), v);
+        assert_eq!(gs.round_off(&v), v);
+        // (5, 4) = (4, 4) + (1, 0), with (1, 0) = 0.3 b_1* - 0.25 b_2*
+        assert_eq!(gs.nearest_plane(&int_vec(&[5, 4])), int_vec(&[4, 4]));
+    }
+
+    #[test]
+    fn test_exact_fallback() {
+        // entries beyond the f64 mantissa: Babai runs on the exact coefficients
+        let big = BigInt::from(1u64 << 60);
+        let basis = DenseMatrix::from_row_vectors(vec![
+            vec![big.clone(), BigInt::from(1)],
+            vec![BigInt::from(1), &big + 1],
+        ]);
+        let gs = GramSchmidtBasis::new(&basis);
+        assert!(gs.squared_norms().iter().all(|x| x.is_finite() && *x > 0.0));
+        let v: Vec<BigInt> = (0..2)
+            .map(|i| BigInt::from(3) * &basis.rows[0][i] - BigInt::from(2) * &basis.rows[1][i])
+            .collect();
+        let target = vec![&v[0] + 5, &v[1] - 7];
+        assert_eq!(gs.nearest_plane(&target), v);
+        assert_eq!(gs.round_off(&target), v);
+        assert!(gs.coordinates(&target).iter().all(|x| x.is_finite()));
+
+        // a target beyond the range of f64, with an f64 basis
+        let gs = GramSchmidtBasis::new(&DenseMatrix::from_row_vectors(vec![int_vec(&[3, 0])]));
+        let huge = BigInt::from(3) * BigInt::from(2).pow(1100);
+        let target = vec![&huge + 1, BigInt::from(1)];
+        assert_eq!(
+            gs.nearest_plane(&target),
+            vec![huge.clone(), BigInt::zero()]
+        );
+        assert_eq!(gs.round_off(&target), vec![huge, BigInt::zero()]);
+    }
+
+    #[test]
+    fn test_nearest_plane() {
+        let mut rng = test_rng();
+        let (n, m) = (4, 12);
+        let a = DenseMatrix::from_row_vectors(
+            (0..n)
+                .map(|_| (0..m).map(|_| F3329::random(&mut rng)).collect())
+                .collect(),
+        );
+        let lattice = IntegerLattice::q_ary_orthogonal(&a).lll(0.99);
+        let gs = lattice.gram_schmidt();
+        for _ in 0..20 {
+            let target: Vec<BigInt> = (0..m)
+                .map(|_| BigInt::from(rng.gen_range(-5000..5000)))
+                .collect();
+            let v = lattice.babai(&target);
+            assert!(lattice.contains(&v));
+
+            // the error is in the fundamental parallelepiped of the b_i*
+            let e: Vec<BigInt> = target.iter().zip(v.iter()).map(|(t, v)| t - v).collect();
+            assert!(gs.coordinates(&e).iter().all(|x| x.abs() <= 0.5 + 1e-9));
+
+            let w = gs.round_off(&target);
+            assert!(lattice.contains(&w));
+        }
+    }
+
+    #[test]
+    fn test_lwe_decoding() {
+        // b = A^T s + e mod q is close to the q-ary lattice of A
+        let mut rng = test_rng();
+        let (n, m) = (4, 20);
+        let a = DenseMatrix::from_row_vectors(
+            (0..n)
+                .map(|_| (0..m).map(|_| F3329::random(&mut rng)).collect())
+                .collect(),
+        );
+        let lattice = IntegerLattice::q_ary(&a).lll(0.99);
+        let gs = lattice.gram_schmidt();
+
+        for _ in 0..10 {
+            let s: Vec<F3329> = (0..n).map(|_| F3329::random(&mut rng)).collect();
+            let e: Vec<i64> = (0..m).map(|_| rng.gen_range(-1..=1)).collect();
+            let b: Vec<BigInt> = a
+                .transpose()
+                .mul_vector(&s)
+                .iter()
+                .zip(e.iter())
+                .map(|(x, e)| BigInt::from(x.lift() + e))
+                .collect();
+
+            for v in [gs.nearest_plane(&b), gs.round_off(&b)] {
+                let decoded: Vec<BigInt> = b.iter().zip(v.iter()).map(|(b, v)| b - v).collect();
+                assert_eq!(decoded, int_vec(&e));
+            }
+        }
+
+        // the error is at most half the smallest Gram-Schmidt norm away
+        let min = gs.norms().iter().cloned().fold(f64::INFINITY, f64::min);
+        assert!(min > 2.0 * (m as f64).sqrt());
+        let big: Vec<BigInt> = (0..m).map(|i| BigInt::from(i as i64)).collect();
+        assert!(gs
+            .nearest_plane(&big)
+            .iter()
+            .zip(big.iter())
+            .all(|(v, t)| (v - t).abs() < BigInt::from(3329)));
+    }
+}
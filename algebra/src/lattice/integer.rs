@@ -0,0 +1,410 @@
+//! Lattices with an integer basis.
+
+use std::{f64::consts::PI, sync::OnceLock};
+
+use num::{BigInt, Signed, ToPrimitive, Zero};
+
+use super::linear_algebra::{determinant, gram_matrix, hermite_normal_form, hnf_contains};
+use crate::{
+    BkzParameters, ConfigZZp, ConfigZZpX, DenseMatrix, Field, GramSchmidtBasis, Lattice,
+    LatticeReduction, Matrix, PrimeField, ZZp, ZZpX,
+};
+
+/// A lattice spanned by the rows of an integer matrix
+#[derive(Debug, Clone)]
+pub struct IntegerLattice {
+    pub(super) basis: DenseMatrix<BigInt>,
+    pub(super) hnf: OnceLock<Vec<Vec<BigInt>>>,
+}
+
+impl IntegerLattice {
+    /// The lattice spanned by `generators`, which may be linearly dependent.
+    /// The basis is the Hermite normal form of the generators.
+    pub fn from_generators(generators: &DenseMatrix<BigInt>) -> Self {
+        let hnf = hermite_normal_form(&generators.rows, generators.num_columns);
+        Self {
+            basis: DenseMatrix {
+                rows: hnf.clone(),
+                num_columns: generators.num_columns,
+            },
+            hnf: OnceLock::from(hnf),
+        }
+    }
+
+    /// Dimension of the ambient space `ZZ^m`
+    pub fn ambient_dimension(&self) -> usize {
+        self.basis.number_of_columns()
+    }
+
+    /// The Hermite normal form of the basis, computed on first use.
+    /// Two bases span the same lattice iff they have the same Hermite normal form.
+    pub fn hermite_normal_form(&self) -> DenseMatrix<BigInt> {
+        DenseMatrix {
+            rows: self.hnf().to_vec(),
+            num_columns: self.basis.num_columns,
+        }
+    }
+
+    fn hnf(&self) -> &[Vec<BigInt>] {
+        self.hnf
+            .get_or_init(|| hermite_normal_form(&self.basis.rows, self.basis.num_columns))
+    }
+
+    /// Determinant of the Gram matrix `B * B^T`, i.e., the square of the volume
+    pub fn gram_determinant(&self) -> BigInt {
+        determinant(&gram_matrix(&self.basis.rows))
+    }
+
+    /// The q-ary lattice `{ y in ZZ^m : y = A^T s mod q for some s }`
+    /// of an `n x m` matrix `A` over `ZZ_q`.
+    pub fn q_ary<C: ConfigZZp>(a: &DenseMatrix<ZZp<C>>) -> Self {
+        let m = a.number_of_columns();
+        let rows = a
+            .row_vectors()
+            .map(|row| row.iter().map(lift_positive).collect())
+            .collect();
+        Self::from_generators(&with_q_identity(rows, m, ZZp::<C>::modulus()))
+    }
+
+    /// The q-ary lattice `{ y in ZZ^m : A y = 0 mod q }`
+    /// of an `n x m` matrix `A` over `ZZ_q`.
+    pub fn q_ary_orthogonal<C: ConfigZZp>(a: &DenseMatrix<ZZp<C>>) -> Self {
+        let m = a.number_of_columns();
+        let (_, kernel) = kernel(a);
+        let rows = kernel
+            .iter()
+            .map(|row| row.iter().map(lift_positive).collect())
+            .collect();
+        Self::from_generators(&with_q_identity(rows, m, ZZp::<C>::modulus()))
+    }
+
+    /// The ideal lattice generated by `a` in `ZZ[x]/(x^n + 1)`, where `a` is the
+    /// centered lift of the ring element.
+    /// Its basis holds the coefficients of `a * x^j` for `j < n`.
+    pub fn ideal<C: ConfigZZpX>(a: &ZZpX<C>) -> Self {
+        let n = C::DIM;
+        let lifts: Vec<BigInt> = a.coeffs.iter().map(|x| BigInt::from(x.lift())).collect();
+        let rows = (0..n)
+            .map(|j| {
+                (0..n)
+                    .map(|i| {
+                        if i >= j {
+                            lifts[i - j].clone()
+                        } else {
+                            -&lifts[n + i - j]
+                        }
+                    })
+                    .collect()
+            })
+            .collect();
+        Self::from_bases(&DenseMatrix {
+            rows,
+            num_columns: n,
+        })
+    }
+}
+
+impl PartialEq for IntegerLattice {
+    fn eq(&self, other: &Self) -> bool {
+        self.ambient_dimension() == other.ambient_dimension() && self.hnf() == other.hnf()
+    }
+}
+
+impl Eq for IntegerLattice {}
+
+impl Lattice for IntegerLattice {
+    type Integer = BigInt;
+    type LatticeVector = Vec<BigInt>;
+    type Basis = DenseMatrix<BigInt>;
+
+    /// The volume `sqrt(det(B B^T))`, i.e., `|det B|` for a full rank lattice.
+    ///
+    /// The volume of a lattice of lower rank is not an integer in general, and is
+    /// then rounded down: [`IntegerLattice::gram_determinant`] is its exact square.
+    fn determinant(&self) -> BigInt {
+        if self.dimension() == self.ambient_dimension() {
+            determinant(&self.basis.rows).abs()
+        } else {
+            self.gram_determinant().sqrt()
+        }
+    }
+
+    /// dimension (rank) of the lattice
+    fn dimension(&self) -> usize {
+        self.basis.number_of_rows()
+    }
+
+    /// Build a lattice from a basis, whose rows must be linearly independent.
+    /// Use [`IntegerLattice::from_generators`] otherwise.
+    fn from_bases(basis: &DenseMatrix<BigInt>) -> Self {
+        Self {
+            basis: basis.clone(),
+            hnf: OnceLock::new(),
+        }
+    }
+
+    /// extract a bases for the lattice
+    fn bases(&self) -> DenseMatrix<BigInt> {
+        self.basis.clone()
+    }
+
+    /// The Gaussian heuristic `(Gamma(n/2 + 1) * vol)^(1/n) / sqrt(pi)`:
+    /// the expected length of a shortest vector of a random lattice.
+    fn gaussian_length(&self) -> f64 {
+        let n = self.dimension();
+        if n == 0 {
+            return 0.0;
+        }
+        let log_volume = ln(&self.gram_determinant()) / 2.0;
+        ((ln_gamma_half(n) + log_volume) / n as f64).exp() / PI.sqrt()
+    }
+
+    /// If a vector is in the lattice, reducing it with the Hermite normal form
+    fn contains(&self, vec: &Vec<BigInt>) -> bool {
+        vec.len() == self.ambient_dimension() && hnf_contains(self.hnf(), vec)
+    }
+}
+
+impl LatticeReduction for IntegerLattice {
+    type GramSchmidtBasis = GramSchmidtBasis;
+
+    /// GramSchmidt bases, see [`IntegerLattice::gram_schmidt`]
+    fn gram_schmidt_bases(&self) -> GramSchmidtBasis {
+        self.gram_schmidt()
+    }
+
+    /// Babai nearest plane algorithm, see [`IntegerLattice::babai`]
+    fn babai(&self, vec: &Vec<BigInt>) -> Vec<BigInt> {
+        IntegerLattice::babai(self, vec)
+    }
+
+    /// LLL lattice reduction, see [`IntegerLattice::lll`]
+    fn lll(&self, delta: f64) -> Self {
+        IntegerLattice::lll(self, delta)
+    }
+
+    /// BKZ 2.0 lattice reduction, see [`IntegerLattice::bkz`]
+    fn bkz(&self, params: &BkzParameters) -> Self {
+        IntegerLattice::bkz(self, params)
+    }
+}
+
+/// Lift a field element into `[0, q)`
+pub(super) fn lift_positive<C: ConfigZZp>(x: &ZZp<C>) -> BigInt {
+    BigInt::from(u64::from(*x))
+}
+
+/// Append the rows of `q * I_m` to the generators
+fn with_q_identity(mut rows: Vec<Vec<BigInt>>, m: usize, q: u64) -> DenseMatrix<BigInt> {
+    rows.extend((0..m).map(|i| {
+        let mut row = vec![BigInt::zero(); m];
+        row[i] = BigInt::from(q);
+        row
+    }));
+    DenseMatrix {
+        rows,
+        num_columns: m,
+    }
+}
+
+/// A basis of the kernel `{ y : A y = 0 }` over the field `ZZ_q`, with the pivot
+/// columns of `A`.
+/// The basis has one vector per free column, equal to 1 there and 0 on the other free
+/// columns.
+pub(super) fn kernel<C: ConfigZZp>(a: &DenseMatrix<ZZp<C>>) -> (Vec<usize>, Vec<Vec<ZZp<C>>>) {
+    let m = a.number_of_columns();
+    // reduced row echelon form
+    let mut rows: Vec<Vec<ZZp<C>>> = a.row_vectors().cloned().collect();
+    let mut pivots = vec![];
+    for c in 0..m {
+        let r = pivots.len();
+        let i = match (r..rows.len()).find(|&i| !rows[i][c].is_zero_vartime()) {
+            Some(i) => i,
+            None => continue,
+        };
+        rows.swap(r, i);
+        let inv = rows[r][c].invert().unwrap();
+        rows[r].iter_mut().for_each(|x| *x *= inv);
+        let pivot_row = rows[r].clone();
+        for (i, row) in rows.iter_mut().enumerate() {
+            let k = row[c];
+            if i != r && !k.is_zero_vartime() {
+                row.iter_mut()
+                    .zip(pivot_row.iter())
+                    .for_each(|(x, y)| *x -= k * y);
+            }
+        }
+        pivots.push(c);
+    }
+
+    // one vector per free column
+    let vectors = (0..m)
+        .filter(|c| !pivots.contains(c))
+        .map(|f| {
+            let mut v = vec![ZZp::zero(); m];
+            v[f] = ZZp::one();
+            for (r, &c) in pivots.iter().enumerate() {
+                v[c] = -rows[r][f];
+            }
+            v
+        })
+        .collect();
+    (pivots, vectors)
+}
+
+/// Natural logarithm of a positive integer
+pub(super) fn ln(x: &BigInt) -> f64 {
+    let shift = x.bits().saturating_sub(64);
+    let top = (x >> shift).to_f64().unwrap_or(f64::NAN);
+    top.ln() + shift as f64 * std::f64::consts::LN_2
+}
+
+/// `ln Gamma(n/2 + 1)`, exactly summed for integers and half integers
+pub(super) fn ln_gamma_half(n: usize) -> f64 {
+    let ln_factorial = |k: usize| (1..=k).map(|i| (i as f64).ln()).sum::<f64>();
+    if n.is_multiple_of(2) {
+        ln_factorial(n / 2)
+    } else {
+        // Gamma(j + 1/2) = (2j)! sqrt(pi) / (4^j j!)
+        let j = n.div_ceil(2);
+        ln_factorial(2 * j) - ln_factorial(j) - j as f64 * 4f64.ln() + PI.ln() / 2.0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use num::BigInt;
+
+    use super::{ln_gamma_half, IntegerLattice};
+    use crate::tests::{int_matrix, int_vec, test_rng, ConfigZZpX12289_8};
+    use crate::{DenseMatrix, Field, Lattice, Matrix, Polynomial, PrimeField, ZZpX, F3329};
+
+    #[test]
+    fn test_integer_lattice() {
+        let basis = int_matrix(&[&[2, 1, 0], &[0, 3, 1], &[1, 0, 4]]);
+        let lattice = IntegerLattice::from_bases(&basis);
+        assert_eq!(lattice.dimension(), 3);
+        assert_eq!(lattice.ambient_dimension(), 3);
+        assert_eq!(lattice.determinant(), BigInt::from(25));
+        assert_eq!(lattice.gram_determinant(), BigInt::from(625));
+        assert!(lattice.contains(&int_vec(&[3, 4, 5])));
+        assert!(lattice.contains(&int_vec(&[-2, 2, 1])));
+        assert!(!lattice.contains(&int_vec(&[1, 0, 0])));
+        assert!(!lattice.contains(&int_vec(&[1, 0])));
+
+        // a lattice does not depend on its basis
+        let other = int_matrix(&[&[2, 4, 1], &[0, 3, 1], &[3, 1, 4]]);
+        assert_eq!(IntegerLattice::from_bases(&other), lattice);
+        assert_eq!(IntegerLattice::from_generators(&other), lattice);
+        assert_ne!(
+            IntegerLattice::from_bases(&int_matrix(&[&[1, 0, 0]])),
+            lattice
+        );
+
+        // a rank 1 lattice in ZZ^2
+        let line = IntegerLattice::from_bases(&int_matrix(&[&[3, 4]]));
+        assert_eq!(line.determinant(), BigInt::from(5));
+        assert_eq!(line.gram_determinant(), BigInt::from(25));
+        let diagonal = IntegerLattice::from_bases(&int_matrix(&[&[1, 1]]));
+        assert_eq!(diagonal.determinant(), BigInt::from(1));
+        assert_eq!(diagonal.gram_determinant(), BigInt::from(2));
+        assert!(line.contains(&int_vec(&[-6, -8])));
+        assert!(!line.contains(&int_vec(&[3, 3])));
+    }
+
+    #[test]
+    fn test_gaussian_heuristic() {
+        // Gamma(1.5) = sqrt(pi)/2 and Gamma(4) = 6
+        assert!((ln_gamma_half(1) - (std::f64::consts::PI.sqrt() / 2.0).ln()).abs() < 1e-12);
+        assert!((ln_gamma_half(6) - 6f64.ln()).abs() < 1e-12);
+
+        // ZZ^2: a disc of radius 1/sqrt(pi) has area 1
+        let z2 = IntegerLattice::from_bases(&DenseMatrix::identity(2));
+        let gh = z2.gaussian_length();
+        assert!((gh - 1.0 / std::f64::consts::PI.sqrt()).abs() < 1e-12);
+
+        // scaling the lattice scales the heuristic
+        let scaled = IntegerLattice::from_bases(&int_matrix(&[&[1000, 0], &[0, 1000]]));
+        assert!((scaled.gaussian_length() - 1000.0 * gh).abs() < 1e-9);
+        let q = IntegerLattice::from_bases(&int_matrix(&[&[1, 0, 0], &[0, 1, 0], &[0, 0, 7]]));
+        let z3 = IntegerLattice::from_bases(&DenseMatrix::identity(3));
+        assert!((q.gaussian_length() / z3.gaussian_length() - 7f64.cbrt()).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_q_ary_lattices() {
+        let mut rng = test_rng();
+        let (n, m) = (2, 5);
+        let a = DenseMatrix::from_row_vectors(
+            (0..n)
+                .map(|_| (0..m).map(|_| F3329::random(&mut rng)).collect())
+                .collect(),
+        );
+        let q = BigInt::from(3329);
+
+        // the image of A^T has volume q^(m-n), and its orthogonal has volume q^n
+        let image = IntegerLattice::q_ary(&a);
+        let kernel = IntegerLattice::q_ary_orthogonal(&a);
+        assert_eq!(image.dimension(), m);
+        assert_eq!(kernel.dimension(), m);
+        assert_eq!(image.determinant(), q.pow((m - n) as u32));
+        assert_eq!(kernel.determinant(), q.pow(n as u32));
+
+        let s: Vec<F3329> = (0..n).map(|_| F3329::random(&mut rng)).collect();
+        let y: Vec<BigInt> = a
+            .transpose()
+            .mul_vector(&s)
+            .iter()
+            .map(|x| BigInt::from(x.lift()))
+            .collect();
+        assert!(image.contains(&y));
+
+        for row in kernel.bases().row_vectors() {
+            let row: Vec<F3329> = row
+                .iter()
+                .map(|x| F3329::from_lift(i64::try_from(x).unwrap()))
+                .collect();
+            assert!(a.mul_vector(&row).iter().all(|x| *x == F3329::zero()));
+        }
+        assert!(kernel.contains(&int_vec(&[3329, 0, 0, -3329, 0])));
+        assert!(!kernel.contains(&int_vec(&[1, 0, 0, 0, 0])));
+    }
+
+    #[test]
+    fn test_ideal_lattice() {
+        let mut rng = test_rng();
+        let a = ZZpX::<ConfigZZpX12289_8>::random(&mut rng, None);
+        let b = ZZpX::<ConfigZZpX12289_8>::random_binary(&mut rng);
+        let ideal = IntegerLattice::ideal(&a);
+        assert_eq!(ideal.dimension(), 8);
+        assert!(ideal.determinant() > BigInt::from(0));
+
+        // the basis vectors are a * x^j, and the lattice is closed under x
+        let basis = ideal.bases();
+        assert_eq!(
+            basis.row(0),
+            &a.coefficients()
+                .map(|x| BigInt::from(x.lift()))
+                .collect::<Vec<_>>()[..]
+        );
+        let mut rotated: Vec<BigInt> = basis.row(7).to_vec();
+        rotated.rotate_right(1);
+        rotated[0] = -&rotated[0];
+        assert!(ideal.contains(&rotated));
+
+        // a * b over the integers, for a binary b
+        let ab: Vec<BigInt> = (0..8)
+            .map(|i| {
+                b.coefficients()
+                    .enumerate()
+                    .filter(|(_, x)| x.lift() == 1)
+                    .map(|(j, _)| basis.row(j)[i].clone())
+                    .sum()
+            })
+            .collect();
+        assert!(ideal.contains(&ab));
+        let mut off = ab.clone();
+        off[3] += 1;
+        assert!(!ideal.contains(&off));
+    }
+}
@@ -0,0 +1,182 @@
+//! Exact linear algebra over the integers.
+
+use num::{BigInt, Integer, One, Signed, ToPrimitive, Zero};
+
+/// Row-style Hermite normal form of the lattice generated by `rows`, which may be
+/// linearly dependent.
+///
+/// The output is a basis in echelon form: the pivot of each row is positive, and the
+/// entries above a pivot are reduced into `[0, pivot)`. It is unique for a lattice.
+pub(crate) fn hermite_normal_form(rows: &[Vec<BigInt>], num_columns: usize) -> Vec<Vec<BigInt>> {
+    let mut a = rows.to_vec();
+    let mut r = 0;
+    for c in 0..num_columns {
+        if r == a.len() {
+            break;
+        }
+        // gcd of the column c into row r, with unimodular row operations
+        for i in r + 1..a.len() {
+            if a[i][c].is_zero() {
+                continue;
+            }
+            let gcd = a[r][c].extended_gcd(&a[i][c]);
+            let p = &a[r][c] / &gcd.gcd;
+            let q = &a[i][c] / &gcd.gcd;
+            let (row_r, row_i): (Vec<BigInt>, Vec<BigInt>) = a[r]
+                .iter()
+                .zip(a[i].iter())
+                .map(|(x, y)| (&gcd.x * x + &gcd.y * y, &p * y - &q * x))
+                .unzip();
+            a[r] = row_r;
+            a[i] = row_i;
+        }
+        if a[r][c].is_zero() {
+            continue;
+        }
+        if a[r][c].is_negative() {
+            a[r].iter_mut().for_each(|x| *x = -&*x);
+        }
+        let pivot_row = a[r].clone();
+        for row in a[..r].iter_mut() {
+            let k = row[c].div_floor(&pivot_row[c]);
+            if !k.is_zero() {
+                row.iter_mut()
+                    .zip(pivot_row.iter())
+                    .for_each(|(x, y)| *x -= &k * y);
+            }
+        }
+        r += 1;
+    }
+    a.truncate(r);
+    a
+}
+
+/// Reduce `vec` with a basis in Hermite normal form.
+/// Returns true if it reduces to zero, i.e., `vec` is in the lattice.
+pub(crate) fn hnf_contains(hnf: &[Vec<BigInt>], vec: &[BigInt]) -> bool {
+    let mut v = vec.to_vec();
+    for row in hnf {
+        let c = match row.iter().position(|x| !x.is_zero()) {
+            Some(c) => c,
+            None => continue,
+        };
+        if v[..c].iter().any(|x| !x.is_zero()) {
+            return false;
+        }
+        let (k, rem) = v[c].div_rem(&row[c]);
+        if !rem.is_zero() {
+            return false;
+        }
+        v.iter_mut().zip(row.iter()).for_each(|(x, y)| *x -= &k * y);
+    }
+    v.iter().all(|x| x.is_zero())
+}
+
+/// Determinant of a square matrix, with Bareiss' fraction-free elimination
+pub(crate) fn determinant(matrix: &[Vec<BigInt>]) -> BigInt {
+    let n = matrix.len();
+    let mut m = matrix.to_vec();
+    let mut sign = BigInt::one();
+    let mut prev = BigInt::one();
+    for k in 0..n {
+        if m[k][k].is_zero() {
+            match (k + 1..n).find(|&i| !m[i][k].is_zero()) {
+                Some(i) => {
+                    m.swap(k, i);
+                    sign = -sign;
+                }
+                None => return BigInt::zero(),
+            }
+        }
+        let pivot_row = m[k].clone();
+        for row in m[k + 1..].iter_mut() {
+            for j in k + 1..n {
+                row[j] = (&row[j] * &pivot_row[k] - &row[k] * &pivot_row[j]) / &prev;
+            }
+        }
+        prev = pivot_row[k].clone();
+    }
+    sign * prev
+}
+
+/// Gram matrix `B * B^T` of the rows of `B`
+pub(crate) fn gram_matrix(rows: &[Vec<BigInt>]) -> Vec<Vec<BigInt>> {
+    rows.iter()
+        .map(|x| rows.iter().map(|y| inner_product(x, y)).collect())
+        .collect()
+}
+
+/// Inner product of two integer vectors
+pub(crate) fn inner_product(a: &[BigInt], b: &[BigInt]) -> BigInt {
+    // most lattices have small entries: accumulate in i128 while possible
+    let small = a.iter().zip(b.iter()).try_fold(0i128, |acc, (x, y)| {
+        acc.checked_add(x.to_i64()? as i128 * y.to_i64()? as i128)
+    });
+    match small {
+        Some(x) => BigInt::from(x),
+        None => a.iter().zip(b.iter()).map(|(x, y)| x * y).sum(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use num::BigInt;
+
+    use super::{determinant, hermite_normal_form, hnf_contains};
+
+    fn int_rows(rows: &[&[i64]]) -> Vec<Vec<BigInt>> {
+        rows.iter()
+            .map(|row| row.iter().map(|&x| BigInt::from(x)).collect())
+            .collect()
+    }
+
+    #[test]
+    fn test_hermite_normal_form() {
+        let rows = int_rows(&[&[2, 3, 6, 2], &[5, 6, 1, 6], &[8, 3, 1, 1]]);
+        let hnf = hermite_normal_form(&rows, 4);
+        assert_eq!(hnf.len(), 3);
+
+        // echelon form, with positive pivots and reduced entries above them
+        let pivots: Vec<usize> = hnf
+            .iter()
+            .map(|row| row.iter().position(|x| *x != BigInt::from(0)).unwrap())
+            .collect();
+        assert!(pivots.windows(2).all(|w| w[0] < w[1]));
+        for (i, &c) in pivots.iter().enumerate() {
+            assert!(hnf[i][c] > BigInt::from(0));
+            for row in hnf[..i].iter() {
+                assert!(row[c] >= BigInt::from(0) && row[c] < hnf[i][c]);
+            }
+        }
+
+        // same lattice, and unique for the lattice:
+        // the other basis is (r0 + r1, r1, r0 - r2)
+        for row in rows.iter() {
+            assert!(hnf_contains(&hnf, row));
+        }
+        let other = int_rows(&[&[7, 9, 7, 8], &[5, 6, 1, 6], &[-6, 0, 5, 1]]);
+        assert_eq!(hermite_normal_form(&other, 4), hnf);
+    }
+
+    #[test]
+    fn test_hnf_dependent_rows() {
+        // 2Z + 3Z = Z, and a repeated row
+        let hnf = hermite_normal_form(&int_rows(&[&[2, 0], &[3, 0], &[0, 4], &[0, 4]]), 2);
+        assert_eq!(hnf, int_rows(&[&[1, 0], &[0, 4]]));
+        assert!(hnf_contains(&hnf, &int_rows(&[&[-7, 12]])[0]));
+        assert!(!hnf_contains(&hnf, &int_rows(&[&[1, 2]])[0]));
+
+        assert_eq!(hermite_normal_form(&int_rows(&[&[0, 0]]), 2).len(), 0);
+    }
+
+    #[test]
+    fn test_determinant() {
+        let m = int_rows(&[&[2, 3, 6], &[5, 6, 1], &[8, 3, 1]]);
+        assert_eq!(determinant(&m), BigInt::from(-183));
+        let m = int_rows(&[&[0, 1], &[1, 0]]);
+        assert_eq!(determinant(&m), BigInt::from(-1));
+        let m = int_rows(&[&[1, 2], &[2, 4]]);
+        assert_eq!(determinant(&m), BigInt::from(0));
+        assert_eq!(determinant(&[]), BigInt::from(1));
+    }
+}
@@ -0,0 +1,321 @@
+//! LLL lattice reduction.
+//!
+//! The basis is kept exact over the integers, while the Gram-Schmidt coefficients are
+//! computed in `f64` as in Schnorr-Euchner, from exact inner products. When these are
+//! out of the range of `f64`, or size reduction fails to converge, it falls back to
+//! exact rational arithmetic. So does a result whose inner products are not exact in
+//! `f64` and that fails an exact check of the LLL conditions.
+
+use num::{BigInt, BigRational, Signed, ToPrimitive, Zero};
+
+use super::{
+    gram_schmidt::{Gso, GsoScalar},
+    integer::{ln, IntegerLattice},
+};
+use crate::{DenseMatrix, Lattice};
+
+/// Number of size reduction passes on a row before giving up on the precision
+const MAX_SIZE_REDUCTION_PASSES: usize = 16;
+
+/// LLL-reduce `rows` in place with scalars `S`.
+/// Returns None if the precision of `S` is not enough; `rows` is still a basis of
+/// the same lattice in that case.
+fn lll_with<S: GsoScalar>(rows: &mut [Vec<BigInt>], delta: f64) -> Option<()> {
+    let n = rows.len();
+    if n < 2 {
+        return Some(());
+    }
+    let mut gso = Gso::<S>::new(rows);
+    let delta = S::from_f64(delta);
+    let eta = S::from_f64(S::ETA);
+    let max_bits = rows.iter().flatten().map(|x| x.bits()).max().unwrap_or(1) as usize;
+    let max_iterations = 1000 + 100 * n * n * (max_bits + n);
+
+    gso.update_row(0)?;
+    let mut k = 1;
+    let mut iterations = 0;
+    while k < n {
+        iterations += 1;
+        if iterations > max_iterations {
+            return None;
+        }
+
+        // size reduction, repeated against rounding errors
+        let mut reduced = false;
+        for _ in 0..MAX_SIZE_REDUCTION_PASSES {
+            gso.update_row(k)?;
+            if (0..k).all(|j| gso.mu[k][j].magnitude() <= eta) {
+                reduced = true;
+                break;
+            }
+            for j in (0..k).rev() {
+                let r = gso.mu[k][j].round_to_integer()?;
+                if r.is_zero() {
+                    continue;
+                }
+                let (head, tail) = rows.split_at_mut(k);
+                tail[0]
+                    .iter_mut()
+                    .zip(head[j].iter())
+                    .for_each(|(x, y)| *x -= &r * y);
+                let r = S::from_integer_rounded(&r)?;
+                for i in 0..j {
+                    gso.mu[k][i] = gso.mu[k][i].clone() - r.clone() * gso.mu[j][i].clone();
+                }
+                gso.mu[k][j] = gso.mu[k][j].clone() - r;
+            }
+            gso.rows[k].clone_from(&rows[k]);
+        }
+        if !reduced {
+            return None;
+        }
+
+        // Lovasz condition
+        let mu = gso.mu[k][k - 1].clone();
+        if gso.norms[k] >= (delta.clone() - mu.clone() * mu) * gso.norms[k - 1].clone() {
+            k += 1;
+        } else {
+            rows.swap(k - 1, k);
+            gso.rows.swap(k - 1, k);
+            if k == 1 {
+                gso.update_row(0)?;
+            } else {
+                k -= 1;
+            }
+        }
+    }
+    Some(())
+}
+
+/// LLL-reduce `rows` in place, in `f64` if possible
+pub(super) fn lll_in_place(rows: &mut [Vec<BigInt>], delta: f64) {
+    if lll_with::<f64>(rows, delta).is_some() && (exact_in_f64(rows) || is_lll_reduced(rows, delta))
+    {
+        return;
+    }
+    log::debug!("LLL: not enough precision in f64, using exact arithmetic");
+    lll_with::<BigRational>(rows, delta).expect("the basis is linearly dependent");
+}
+
+/// Whether the inner products of the rows are exact in `f64`, i.e.,
+/// `2 * bits + log2(n) <= 53` for entries of `bits` bits in dimension `n`
+fn exact_in_f64(rows: &[Vec<BigInt>]) -> bool {
+    let bits = rows.iter().flatten().map(|x| x.bits()).max().unwrap_or(0);
+    let log_n = rows
+        .first()
+        .map_or(0, |row| row.len())
+        .next_power_of_two()
+        .trailing_zeros();
+    2 * bits + log_n as u64 <= f64::MANTISSA_DIGITS as u64
+}
+
+/// Check size reduction, `|mu_{k,j}| <= 0.51`, and the Lovasz condition exactly
+pub(super) fn is_lll_reduced(rows: &[Vec<BigInt>], delta: f64) -> bool {
+    let mut gso = Gso::<BigRational>::new(rows);
+    if gso.update_all().is_none() {
+        return false;
+    }
+    let eta = BigRational::from_f64(<f64 as GsoScalar>::ETA);
+    let delta = BigRational::from_f64(delta);
+    (1..rows.len()).all(|k| {
+        let mu = &gso.mu[k][k - 1];
+        (0..k).all(|j| gso.mu[k][j].abs() <= eta)
+            && gso.norms[k] >= (&delta - mu * mu) * &gso.norms[k - 1]
+    })
+}
+
+/// LLL reduction of the rows of `basis`, which must be linearly independent,
+/// with parameter `delta` in `(1/4, 1)`.
+///
+/// The output spans the same lattice, is size reduced, i.e., `|mu_{k,j}| <= 0.51`
+/// to leave room for rounding errors, and satisfies the Lovasz condition
+/// `|b_k*|^2 >= (delta - mu_{k,k-1}^2) |b_{k-1}*|^2`.
+pub fn lll_reduce(basis: &DenseMatrix<BigInt>, delta: f64) -> DenseMatrix<BigInt> {
+    assert!(delta > 0.25 && delta < 1.0, "delta must be in (1/4, 1)");
+    let mut rows = basis.rows.clone();
+    lll_in_place(&mut rows, delta);
+    DenseMatrix {
+        rows,
+        num_columns: basis.num_columns,
+    }
+}
+
+impl IntegerLattice {
+    /// The same lattice, with an LLL reduced basis. See [`lll_reduce`].
+    pub fn lll(&self, delta: f64) -> Self {
+        let res = Self::from_bases(&lll_reduce(&self.basis, delta));
+        if let Some(hnf) = self.hnf.get() {
+            res.hnf.get_or_init(|| hnf.clone());
+        }
+        res
+    }
+
+    /// Hermite factor `|b_1| / vol^(1/n)` of the basis
+    pub fn hermite_factor(&self) -> f64 {
+        let n = self.dimension();
+        let b1 = self.basis.rows[0]
+            .iter()
+            .map(|x| x.to_f64().unwrap_or(f64::INFINITY).powi(2))
+            .sum::<f64>()
+            .sqrt();
+        let log_volume = ln(&self.gram_determinant()) / 2.0;
+        b1 / (log_volume / n as f64).exp()
+    }
+
+    /// Root Hermite factor `(|b_1| / vol^(1/n))^(1/n)` of the basis.
+    /// It is about 1.02 for LLL reduced bases of random lattices.
+    pub fn root_hermite_factor(&self) -> f64 {
+        self.hermite_factor().powf(1.0 / self.dimension() as f64)
+    }
+
+    /// Slope of the least-squares line through `(i, ln |b_i*|)`.
+    /// Under the geometric series assumption, reduced bases have a negative slope
+    /// of `-2 ln(root Hermite factor)`.
+    pub fn gsa_slope(&self) -> f64 {
+        gsa_slope(self.gram_schmidt().squared_norms())
+    }
+}
+
+/// Slope of the least-squares line through `(i, ln sqrt(squared_norms[i]))`
+pub(super) fn gsa_slope(squared_norms: &[f64]) -> f64 {
+    let log_norms: Vec<f64> = squared_norms.iter().map(|x| x.ln() / 2.0).collect();
+    let n = log_norms.len() as f64;
+    let mean_x = (n - 1.0) / 2.0;
+    let mean_y = log_norms.iter().sum::<f64>() / n;
+    let (cov, var) = log_norms
+        .iter()
+        .enumerate()
+        .fold((0.0, 0.0), |(cov, var), (i, y)| {
+            let dx = i as f64 - mean_x;
+            (cov + dx * (y - mean_y), var + dx * dx)
+        });
+    cov / var
+}
+
+#[cfg(test)]
+mod tests {
+    use num::{BigInt, BigRational, Signed, ToPrimitive};
+
+    use super::{exact_in_f64, is_lll_reduced, lll_reduce, lll_with};
+    use crate::tests::{int_matrix, int_vec, test_rng};
+    use crate::{DenseMatrix, Field, IntegerLattice, Lattice, Matrix, F3329};
+
+    fn assert_lll_reduced(basis: &DenseMatrix<BigInt>, delta: f64) {
+        assert!(is_lll_reduced(&basis.rows, delta));
+    }
+
+    #[test]
+    fn test_lll_small() {
+        let basis = int_matrix(&[&[1, 1, 1], &[-1, 0, 2], &[3, 5, 6]]);
+        let reduced = lll_reduce(&basis, 0.75);
+        assert_lll_reduced(&reduced, 0.75);
+        assert_eq!(
+            IntegerLattice::from_bases(&reduced),
+            IntegerLattice::from_bases(&basis)
+        );
+        let norms: Vec<BigInt> = reduced
+            .row_vectors()
+            .map(|row| row.iter().map(|x| x * x).sum())
+            .collect();
+        assert_eq!(norms, vec![1.into(), 2.into(), 5.into()]);
+
+        // exact arithmetic agrees
+        let mut rows = basis.rows.clone();
+        lll_with::<BigRational>(&mut rows, 0.75).unwrap();
+        assert_eq!(rows, reduced.rows);
+    }
+
+    #[test]
+    fn test_lll_q_ary() {
+        let mut rng = test_rng();
+        let (n, m) = (8, 24);
+        let a = DenseMatrix::from_row_vectors(
+            (0..n)
+                .map(|_| (0..m).map(|_| F3329::random(&mut rng)).collect())
+                .collect(),
+        );
+        let lattice = IntegerLattice::q_ary_orthogonal(&a);
+        let reduced = lattice.lll(0.99);
+        assert_lll_reduced(&reduced.bases(), 0.99);
+        assert_eq!(reduced, lattice);
+
+        // the HNF basis is far from reduced
+        assert!(lattice.root_hermite_factor() > 1.05);
+        assert!(reduced.root_hermite_factor() < 1.03);
+        assert!(reduced.gsa_slope() < 0.0);
+        assert!(reduced.gsa_slope() > lattice.gsa_slope());
+        // the first vector is at most a small multiple of the Gaussian heuristic
+        let b1: f64 = reduced
+            .bases()
+            .row(0)
+            .iter()
+            .map(|x| x.to_f64().unwrap().powi(2))
+            .sum::<f64>()
+            .sqrt();
+        assert!(b1 < 2.0 * reduced.gaussian_length());
+    }
+
+    #[test]
+    fn test_lll_exact_fallback() {
+        // a knapsack lattice with 80-bit weights does not fit in f64
+        let weights = [
+            "1208925819614629174706175",
+            "987654321987654321987654",
+            "1111111111111111111111111",
+            "1000000000000000000000001",
+        ];
+        let rows: Vec<Vec<BigInt>> = weights
+            .iter()
+            .enumerate()
+            .map(|(i, w)| {
+                let mut row = vec![BigInt::from(0); weights.len() + 1];
+                row[i] = BigInt::from(1);
+                row[weights.len()] = w.parse().unwrap();
+                row
+            })
+            .collect();
+        let mut copy = rows.clone();
+        assert!(lll_with::<f64>(&mut copy, 0.99).is_none());
+
+        let basis = DenseMatrix::from_row_vectors(rows);
+        let reduced = lll_reduce(&basis, 0.99);
+        assert_lll_reduced(&reduced, 0.99);
+        assert_eq!(
+            IntegerLattice::from_bases(&reduced),
+            IntegerLattice::from_bases(&basis)
+        );
+        assert!(reduced
+            .row(0)
+            .iter()
+            .all(|x| x.abs() < BigInt::from(1u64 << 40)));
+    }
+
+    #[test]
+    fn test_lll_inexact_products() {
+        // 40-bit entries fit in the mantissa, but their inner products do not:
+        // the f64 result is checked exactly
+        let rows = vec![
+            int_vec(&[1 << 40, 0, 3]),
+            int_vec(&[(1 << 40) + 1, 1, 0]),
+            int_vec(&[5, (1 << 39) - 7, 1 << 38]),
+        ];
+        assert!(!exact_in_f64(&rows));
+        assert!(exact_in_f64(&[int_vec(&[1 << 24, 1 << 24, 1 << 24])]));
+        assert!(!exact_in_f64(&[int_vec(&[1 << 25, 1 << 25, 1 << 25])]));
+
+        let basis = DenseMatrix::from_row_vectors(rows);
+        let reduced = lll_reduce(&basis, 0.99);
+        assert_lll_reduced(&reduced, 0.99);
+        assert_eq!(
+            IntegerLattice::from_bases(&reduced),
+            IntegerLattice::from_bases(&basis)
+        );
+        assert!(!is_lll_reduced(&basis.rows, 0.99));
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_lll_delta() {
+        lll_reduce(&int_matrix(&[&[1, 0], &[0, 1]]), 1.0);
+    }
+}
@@ -0,0 +1,274 @@
+//! Ideal and module lattices of polynomial rings `ZZ_q[x]/(x^n + 1)`.
+//!
+//! Both keep an explicit [`IntegerLattice`], so that the reductions and metrics of
+//! integer lattices apply to concrete ring-SIS and module-SIS instances.
+
+use std::marker::PhantomData;
+
+use num::{BigInt, Zero};
+
+use super::integer::{kernel, lift_positive};
+use crate::{
+    BkzParameters, ConfigZZpX, DenseMatrix, GramSchmidtBasis, IdealLattice, IntegerLattice,
+    Lattice, LatticeReduction, Matrix, ModularLattice, PrimeField, ZZp, ZZpX,
+};
+
+/// The ideal lattice of a ring element: the ideal generated by its centered lift in
+/// `ZZ[x]/(x^n + 1)`, see [`IntegerLattice::ideal`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RingIdealLattice<C: ConfigZZpX> {
+    lattice: IntegerLattice,
+    config: PhantomData<C>,
+}
+
+/// The module-SIS lattice of ring elements `a_1, ..., a_k`:
+/// `{ (z_1, ..., z_k) in (ZZ[x]/(x^n + 1))^k : sum_i a_i z_i = 0 mod q }`,
+/// where the vector of `z_i` is the concatenation of their coefficients.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ModuleSisLattice<C: ConfigZZpX> {
+    lattice: IntegerLattice,
+    config: PhantomData<C>,
+}
+
+macro_rules! impl_structured_lattice {
+    ($lattice: ident) => {
+        impl<C: ConfigZZpX> $lattice<C> {
+            /// The underlying integer lattice
+            pub fn integer_lattice(&self) -> &IntegerLattice {
+                &self.lattice
+            }
+
+            /// Consume into the underlying integer lattice
+            pub fn into_integer_lattice(self) -> IntegerLattice {
+                self.lattice
+            }
+
+            fn new(lattice: IntegerLattice) -> Self {
+                Self {
+                    lattice,
+                    config: PhantomData,
+                }
+            }
+        }
+
+        impl<C: ConfigZZpX> From<$lattice<C>> for IntegerLattice {
+            fn from(lattice: $lattice<C>) -> Self {
+                lattice.lattice
+            }
+        }
+
+        impl<C: ConfigZZpX> Lattice for $lattice<C> {
+            type Integer = BigInt;
+            type LatticeVector = Vec<BigInt>;
+            type Basis = DenseMatrix<BigInt>;
+
+            fn determinant(&self) -> BigInt {
+                self.lattice.determinant()
+            }
+
+            fn dimension(&self) -> usize {
+                self.lattice.dimension()
+            }
+
+            fn from_bases(basis: &DenseMatrix<BigInt>) -> Self {
+                Self::new(IntegerLattice::from_bases(basis))
+            }
+
+            fn bases(&self) -> DenseMatrix<BigInt> {
+                self.lattice.bases()
+            }
+
+            fn gaussian_length(&self) -> f64 {
+                self.lattice.gaussian_length()
+            }
+
+            fn contains(&self, vec: &Vec<BigInt>) -> bool {
+                self.lattice.contains(vec)
+            }
+        }
+
+        impl<C: ConfigZZpX> LatticeReduction for $lattice<C> {
+            type GramSchmidtBasis = GramSchmidtBasis;
+
+            fn gram_schmidt_bases(&self) -> GramSchmidtBasis {
+                self.lattice.gram_schmidt()
+            }
+
+            fn babai(&self, vec: &Vec<BigInt>) -> Vec<BigInt> {
+                self.lattice.babai(vec)
+            }
+
+            fn lll(&self, delta: f64) -> Self {
+                Self::new(self.lattice.lll(delta))
+            }
+
+            fn bkz(&self, params: &BkzParameters) -> Self {
+                Self::new(self.lattice.bkz(params))
+            }
+        }
+    };
+}
+
+impl_structured_lattice!(RingIdealLattice);
+impl_structured_lattice!(ModuleSisLattice);
+
+impl<C: ConfigZZpX> IdealLattice for RingIdealLattice<C> {
+    type Ring = ZZpX<C>;
+
+    /// Build the ideal lattice, with basis `a * x^j` for `j < n`
+    fn from_ring_element(elem: &ZZpX<C>) -> Self {
+        Self::new(IntegerLattice::ideal(elem))
+    }
+}
+
+impl<C: ConfigZZpX> ModularLattice for ModuleSisLattice<C> {
+    type Ring = ZZpX<C>;
+
+    /// Number of ring elements in a lattice vector
+    fn rank(&self) -> usize {
+        self.lattice.ambient_dimension() / C::DIM
+    }
+
+    /// Build the module-SIS lattice of `elem`.
+    ///
+    /// With `A = [rot(a_1) | ... | rot(a_k)]` the `n x kn` matrix of the map
+    /// `z -> sum_i a_i z_i`, and its kernel over `ZZ_q` in reduced echelon form,
+    /// the basis holds `q e_p` for the pivot columns `p` of `A`, then the kernel
+    /// vectors lifted into `[0, q)`. Its determinant is `q^rank(A)`, i.e., `q^n` as
+    /// soon as one `a_i` is invertible.
+    fn from_ring_elements(elem: &[ZZpX<C>]) -> Self {
+        assert!(!elem.is_empty(), "no ring element");
+        let n = C::DIM;
+        let m = n * elem.len();
+        let blocks: Vec<DenseMatrix<ZZp<C::BaseConfig>>> =
+            elem.iter().map(DenseMatrix::from).collect();
+        let a = DenseMatrix::from_row_vectors(
+            (0..n)
+                .map(|i| blocks.iter().flat_map(|b| b.row(i).to_vec()).collect())
+                .collect(),
+        );
+
+        let (pivots, kernel) = kernel(&a);
+        let q = BigInt::from(ZZp::<C::BaseConfig>::modulus());
+        let mut rows: Vec<Vec<BigInt>> = pivots
+            .iter()
+            .map(|&p| {
+                let mut row = vec![BigInt::zero(); m];
+                row[p] = q.clone();
+                row
+            })
+            .collect();
+        rows.extend(
+            kernel
+                .iter()
+                .map(|v| v.iter().map(lift_positive).collect::<Vec<_>>()),
+        );
+        Self::from_bases(&DenseMatrix {
+            rows,
+            num_columns: m,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use num::{BigInt, Integer, ToPrimitive};
+
+    use super::{ModuleSisLattice, RingIdealLattice};
+    use crate::tests::{test_rng, ConfigZZpX12289_8};
+    use crate::{
+        ConfigRingGoldilocks256, ConfigZZpX, DenseMatrix, IdealLattice, IntegerLattice, Lattice,
+        LatticeReduction, Matrix, ModularLattice, Polynomial, PrimeField, Ring12289_512,
+        RingGoldilock256, ZZp, ZZpX,
+    };
+
+    /// Coefficients of `sum_i a_i z_i mod q`, where `z` is split into ring elements
+    fn module_product<C: ConfigZZpX>(a: &[ZZpX<C>], z: &[BigInt]) -> Vec<ZZp<C::BaseConfig>> {
+        let q = BigInt::from(ZZp::<C::BaseConfig>::modulus());
+        a.iter()
+            .zip(z.chunks(C::DIM))
+            .map(|(a, z)| {
+                let z: Vec<ZZp<C::BaseConfig>> = z
+                    .iter()
+                    .map(|x| ZZp::from(x.mod_floor(&q).to_u64().unwrap()))
+                    .collect();
+                DenseMatrix::from(a).mul_vector(&z)
+            })
+            .reduce(|x, y| x.iter().zip(y.iter()).map(|(x, y)| *x + y).collect())
+            .unwrap()
+    }
+
+    #[test]
+    fn test_ring_ideal_lattice() {
+        let mut rng = test_rng();
+        let a = Ring12289_512::random(&mut rng, None);
+        let lattice = RingIdealLattice::from_ring_element(&a);
+        assert_eq!(lattice.dimension(), 512);
+        let basis = lattice.bases();
+        assert_eq!(basis, IntegerLattice::ideal(&a).bases());
+
+        // row j holds a * x^j
+        let mut x = vec![0; 512];
+        x[1] = 1;
+        let x = Ring12289_512::from_primitive_types(&x);
+        let mut ax = a.clone();
+        for j in 0..4 {
+            let lift: Vec<BigInt> = ax.coefficients().map(|x| BigInt::from(x.lift())).collect();
+            assert_eq!(basis.row(j), &lift[..]);
+            ax *= &x;
+        }
+
+        let b = RingGoldilock256::random(&mut rng, None);
+        let lattice = RingIdealLattice::from_ring_element(&b);
+        assert_eq!(lattice.dimension(), 256);
+        assert_eq!(
+            lattice.into_integer_lattice().bases(),
+            IntegerLattice::ideal(&b).bases()
+        );
+    }
+
+    #[test]
+    fn test_module_sis_lattice() {
+        let mut rng = test_rng();
+        let a: Vec<ZZpX<ConfigZZpX12289_8>> =
+            (0..2).map(|_| ZZpX::random(&mut rng, None)).collect();
+        let lattice = ModuleSisLattice::from_ring_elements(&a);
+        assert_eq!(lattice.rank(), 2);
+        assert_eq!(lattice.dimension(), 16);
+        assert_eq!(lattice.determinant(), BigInt::from(12289).pow(8));
+
+        // the basis vectors are solutions; as the volume of all solutions is q^n,
+        // they span all of them
+        for row in lattice.bases().row_vectors() {
+            assert!(module_product(&a, row).iter().all(|x| u64::from(*x) == 0));
+        }
+
+        // a_2 e_1 - a_1 e_2 is a solution
+        let mut z: Vec<BigInt> = a[1].coefficients().map(|x| x.lift().into()).collect();
+        z.extend(a[0].coefficients().map(|x| BigInt::from(-x.lift())));
+        assert!(lattice.contains(&z));
+
+        // reduction finds short solutions
+        let reduced = lattice.lll(0.99);
+        assert_eq!(reduced, lattice);
+        assert!(reduced.integer_lattice().root_hermite_factor() < 1.05);
+    }
+
+    #[test]
+    fn test_module_sis_lattice_goldilocks() {
+        // the lattice under AjtaiHash, with two ring elements
+        let mut rng = test_rng();
+        let a: Vec<RingGoldilock256> = (0..2)
+            .map(|_| RingGoldilock256::random(&mut rng, None))
+            .collect();
+        let lattice = ModuleSisLattice::<ConfigRingGoldilocks256>::from_ring_elements(&a);
+        assert_eq!(lattice.rank(), 2);
+        assert_eq!(lattice.dimension(), 512);
+        let basis = lattice.bases();
+        for j in [0, 255, 256, 300, 511] {
+            assert!(module_product(&a, basis.row(j))
+                .iter()
+                .all(|x| u64::from(*x) == 0));
+        }
+    }
+}
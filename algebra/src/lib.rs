@@ -6,18 +6,34 @@
 
 /// NTT domain
 mod domain;
+/// Kyber-style encoding
+mod encoding;
+/// Seed expansion
+mod expand;
 /// Fields
 mod field;
+/// Gadget decomposition
+mod gadget;
+/// Lattices
+mod lattice;
+/// Matrices
+mod matrix;
+/// Modules over polynomial rings
+mod module;
 /// Polynomials
 mod polynomial;
 /// Polynomial ring elements
 mod ring;
+/// Samplers
+mod sampler;
+/// Serde support
+#[cfg(feature = "serde")]
+mod serde_impl;
+/// Serialization
+mod serialize;
 /// Vector space
 mod vector;
 
-// mod lattice;
-// mod matrix;
-
 #[cfg(test)]
 mod tests;
 
@@ -2,19 +2,22 @@
 
 use std::{
     fmt::Debug,
-    iter::Sum,
-    ops::{Add, AddAssign, Neg, Sub, SubAssign},
+    ops::{Add, AddAssign, Mul, Neg, Sub, SubAssign},
     slice::Iter,
 };
 
-use crate::{field::NTTField, RingElement};
+use num::{BigInt, One, Zero};
 
+use crate::{
+    ConfigNTTDomain, ConfigZZVec, ConfigZZp, ConfigZZpX, Field, NTTDomain, Polynomial, Vector,
+    ZZVec, ZZp, ZZpX,
+};
+
+/// larkwork's matrix trait
 pub trait Matrix:
     Sized
     + Eq
-    + Copy
     + Clone
-    + Default
     + Debug
     + Neg<Output = Self>
     + Add<Output = Self>
@@ -23,17 +26,17 @@ pub trait Matrix:
     + for<'a> Sub<&'a Self, Output = Self>
     + AddAssign
     + SubAssign
-    + Sum
     + for<'a> AddAssign<&'a Self>
     + for<'a> SubAssign<&'a Self>
-    + for<'a> Sum<&'a Self>
 {
     /// A matrix's element can be either a field element F
     /// or a ring element R.
     type Element;
 
     /// Number of rows and columns.
-    fn dim(&self) -> (usize, usize);
+    fn dim(&self) -> (usize, usize) {
+        (self.number_of_rows(), self.number_of_columns())
+    }
 
     /// Number of rows
     fn number_of_rows(&self) -> usize;
@@ -41,35 +44,639 @@ pub trait Matrix:
     /// Number of columns
     fn number_of_columns(&self) -> usize;
 
+    /// The i-th row
+    fn row(&self, i: usize) -> &[Self::Element];
+
+    /// The j-th column
+    fn column(&self, j: usize) -> Vec<Self::Element>;
+
     /// Expose rows as vectors
     fn row_vectors(&self) -> Iter<'_, Vec<Self::Element>>;
 
     /// Build a matrix from row vectors
     fn from_row_vectors(rows: Vec<Vec<Self::Element>>) -> Self;
 
-    /// Expose columns as vectors
-    fn column_vectors(&self) -> Iter<'_, Vec<Self::Element>>;
+    /// Columns as vectors
+    fn column_vectors(&self) -> Vec<Vec<Self::Element>>;
 
     /// Build a matrix from column vectors
-    fn from_column_vectors(rows: Vec<Vec<Self::Element>>) -> Self;
+    fn from_column_vectors(columns: Vec<Vec<Self::Element>>) -> Self;
 
     /// Transpose a matrix
     fn transpose(&self) -> Self;
 
-    /// Compute the determinant over F or R
-    fn determinant_modulo_p(&self) -> Self::Element;
-
-    /// multiply a matrix by left
+    /// multiply a matrix by left, i.e., `lhs * self`
     fn mul_by_left(&self, lhs: &Self) -> Self;
 
-    /// multiply a matrix by left
+    /// multiply a matrix by right, i.e., `self * rhs`
     fn mul_by_right(&self, rhs: &Self) -> Self;
+
+    /// multiply a column vector by right, i.e., `self * vector`
+    fn mul_vector(&self, vector: &[Self::Element]) -> Vec<Self::Element>;
 }
 
 /// An anti-circulant matrix can be obtained from a ring element
-pub trait AnitCirculantMatrix<R>: Matrix + From<R> + Into<R>
+pub trait AntiCirculantMatrix<R>: Matrix + for<'a> From<&'a R> {
+    /// Recover the ring element from its matrix.
+    /// Returns None if the matrix is not anti-circulant.
+    fn to_ring_element(&self) -> Option<R>;
+}
+
+/// Entries of a matrix: elements of a commutative ring
+pub trait MatrixElement:
+    Sized
+    + Eq
+    + Clone
+    + Debug
+    + Neg<Output = Self>
+    + for<'a> Add<&'a Self, Output = Self>
+    + for<'a> Sub<&'a Self, Output = Self>
+    + for<'a> Mul<&'a Self, Output = Self>
+    + for<'a> AddAssign<&'a Self>
+    + for<'a> SubAssign<&'a Self>
+{
+    /// Additive identity. Named so as not to clash with `Field::zero` and
+    /// friends when both traits are in scope.
+    fn additive_identity() -> Self;
+
+    /// Multiplicative identity
+    fn multiplicative_identity() -> Self;
+}
+
+impl<C: ConfigZZp> MatrixElement for ZZp<C> {
+    fn additive_identity() -> Self {
+        <Self as Field>::zero()
+    }
+
+    fn multiplicative_identity() -> Self {
+        <Self as Field>::one()
+    }
+}
+
+/// Ring elements
+impl<C: ConfigZZpX> MatrixElement for ZZpX<C>
 where
-    R: RingElement<Self::Element>,
-    Self::Element: NTTField,
+    Self: for<'a> Mul<&'a Self, Output = Self>,
 {
+    fn additive_identity() -> Self {
+        <Self as Polynomial<C>>::zero()
+    }
+
+    fn multiplicative_identity() -> Self {
+        <Self as Polynomial<C>>::one()
+    }
+}
+
+/// Ring elements in the NTT domain, with coefficient-wise multiplication
+impl<C: ConfigZZVec> MatrixElement for ZZVec<C> {
+    fn additive_identity() -> Self {
+        <Self as Vector<C>>::zero()
+    }
+
+    fn multiplicative_identity() -> Self {
+        <Self as Vector<C>>::one()
+    }
+}
+
+/// Integers, e.g., for lattice bases
+impl MatrixElement for BigInt {
+    fn additive_identity() -> Self {
+        BigInt::zero()
+    }
+
+    fn multiplicative_identity() -> Self {
+        BigInt::one()
+    }
+}
+
+/// A dense matrix, stored row by row
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DenseMatrix<E> {
+    pub(crate) rows: Vec<Vec<E>>,
+    pub(crate) num_columns: usize,
+}
+
+impl<E: MatrixElement> DenseMatrix<E> {
+    /// The `rows x columns` zero matrix
+    pub fn zero(rows: usize, columns: usize) -> Self {
+        Self {
+            rows: vec![vec![E::additive_identity(); columns]; rows],
+            num_columns: columns,
+        }
+    }
+
+    /// The `dim x dim` identity matrix
+    pub fn identity(dim: usize) -> Self {
+        let mut res = Self::zero(dim, dim);
+        for (i, row) in res.rows.iter_mut().enumerate() {
+            row[i] = E::multiplicative_identity();
+        }
+        res
+    }
+
+    /// Apply `f` to all entries
+    pub fn map<T>(&self, f: impl Fn(&E) -> T) -> DenseMatrix<T> {
+        DenseMatrix {
+            rows: self
+                .rows
+                .iter()
+                .map(|row| row.iter().map(&f).collect())
+                .collect(),
+            num_columns: self.num_columns,
+        }
+    }
+
+    fn assert_same_dim(&self, other: &Self) {
+        assert_eq!(self.dim(), other.dim(), "matrices of different dimensions");
+    }
+}
+
+impl<E: MatrixElement> Matrix for DenseMatrix<E> {
+    type Element = E;
+
+    /// Number of rows
+    fn number_of_rows(&self) -> usize {
+        self.rows.len()
+    }
+
+    /// Number of columns
+    fn number_of_columns(&self) -> usize {
+        self.num_columns
+    }
+
+    /// The i-th row
+    fn row(&self, i: usize) -> &[E] {
+        &self.rows[i]
+    }
+
+    /// The j-th column
+    fn column(&self, j: usize) -> Vec<E> {
+        assert!(j < self.num_columns, "column {} out of range", j);
+        self.rows.iter().map(|row| row[j].clone()).collect()
+    }
+
+    /// Expose rows as vectors
+    fn row_vectors(&self) -> Iter<'_, Vec<E>> {
+        self.rows.iter()
+    }
+
+    /// Build a matrix from row vectors, which must have the same length
+    fn from_row_vectors(rows: Vec<Vec<E>>) -> Self {
+        let num_columns = rows.first().map_or(0, |row| row.len());
+        assert!(
+            rows.iter().all(|row| row.len() == num_columns),
+            "rows of different lengths"
+        );
+        Self { rows, num_columns }
+    }
+
+    /// Columns as vectors
+    fn column_vectors(&self) -> Vec<Vec<E>> {
+        (0..self.num_columns).map(|j| self.column(j)).collect()
+    }
+
+    /// Build a matrix from column vectors, which must have the same length
+    fn from_column_vectors(columns: Vec<Vec<E>>) -> Self {
+        Self::from_row_vectors(columns).transpose()
+    }
+
+    /// Transpose a matrix
+    fn transpose(&self) -> Self {
+        Self {
+            rows: self.column_vectors(),
+            num_columns: self.rows.len(),
+        }
+    }
+
+    /// multiply a matrix by left, i.e., `lhs * self`
+    fn mul_by_left(&self, lhs: &Self) -> Self {
+        lhs.mul_by_right(self)
+    }
+
+    /// multiply a matrix by right, i.e., `self * rhs`
+    fn mul_by_right(&self, rhs: &Self) -> Self {
+        assert_eq!(
+            self.num_columns,
+            rhs.number_of_rows(),
+            "incompatible dimensions"
+        );
+        let columns = rhs.column_vectors();
+        let rows = self
+            .rows
+            .iter()
+            .map(|row| {
+                columns
+                    .iter()
+                    .map(|column| inner_product(row, column))
+                    .collect()
+            })
+            .collect();
+        Self {
+            rows,
+            num_columns: rhs.num_columns,
+        }
+    }
+
+    /// multiply a column vector by right, i.e., `self * vector`
+    fn mul_vector(&self, vector: &[E]) -> Vec<E> {
+        assert_eq!(self.num_columns, vector.len(), "incompatible dimensions");
+        self.rows
+            .iter()
+            .map(|row| inner_product(row, vector))
+            .collect()
+    }
+}
+
+/// Sum of the products of the entries of `a` and `b`
+fn inner_product<E: MatrixElement>(a: &[E], b: &[E]) -> E {
+    a.iter()
+        .zip(b.iter())
+        .fold(E::additive_identity(), |acc, (x, y)| acc + &(x.clone() * y))
+}
+
+// ========================
+// anti-circulant matrices
+// ========================
+/// The `DIM x DIM` negacyclic rotation matrix of a ring element `a`,
+/// whose j-th column holds the coefficients of `a * x^j`.
+/// Then `M(a) * b = a * b` for the coefficient vector of `b`.
+impl<C: ConfigZZpX> From<&ZZpX<C>> for DenseMatrix<ZZp<C::BaseConfig>> {
+    fn from(a: &ZZpX<C>) -> Self {
+        let n = C::DIM;
+        let rows = (0..n)
+            .map(|i| {
+                (0..n)
+                    .map(|j| {
+                        if i >= j {
+                            a.coeffs[i - j]
+                        } else {
+                            -a.coeffs[n + i - j]
+                        }
+                    })
+                    .collect()
+            })
+            .collect();
+        Self {
+            rows,
+            num_columns: n,
+        }
+    }
+}
+
+impl<C: ConfigZZpX> AntiCirculantMatrix<ZZpX<C>> for DenseMatrix<ZZp<C::BaseConfig>> {
+    fn to_ring_element(&self) -> Option<ZZpX<C>> {
+        if self.dim() != (C::DIM, C::DIM) {
+            return None;
+        }
+        let a = ZZpX {
+            coeffs: self.column(0),
+        };
+        if Self::from(&a) == *self {
+            Some(a)
+        } else {
+            None
+        }
+    }
+}
+
+// ========================
+// NTT domain
+// ========================
+impl<C: ConfigNTTDomain> DenseMatrix<ZZVec<C>> {
+    /// Move all entries of a matrix over the ring to the NTT domain
+    pub fn forward_ntt(matrix: &DenseMatrix<ZZpX<C::PolyConfig>>) -> Self {
+        DenseMatrix {
+            rows: matrix
+                .rows
+                .iter()
+                .map(|row| {
+                    row.iter()
+                        .map(<ZZVec<C> as NTTDomain<C::PolyConfig, C>>::forward_ntt)
+                        .collect()
+                })
+                .collect(),
+            num_columns: matrix.num_columns,
+        }
+    }
+
+    /// Move all entries back to the coefficient domain
+    pub fn reverse_ntt(&self) -> DenseMatrix<ZZpX<C::PolyConfig>> {
+        DenseMatrix {
+            rows: self
+                .rows
+                .iter()
+                .map(|row| {
+                    row.iter()
+                        .map(<ZZVec<C> as NTTDomain<C::PolyConfig, C>>::reverse_ntt)
+                        .collect()
+                })
+                .collect(),
+            num_columns: self.num_columns,
+        }
+    }
+
+    /// `A * s` for a matrix `A` kept in the NTT domain and a vector `s` of ring
+    /// elements, as in module-LWE. It takes one forward NTT per entry of `s`
+    /// and one reverse NTT per entry of the output.
+    pub fn mul_ring_vector(&self, s: &[ZZpX<C::PolyConfig>]) -> Vec<ZZpX<C::PolyConfig>> {
+        let s: Vec<ZZVec<C>> = s
+            .iter()
+            .map(<ZZVec<C> as NTTDomain<C::PolyConfig, C>>::forward_ntt)
+            .collect();
+        self.mul_vector(&s)
+            .iter()
+            .map(<ZZVec<C> as NTTDomain<C::PolyConfig, C>>::reverse_ntt)
+            .collect()
+    }
+}
+
+// ========================
+// additions
+// ========================
+impl<'a, E: MatrixElement> Add<&'a Self> for DenseMatrix<E> {
+    type Output = Self;
+
+    fn add(mut self, rhs: &'a Self) -> Self::Output {
+        self += rhs;
+        self
+    }
+}
+
+impl<E: MatrixElement> Add for DenseMatrix<E> {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        self + &rhs
+    }
+}
+
+impl<E: MatrixElement> AddAssign for DenseMatrix<E> {
+    fn add_assign(&mut self, rhs: Self) {
+        *self += &rhs;
+    }
+}
+
+impl<'a, E: MatrixElement> AddAssign<&'a Self> for DenseMatrix<E> {
+    fn add_assign(&mut self, rhs: &'a Self) {
+        self.assert_same_dim(rhs);
+        for (row, rhs_row) in self.rows.iter_mut().zip(rhs.rows.iter()) {
+            for (x, y) in row.iter_mut().zip(rhs_row.iter()) {
+                *x += y;
+            }
+        }
+    }
+}
+
+// ========================
+// subtract
+// ========================
+impl<'a, E: MatrixElement> Sub<&'a Self> for DenseMatrix<E> {
+    type Output = Self;
+
+    fn sub(mut self, rhs: &'a Self) -> Self::Output {
+        self -= rhs;
+        self
+    }
+}
+
+impl<E: MatrixElement> Sub for DenseMatrix<E> {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        self - &rhs
+    }
+}
+
+impl<E: MatrixElement> SubAssign for DenseMatrix<E> {
+    fn sub_assign(&mut self, rhs: Self) {
+        *self -= &rhs;
+    }
+}
+
+impl<'a, E: MatrixElement> SubAssign<&'a Self> for DenseMatrix<E> {
+    fn sub_assign(&mut self, rhs: &'a Self) {
+        self.assert_same_dim(rhs);
+        for (row, rhs_row) in self.rows.iter_mut().zip(rhs.rows.iter()) {
+            for (x, y) in row.iter_mut().zip(rhs_row.iter()) {
+                *x -= y;
+            }
+        }
+    }
+}
+
+// ========================
+// neg
+// ========================
+impl<E: MatrixElement> Neg for DenseMatrix<E> {
+    type Output = Self;
+
+    fn neg(self) -> Self::Output {
+        Self {
+            rows: self
+                .rows
+                .into_iter()
+                .map(|row| row.into_iter().map(|x| -x).collect())
+                .collect(),
+            num_columns: self.num_columns,
+        }
+    }
+}
+
+// ========================
+// multiplications
+// ========================
+impl<'a, E: MatrixElement> Mul<&'a Self> for DenseMatrix<E> {
+    type Output = Self;
+
+    fn mul(self, rhs: &'a Self) -> Self::Output {
+        self.mul_by_right(rhs)
+    }
+}
+
+impl<E: MatrixElement> Mul for DenseMatrix<E> {
+    type Output = Self;
+
+    fn mul(self, rhs: Self) -> Self::Output {
+        self.mul_by_right(&rhs)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use rand_xorshift::XorShiftRng;
+
+    use super::{AntiCirculantMatrix, DenseMatrix, Matrix};
+    use crate::tests::test_rng;
+    use crate::{
+        expand_matrix, ConfigZZVec8380417_256, Field, Polynomial, Ring3329_256, Ring8380417_256,
+        F12289, F3329, F8380417,
+    };
+
+    fn random_field_matrix(
+        rng: &mut XorShiftRng,
+        rows: usize,
+        columns: usize,
+    ) -> DenseMatrix<F12289> {
+        DenseMatrix::from_row_vectors(
+            (0..rows)
+                .map(|_| (0..columns).map(|_| F12289::random(&mut *rng)).collect())
+                .collect(),
+        )
+    }
+
+    #[test]
+    fn test_field_matrix() {
+        let f = |rows: Vec<Vec<u64>>| {
+            DenseMatrix::from_row_vectors(
+                rows.into_iter()
+                    .map(|row| row.into_iter().map(F12289::from).collect())
+                    .collect(),
+            )
+        };
+        let a = f(vec![vec![1, 2, 3], vec![4, 5, 6]]);
+        let b = f(vec![vec![7, 8], vec![9, 10], vec![11, 12]]);
+        assert_eq!(a.dim(), (2, 3));
+        assert_eq!(a.row(1), &[4, 5, 6].map(F12289::from));
+        assert_eq!(
+            a.column(2),
+            vec![3, 6].into_iter().map(F12289::from).collect::<Vec<_>>()
+        );
+        assert_eq!(a.transpose(), f(vec![vec![1, 4], vec![2, 5], vec![3, 6]]));
+        assert_eq!(
+            DenseMatrix::from_column_vectors(a.row_vectors().cloned().collect()),
+            a.transpose()
+        );
+        assert_eq!(a.clone() * &b, f(vec![vec![58, 64], vec![139, 154]]));
+        assert_eq!(b.mul_by_left(&a), a.mul_by_right(&b));
+        assert_eq!(
+            a.mul_vector(&[1, 0, 12288].map(F12289::from)),
+            vec![12287, 12287]
+                .into_iter()
+                .map(F12289::from)
+                .collect::<Vec<_>>()
+        );
+
+        let mut rng = test_rng();
+        let a = random_field_matrix(&mut rng, 3, 4);
+        let b = random_field_matrix(&mut rng, 4, 5);
+        let c = random_field_matrix(&mut rng, 5, 2);
+        let d = random_field_matrix(&mut rng, 4, 5);
+        // associativity, distributivity and transposition
+        assert_eq!((a.clone() * &b) * &c, a.clone() * (b.clone() * &c));
+        assert_eq!(
+            a.clone() * (b.clone() + &d),
+            a.clone() * &b + a.clone() * &d
+        );
+        assert_eq!((a.clone() * &b).transpose(), b.transpose() * a.transpose());
+        assert_eq!(DenseMatrix::identity(3) * &a, a);
+        assert_eq!(a.clone() - &a, DenseMatrix::zero(3, 4));
+        assert_eq!(-a.clone() + &a, DenseMatrix::zero(3, 4));
+    }
+
+    #[test]
+    fn test_ring_matrix() {
+        let mut rng = test_rng();
+        let a = DenseMatrix::from_row_vectors(
+            (0..2)
+                .map(|_| {
+                    (0..3)
+                        .map(|_| Ring3329_256::random(&mut rng, None))
+                        .collect()
+                })
+                .collect(),
+        );
+        let s: Vec<Ring3329_256> = (0..3)
+            .map(|_| Ring3329_256::random(&mut rng, None))
+            .collect();
+        let t = a.mul_vector(&s);
+        assert_eq!(t.len(), 2);
+        assert_eq!(
+            t[1],
+            a.row(1)[0].clone() * &s[0] + a.row(1)[1].clone() * &s[1] + a.row(1)[2].clone() * &s[2]
+        );
+        let column = DenseMatrix::from_column_vectors(vec![s]);
+        assert_eq!(
+            a.clone() * &column,
+            DenseMatrix::from_column_vectors(vec![t])
+        );
+    }
+
+    #[test]
+    fn test_anti_circulant_matrix() {
+        let mut rng = test_rng();
+        let a = Ring8380417_256::random(&mut rng, None);
+        let b = Ring8380417_256::random(&mut rng, None);
+        let ma = DenseMatrix::from(&a);
+        let mb = DenseMatrix::from(&b);
+        assert_eq!(ma.dim(), (256, 256));
+        assert_eq!(ma.to_ring_element(), Some(a.clone()));
+
+        // matrix-vector products agree with the ring multiplication
+        let coeffs: Vec<_> = b.coefficients().cloned().collect();
+        let ab = a.clone() * &b;
+        assert_eq!(
+            ma.mul_vector(&coeffs),
+            ab.coefficients().cloned().collect::<Vec<_>>()
+        );
+        assert_eq!(ma.clone() * &mb, DenseMatrix::from(&ab));
+        assert_eq!((ma.clone() + &mb).to_ring_element(), Some(a.clone() + &b));
+
+        // x^255 * x = -1
+        let mut x = [0u16; 256];
+        x[1] = 1;
+        let mx = DenseMatrix::from(&Ring3329_256::from_primitive_types(&x));
+        assert_eq!(mx.row(0)[255], -F3329::one());
+        let a = Ring3329_256::random(&mut rng, None);
+        let b = Ring3329_256::random(&mut rng, None);
+        let coeffs: Vec<_> = b.coefficients().cloned().collect();
+        assert_eq!(
+            DenseMatrix::from(&a).mul_vector(&coeffs),
+            (a * &b).coefficients().cloned().collect::<Vec<_>>()
+        );
+
+        // not anti-circulant
+        let mut rows: Vec<_> = ma.row_vectors().cloned().collect();
+        rows[3][7] += F8380417::one();
+        assert_eq!(
+            AntiCirculantMatrix::<Ring8380417_256>::to_ring_element(
+                &DenseMatrix::from_row_vectors(rows)
+            ),
+            None
+        );
+        assert_eq!(
+            AntiCirculantMatrix::<Ring8380417_256>::to_ring_element(&DenseMatrix::identity(3)),
+            None
+        );
+    }
+
+    #[test]
+    fn test_ntt_matrix() {
+        let mut rng = test_rng();
+        let seed = [7u8; 32];
+        let a_ntt =
+            DenseMatrix::from_row_vectors(expand_matrix::<ConfigZZVec8380417_256>(&seed, 4, 3));
+        let a = a_ntt.reverse_ntt();
+        assert_eq!(DenseMatrix::forward_ntt(&a), a_ntt);
+
+        let s: Vec<Ring8380417_256> = (0..3)
+            .map(|_| Ring8380417_256::random(&mut rng, None))
+            .collect();
+        assert_eq!(a_ntt.mul_ring_vector(&s), a.mul_vector(&s));
+
+        // products stay in the NTT domain
+        let b = DenseMatrix::from_row_vectors(
+            (0..3)
+                .map(|_| {
+                    (0..2)
+                        .map(|_| Ring8380417_256::random(&mut rng, None))
+                        .collect()
+                })
+                .collect(),
+        );
+        assert_eq!(
+            (a_ntt * &DenseMatrix::forward_ntt(&b)).reverse_ntt(),
+            a * &b
+        );
+    }
 }
@@ -0,0 +1,345 @@
+//! Modules `R_q^K` over polynomial rings, as used by module-lattice schemes.
+//!
+//! A [`ModuleVector`] keeps its elements in the coefficient domain, the NTT domain, or
+//! both, and computes the missing representation on first use, so that a vector used
+//! in several products, e.g., a public key, is transformed once, and a vector that is
+//! only used in products, e.g., an expanded matrix row, is never transformed back.
+
+use std::{
+    array,
+    ops::{Add, AddAssign, Mul, Neg, Sub, SubAssign},
+    slice::Iter,
+    sync::OnceLock,
+};
+
+use rand::RngCore;
+
+use crate::{ConfigNTTRing, NTTDomain, Polynomial, ZZVec, ZZpX};
+
+/// A vector of `K` elements of the ring configured by `R`
+#[derive(Debug, Clone)]
+pub struct ModuleVector<R: ConfigNTTRing, const K: usize> {
+    /// Coefficient representation; at least one of the two is always set
+    elements: OnceLock<[ZZpX<R>; K]>,
+    /// NTT representation
+    ntt: OnceLock<[ZZVec<R::NTTConfig>; K]>,
+}
+
+/// Forward NTT of a ring element
+fn forward<R: ConfigNTTRing>(x: &ZZpX<R>) -> ZZVec<R::NTTConfig> {
+    <ZZVec<R::NTTConfig> as NTTDomain<R, R::NTTConfig>>::forward_ntt(x)
+}
+
+/// Reverse NTT of a ring element
+fn reverse<R: ConfigNTTRing>(x: &ZZVec<R::NTTConfig>) -> ZZpX<R> {
+    <ZZVec<R::NTTConfig> as NTTDomain<R, R::NTTConfig>>::reverse_ntt(x)
+}
+
+/// Apply `f` entry-wise to a representation held by both sides; otherwise `x`
+/// drops its own, which is stale.
+fn zip_assign<T, const K: usize>(
+    x: &mut OnceLock<[T; K]>,
+    y: &OnceLock<[T; K]>,
+    f: impl Fn(&mut T, &T),
+) {
+    *x = match (x.take(), y.get()) {
+        (Some(mut x), Some(y)) => {
+            x.iter_mut().zip(y.iter()).for_each(|(a, b)| f(a, b));
+            OnceLock::from(x)
+        }
+        _ => OnceLock::new(),
+    };
+}
+
+impl<R: ConfigNTTRing, const K: usize> ModuleVector<R, K> {
+    /// Build a vector from its elements
+    pub fn new(elements: [ZZpX<R>; K]) -> Self {
+        Self {
+            elements: OnceLock::from(elements),
+            ntt: OnceLock::new(),
+        }
+    }
+
+    /// Build a vector from the NTT representation of its elements.
+    /// The coefficient representation is only computed if it is used.
+    pub fn from_ntt(ntt: [ZZVec<R::NTTConfig>; K]) -> Self {
+        Self {
+            elements: OnceLock::new(),
+            ntt: OnceLock::from(ntt),
+        }
+    }
+
+    /// The zero vector
+    pub fn zero() -> Self {
+        Self::new(array::from_fn(|_| ZZpX::zero()))
+    }
+
+    /// Sample a uniformly random vector
+    pub fn random(mut rng: impl RngCore) -> Self {
+        Self::new(array::from_fn(|_| ZZpX::random(&mut rng, None)))
+    }
+
+    /// Elements of the vector, computed on first use
+    pub fn elements(&self) -> &[ZZpX<R>; K] {
+        self.elements.get_or_init(|| {
+            let ntt = self.ntt.get().expect("a module vector without elements");
+            array::from_fn(|i| reverse(&ntt[i]))
+        })
+    }
+
+    /// Expose the elements as an iter
+    pub fn iter(&self) -> Iter<'_, ZZpX<R>> {
+        self.elements().iter()
+    }
+
+    /// Consume the vector into its elements
+    pub fn into_elements(self) -> [ZZpX<R>; K] {
+        self.elements();
+        self.elements.into_inner().unwrap()
+    }
+
+    /// NTT representation of the elements, computed on first use
+    pub fn ntt(&self) -> &[ZZVec<R::NTTConfig>; K] {
+        self.ntt.get_or_init(|| {
+            let elements = self
+                .elements
+                .get()
+                .expect("a module vector without elements");
+            array::from_fn(|i| forward(&elements[i]))
+        })
+    }
+
+    /// Multiply all elements by a ring element
+    pub fn mul_scalar(&self, scalar: &ZZpX<R>) -> Self {
+        let scalar = forward(scalar);
+        Self::from_ntt(array::from_fn(|i| self.ntt()[i].clone() * &scalar))
+    }
+
+    /// Inner product `sum_i self_i * other_i` over the ring.
+    /// It takes one reverse NTT, on top of the cached ones.
+    pub fn inner_product(&self, other: &Self) -> ZZpX<R> {
+        let sum: ZZVec<R::NTTConfig> = self
+            .ntt()
+            .iter()
+            .zip(other.ntt().iter())
+            .map(|(a, b)| a.clone() * b)
+            .sum();
+        reverse(&sum)
+    }
+
+    /// Infinity norm of the vector, over the centered lifts of the coefficients
+    pub fn infinity_norm(&self) -> u64 {
+        self.iter().map(|x| x.infinity_norm()).max().unwrap_or(0)
+    }
+
+    /// L2 norm of the vector, over the centered lifts of the coefficients.
+    ///
+    /// This is an approximation; use [`Self::l2_norm_square`] for exact norm checks.
+    pub fn l2_norm(&self) -> f64 {
+        (self.l2_norm_square() as f64).sqrt()
+    }
+
+    /// Square of the L2 norm of the vector, over the centered lifts of the coefficients.
+    /// Saturates at `u128::MAX`.
+    pub fn l2_norm_square(&self) -> u128 {
+        self.iter()
+            .fold(0u128, |acc, x| acc.saturating_add(x.l2_norm_square()))
+    }
+}
+
+impl<R: ConfigNTTRing, const K: usize> PartialEq for ModuleVector<R, K> {
+    fn eq(&self, other: &Self) -> bool {
+        self.elements() == other.elements()
+    }
+}
+
+impl<R: ConfigNTTRing, const K: usize> Eq for ModuleVector<R, K> {}
+
+impl<R: ConfigNTTRing, const K: usize> Default for ModuleVector<R, K> {
+    fn default() -> Self {
+        Self::zero()
+    }
+}
+
+impl<R: ConfigNTTRing, const K: usize> From<[ZZpX<R>; K]> for ModuleVector<R, K> {
+    fn from(elements: [ZZpX<R>; K]) -> Self {
+        Self::new(elements)
+    }
+}
+
+// ========================
+// additions
+// ========================
+impl<'a, R: ConfigNTTRing, const K: usize> Add<&'a Self> for ModuleVector<R, K> {
+    type Output = Self;
+
+    fn add(mut self, rhs: &'a Self) -> Self::Output {
+        self += rhs;
+        self
+    }
+}
+
+impl<R: ConfigNTTRing, const K: usize> Add for ModuleVector<R, K> {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        self + &rhs
+    }
+}
+
+impl<R: ConfigNTTRing, const K: usize> AddAssign for ModuleVector<R, K> {
+    fn add_assign(&mut self, rhs: Self) {
+        *self += &rhs;
+    }
+}
+
+impl<'a, R: ConfigNTTRing, const K: usize> AddAssign<&'a Self> for ModuleVector<R, K> {
+    fn add_assign(&mut self, rhs: &'a Self) {
+        // the NTT is linear: add in every representation both sides have,
+        // after making sure that there is one
+        if self.elements.get().is_none() || rhs.elements.get().is_none() {
+            self.ntt();
+            rhs.ntt();
+        }
+        zip_assign(&mut self.elements, &rhs.elements, |x, y| *x += y);
+        zip_assign(&mut self.ntt, &rhs.ntt, |x, y| *x += y);
+    }
+}
+
+// ========================
+// subtract
+// ========================
+impl<'a, R: ConfigNTTRing, const K: usize> Sub<&'a Self> for ModuleVector<R, K> {
+    type Output = Self;
+
+    fn sub(mut self, rhs: &'a Self) -> Self::Output {
+        self -= rhs;
+        self
+    }
+}
+
+impl<R: ConfigNTTRing, const K: usize> Sub for ModuleVector<R, K> {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        self - &rhs
+    }
+}
+
+impl<R: ConfigNTTRing, const K: usize> SubAssign for ModuleVector<R, K> {
+    fn sub_assign(&mut self, rhs: Self) {
+        *self -= &rhs;
+    }
+}
+
+impl<'a, R: ConfigNTTRing, const K: usize> SubAssign<&'a Self> for ModuleVector<R, K> {
+    fn sub_assign(&mut self, rhs: &'a Self) {
+        if self.elements.get().is_none() || rhs.elements.get().is_none() {
+            self.ntt();
+            rhs.ntt();
+        }
+        zip_assign(&mut self.elements, &rhs.elements, |x, y| *x -= y);
+        zip_assign(&mut self.ntt, &rhs.ntt, |x, y| *x -= y);
+    }
+}
+
+// ========================
+// neg
+// ========================
+impl<R: ConfigNTTRing, const K: usize> Neg for ModuleVector<R, K> {
+    type Output = Self;
+
+    fn neg(self) -> Self::Output {
+        let elements = self.elements.into_inner().map(|e| e.map(|x| -x));
+        let ntt = self.ntt.into_inner().map(|ntt| ntt.map(|x| -x));
+        Self {
+            elements: elements.map_or_else(OnceLock::new, OnceLock::from),
+            ntt: ntt.map_or_else(OnceLock::new, OnceLock::from),
+        }
+    }
+}
+
+// ========================
+// multiplications
+// ========================
+impl<'a, R: ConfigNTTRing, const K: usize> Mul<&'a ZZpX<R>> for ModuleVector<R, K> {
+    type Output = Self;
+
+    fn mul(self, rhs: &'a ZZpX<R>) -> Self::Output {
+        self.mul_scalar(rhs)
+    }
+}
+
+impl<R: ConfigNTTRing, const K: usize> Mul<ZZpX<R>> for ModuleVector<R, K> {
+    type Output = Self;
+
+    fn mul(self, rhs: ZZpX<R>) -> Self::Output {
+        self.mul_scalar(&rhs)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::ModuleVector;
+    use crate::tests::test_rng;
+    use crate::{
+        ConfigRing12289_512, ConfigRing8380417_256, Polynomial, Ring12289_512, Ring8380417_256,
+    };
+
+    #[test]
+    fn test_module_arithmetic() {
+        let mut rng = test_rng();
+        let a = ModuleVector::<ConfigRing8380417_256, 3>::random(&mut rng);
+        let b = ModuleVector::<ConfigRing8380417_256, 3>::random(&mut rng);
+        let c = Ring8380417_256::random(&mut rng, None);
+
+        // inner product and scalar multiplication agree with the ring
+        let expected: Ring8380417_256 = a.iter().zip(b.iter()).map(|(x, y)| x.clone() * y).sum();
+        assert_eq!(a.inner_product(&b), expected);
+        assert_eq!(a.inner_product(&b), b.inner_product(&a));
+        let ac = a.clone() * &c;
+        for (x, y) in ac.iter().zip(a.iter()) {
+            assert_eq!(*x, y.clone() * &c);
+        }
+        assert_eq!(ac.inner_product(&b), expected * &c);
+
+        // additions, with and without cached NTTs
+        let sum = a.clone() + &b;
+        assert_eq!(ModuleVector::from_ntt(sum.ntt().clone()), sum);
+        let d = ModuleVector::<ConfigRing8380417_256, 3>::new(a.elements().clone());
+        let e = ModuleVector::new(b.elements().clone());
+        assert_eq!(d.clone() + &e, sum);
+        assert_eq!((d.clone() + &e).ntt(), sum.ntt());
+        assert_eq!((sum.clone() - &b).ntt(), a.ntt());
+        assert_eq!(sum - &b, a);
+        assert_eq!((-a.clone()).ntt(), (ModuleVector::zero() - &a).ntt());
+        assert_eq!(-a.clone() + &a, ModuleVector::zero());
+
+        // a vector built from its NTT is only transformed back when needed
+        let f = ModuleVector::<ConfigRing8380417_256, 3>::from_ntt(a.ntt().clone());
+        assert!(f.elements.get().is_none());
+        let g = -(f.clone() + &b) + &f;
+        assert!(g.elements.get().is_none());
+        assert_eq!(a.inner_product(&f), a.inner_product(&a));
+        assert!(f.elements.get().is_none());
+        assert_eq!(g, -b.clone());
+        assert_eq!(f.into_elements(), a.elements().clone());
+    }
+
+    #[test]
+    fn test_module_norms() {
+        let mut rng = test_rng();
+        let a = Ring12289_512::random_binary(&mut rng);
+        let mut b = Ring12289_512::zero();
+        b -= Ring12289_512::one();
+        let v = ModuleVector::<ConfigRing12289_512, 2>::from([a.clone(), b]);
+        assert_eq!(v.infinity_norm(), 1);
+        assert_eq!(v.l2_norm_square(), a.l2_norm_square() + 1);
+        assert!((v.l2_norm() - (v.l2_norm_square() as f64).sqrt()).abs() < 1e-9);
+        assert_eq!(
+            ModuleVector::<ConfigRing12289_512, 2>::zero().l2_norm_square(),
+            0
+        );
+    }
+}
@@ -4,12 +4,15 @@
 mod definition;
 /// Instances
 mod instances;
+/// Sparse ternary polynomials
+mod sparse;
 /// generic implementation of ZZ[X] mod p
 mod zz_px;
 
-pub use definition::{ConfigZZpX, Polynomial};
+pub use definition::{ConfigZZpX, Polynomial, SparsePolynomial};
 pub use instances::{
-    ConfigZZpX12289_512, ConfigZZpX3329_256, ConfigZZpXGoldilocks256, Poly12289_512, Poly3329_256,
-    PolyGoldilock256,
+    ConfigZZpX12289_512, ConfigZZpX3329_256, ConfigZZpX8380417_256, ConfigZZpXGoldilocks256,
+    Poly12289_512, Poly3329_256, Poly8380417_256, PolyGoldilock256,
 };
+pub use sparse::{SparseError, SparseZZpX};
 pub use zz_px::ZZpX;
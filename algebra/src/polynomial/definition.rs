@@ -92,11 +92,17 @@ pub trait Polynomial<Config>:
         Self::random(rng, None)
     }
 
-    /// Infinity norm of the polynomial
-    fn infinity_norm(&self) -> u32;
+    /// Infinity norm of the polynomial, over the centered lifts of the coefficients
+    fn infinity_norm(&self) -> u64;
 
-    /// L2 norm of the polynomial
-    fn l2_norm(&self) -> u32;
+    /// L2 norm of the polynomial, over the centered lifts of the coefficients.
+    ///
+    /// This is an approximation; use [`Self::l2_norm_square`] for exact norm checks.
+    fn l2_norm(&self) -> f64;
+
+    /// Square of the L2 norm of the polynomial, over the centered lifts of the coefficients.
+    /// Saturates at `u128::MAX`, which is only reachable for 64-bit moduli.
+    fn l2_norm_square(&self) -> u128;
 
     /// degree of the polynomial
     fn degree(&self) -> usize;
@@ -115,18 +121,32 @@ pub trait Polynomial<Config>:
     fn from_primitive_types(coeffs: &[<Self::BaseField as Field>::PrimitiveType]) -> Self;
 }
 
-// /// Represents a sparse polynomial
-// pub trait SparsePolynomial<F: Field>: Polynomial<F> {
-//     /// Error type
-//     type Error;
+/// Represents a sparse polynomial
+pub trait SparsePolynomial<Config>: Sized + Clone + Debug + Eq {
+    /// Dense representation of the polynomial
+    type Dense: Polynomial<Config>;
+
+    /// Error type
+    type Error;
+
+    /// Convert from a polynomial.
+    /// Returns an error if the original polynomial is not sparse.
+    fn from_poly(poly: &Self::Dense) -> Result<Self, Self::Error>;
 
-//     /// Convert from a polynomial.
-//     /// Returns an error if the original polynomial is not sparse.
-//     fn from_poly<P: Polynomial<F>>(_: &P) -> Result<Self, Self::Error>;
+    /// Convert self into a polynomial.
+    fn into_poly(self) -> Self::Dense;
 
-//     /// Convert self into a polynomial.
-//     fn into_poly<P: Polynomial<F>>(self) -> P;
+    /// Sample a random ternary polynomial with a fixed weight:
+    /// `half_weight` coefficients are 1 and `half_weight` coefficients are -1
+    fn random_balanced_ternary(rng: impl RngCore, half_weight: usize) -> Self;
 
-//     /// Sample a random ternary polynomial with a fixed weight
-//     fn random_balanced_ternary(rng: impl RngCore, half_weight: usize) -> Self;
-// }
+    /// Number of non-zero coefficients
+    fn weight(&self) -> usize;
+
+    /// Negacyclic product with a dense polynomial, i.e., modulo `x^DIM + 1`
+    fn mul_dense(&self, poly: &Self::Dense) -> Self::Dense;
+
+    /// Negacyclic product with a dense polynomial, whose running time depends on self.
+    /// Only for public sparse polynomials, e.g., the challenges of a signature.
+    fn mul_dense_vartime(&self, poly: &Self::Dense) -> Self::Dense;
+}
@@ -1,7 +1,9 @@
 mod poly12289;
 mod poly3329;
+mod poly8380417;
 mod polygoldilocks;
 
 pub use poly12289::{ConfigZZpX12289_512, Poly12289_512};
 pub use poly3329::{ConfigZZpX3329_256, Poly3329_256};
+pub use poly8380417::{ConfigZZpX8380417_256, Poly8380417_256};
 pub use polygoldilocks::{ConfigZZpXGoldilocks256, PolyGoldilock256};
@@ -19,10 +19,17 @@ fn test_poly() {
     use crate::F12289;
     let coeffs = (0..ConfigZZpX12289_512::DIM)
         .map(|x| F12289::from(x as u64))
-        .collect::<Vec<_>>()
-        .try_into()
-        .unwrap();
+        .collect::<Vec<_>>();
     let poly = Poly12289_512 { coeffs };
     println!("poly {}", poly);
     println!("poly {}", poly.clone() + poly);
 }
+
+#[test]
+fn test_serialization() {
+    use crate::tests::serialize::serialization_tests;
+    use crate::{Polynomial, PrimeField, F12289};
+    serialization_tests("Poly12289_512".to_string(), F12289::modulus(), |rng| {
+        Poly12289_512::random(rng, None)
+    });
+}
@@ -19,10 +19,37 @@ fn test_poly() {
     use crate::F3329;
     let coeffs = (0..ConfigZZpX3329_256::DIM)
         .map(|x| F3329::from(x as u64))
-        .collect::<Vec<_>>()
-        .try_into()
-        .unwrap();
+        .collect::<Vec<_>>();
     let poly = Poly3329_256 { coeffs };
     println!("poly {}", poly);
     println!("poly {}", poly.clone() + poly);
 }
+
+#[test]
+fn test_norms_and_digest() {
+    use crate::{Polynomial, F3329};
+    let mut poly = Poly3329_256::zero();
+    poly.coeffs[0] = F3329::from(3328);
+    poly.coeffs[1] = F3329::from(2);
+    poly.coeffs[2] = F3329::from(1665);
+
+    // centered lifts are -1, 2 and -1664
+    assert_eq!(poly.infinity_norm(), 1664);
+    assert_eq!(poly.l2_norm_square(), 1 + 4 + 1664 * 1664);
+    assert!((poly.l2_norm() - (2768901f64).sqrt()).abs() < 1e-9);
+
+    // SHA-256 over the 2-byte little-endian encodings of the coefficients
+    let expected = "64356b173dda911fd5e280c808b23213325e37f154d9375c522332af122df9bc";
+    let digest: String = poly.digest().iter().map(|b| format!("{:02x}", b)).collect();
+    assert_eq!(digest, expected);
+    assert_ne!(poly.digest(), Poly3329_256::zero().digest());
+}
+
+#[test]
+fn test_serialization() {
+    use crate::tests::serialize::serialization_tests;
+    use crate::{Polynomial, PrimeField, F3329};
+    serialization_tests("Poly3329_256".to_string(), F3329::modulus(), |rng| {
+        Poly3329_256::random(rng, None)
+    });
+}
@@ -0,0 +1,35 @@
+use crate::{ConfigZZp8380417, ConfigZZpX, ZZpX};
+
+/// Configuration for ZZ[x]/(x^256+1) mod 8380417
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub struct ConfigZZpX8380417_256;
+
+impl ConfigZZpX for ConfigZZpX8380417_256 {
+    /// Config for the base field
+    type BaseConfig = ConfigZZp8380417;
+    /// Number of coefficients in a poly
+    const DIM: usize = 256;
+}
+
+/// Polynomial used in Dilithium with coefficient from ZZ_q where q=8380417.
+pub type Poly8380417_256 = ZZpX<ConfigZZpX8380417_256>;
+
+#[test]
+fn test_poly() {
+    use crate::F8380417;
+    let coeffs = (0..ConfigZZpX8380417_256::DIM)
+        .map(|x| F8380417::from(x as u64))
+        .collect::<Vec<_>>();
+    let poly = Poly8380417_256 { coeffs };
+    println!("poly {}", poly);
+    println!("poly {}", poly.clone() + poly);
+}
+
+#[test]
+fn test_serialization() {
+    use crate::tests::serialize::serialization_tests;
+    use crate::{Polynomial, PrimeField, F8380417};
+    serialization_tests("Poly8380417_256".to_string(), F8380417::modulus(), |rng| {
+        Poly8380417_256::random(rng, None)
+    });
+}
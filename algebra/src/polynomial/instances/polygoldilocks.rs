@@ -19,10 +19,19 @@ fn test_poly() {
     use crate::Goldilocks;
     let coeffs = (0..ConfigZZpXGoldilocks256::DIM)
         .map(|x| Goldilocks::from(x as u64))
-        .collect::<Vec<_>>()
-        .try_into()
-        .unwrap();
+        .collect::<Vec<_>>();
     let poly = PolyGoldilock256 { coeffs };
     println!("poly {}", poly);
     println!("poly {}", poly.clone() + poly);
 }
+
+#[test]
+fn test_serialization() {
+    use crate::tests::serialize::serialization_tests;
+    use crate::{Goldilocks, Polynomial, PrimeField};
+    serialization_tests(
+        "PolyGoldilock256".to_string(),
+        Goldilocks::modulus(),
+        |rng| PolyGoldilock256::random(rng, None),
+    );
+}
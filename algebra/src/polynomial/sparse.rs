@@ -0,0 +1,559 @@
+//! Sparse ternary polynomials, such as the challenges of Fiat-Shamir signatures
+//! and the fixed-weight secrets of NTRU.
+
+use std::fmt::Display;
+use std::marker::PhantomData;
+
+use rand::RngCore;
+use subtle::{Choice, ConditionallySelectable, ConstantTimeEq};
+
+use crate::field::value_barrier;
+use crate::{ConfigZZpX, Field, Polynomial, SparsePolynomial, ZZp, ZZpX};
+
+/// Errors of sparse polynomial conversions
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SparseError {
+    /// A coefficient is not in `{-1, 0, 1}`
+    NotTernary,
+}
+
+impl Display for SparseError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::NotTernary => write!(f, "the polynomial is not ternary"),
+        }
+    }
+}
+
+impl std::error::Error for SparseError {}
+
+/// A ternary polynomial in ZZ_p[X], stored as the positions of its non-zero
+/// coefficients and their signs.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SparseZZpX<C: ConfigZZpX> {
+    /// Positions of the non-zero coefficients, in increasing order
+    indices: Vec<usize>,
+    /// Whether the coefficient at the same position in `indices` is -1
+    signs: Vec<bool>,
+    _phantom: PhantomData<C>,
+}
+
+impl<C: ConfigZZpX> SparseZZpX<C> {
+    /// Positions of the non-zero coefficients, in increasing order
+    pub fn indices(&self) -> &[usize] {
+        &self.indices
+    }
+
+    /// Whether each non-zero coefficient is -1, in the order of [`Self::indices`]
+    pub fn signs(&self) -> &[bool] {
+        &self.signs
+    }
+
+    /// Build a sparse polynomial from a list of ternary coefficients
+    fn from_ternary(coeffs: &[i8]) -> Self {
+        let (indices, signs) = coeffs
+            .iter()
+            .enumerate()
+            .filter(|(_, &c)| c != 0)
+            .map(|(i, &c)| (i, c < 0))
+            .unzip();
+        Self {
+            indices,
+            signs,
+            _phantom: PhantomData,
+        }
+    }
+
+    /// `SampleInBall` from FIPS 204 (Algorithm 29): sample a polynomial with `tau`
+    /// coefficients in `{-1, 1}` from a byte stream, e.g., the output of SHAKE256.
+    ///
+    /// The first 8 bytes give the signs, then the positions are drawn by an
+    /// inside-out Fisher-Yates shuffle, with rejection sampling over
+    /// `ceil(log2(DIM))`-bit integers (bytes for `DIM = 256`).
+    ///
+    /// This is **not** constant time: the running time depends on the positions.
+    /// It is meant for public challenges only; secret fixed-weight polynomials should
+    /// use [`SparsePolynomial::random_balanced_ternary`].
+    ///
+    /// Returns `None` if the stream ends before all positions are sampled.
+    pub fn sample_in_ball(bytes: impl IntoIterator<Item = u8>, tau: usize) -> Option<Self> {
+        assert!(
+            tau <= 64 && tau <= C::DIM,
+            "tau = {} is too large; there are 64 sign bits",
+            tau
+        );
+        let mut bytes = bytes.into_iter();
+        let mut signs = [0u8; 8];
+        for s in signs.iter_mut() {
+            *s = bytes.next()?;
+        }
+        let signs = u64::from_le_bytes(signs);
+
+        let index_bits = usize::BITS - (C::DIM - 1).leading_zeros();
+        let index_bytes = (index_bits as usize).div_ceil(8);
+        let mut coeffs = vec![0i8; C::DIM];
+        for (k, i) in (C::DIM - tau..C::DIM).enumerate() {
+            let j = loop {
+                let mut j = 0usize;
+                for b in 0..index_bytes {
+                    j |= (bytes.next()? as usize) << (8 * b);
+                }
+                j &= (1 << index_bits) - 1;
+                if j <= i {
+                    break j;
+                }
+            };
+            coeffs[i] = coeffs[j];
+            coeffs[j] = 1 - 2 * ((signs >> k) & 1) as i8;
+        }
+        Some(Self::from_ternary(&coeffs))
+    }
+}
+
+// ========================
+// constant time sorting
+// ========================
+
+/// Swap `a` and `b` if `a > b`, without branching
+#[inline(always)]
+fn ct_compare_swap(a: &mut u64, b: &mut u64) {
+    let (_, greater) = b.overflowing_sub(*a);
+    let mask = value_barrier(0u64.wrapping_sub(greater as u64));
+    let t = (*a ^ *b) & mask;
+    *a ^= t;
+    *b ^= t;
+}
+
+/// Sort with a bitonic sorting network, whose memory accesses and branches only
+/// depend on the length. The length must be a power of two.
+fn ct_sort(v: &mut [u64]) {
+    let n = v.len();
+    debug_assert!(n.is_power_of_two());
+    let mut k = 2;
+    while k <= n {
+        let mut j = k / 2;
+        while j > 0 {
+            for i in 0..n {
+                let l = i ^ j;
+                if l > i {
+                    let (left, right) = v.split_at_mut(l);
+                    if i & k == 0 {
+                        ct_compare_swap(&mut left[i], &mut right[0]);
+                    } else {
+                        ct_compare_swap(&mut right[0], &mut left[i]);
+                    }
+                }
+            }
+            j /= 2;
+        }
+        k *= 2;
+    }
+}
+
+// ========================
+// sparse polynomial
+// ========================
+
+impl<C: ConfigZZpX> SparsePolynomial<C> for SparseZZpX<C> {
+    type Dense = ZZpX<C>;
+
+    type Error = SparseError;
+
+    /// Convert from a polynomial.
+    /// Returns an error if the original polynomial is not ternary.
+    fn from_poly(poly: &Self::Dense) -> Result<Self, Self::Error> {
+        let one = ZZp::one();
+        let coeffs = poly
+            .coefficients()
+            .map(|c| match c {
+                c if *c == ZZp::zero() => Ok(0),
+                c if *c == one => Ok(1),
+                c if *c == -one => Ok(-1),
+                _ => Err(SparseError::NotTernary),
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+        Ok(Self::from_ternary(&coeffs))
+    }
+
+    /// Convert self into a polynomial.
+    ///
+    /// Every coefficient is compared with every position, so that the memory
+    /// accesses do not depend on the positions.
+    fn into_poly(self) -> Self::Dense {
+        let one = ZZp::one();
+        let mut res = ZZpX::zero();
+        for (&index, &negative) in self.indices.iter().zip(self.signs.iter()) {
+            let value = ZZp::conditional_select(&one, &-one, Choice::from(negative as u8));
+            for (j, x) in res.coeffs.iter_mut().enumerate() {
+                *x = ZZp::conditional_select(x, &value, (j as u64).ct_eq(&(index as u64)));
+            }
+        }
+        res
+    }
+
+    /// Sample a random ternary polynomial with `half_weight` coefficients 1 and
+    /// `half_weight` coefficients -1, in constant time.
+    ///
+    /// As in NTRU's fixed-type sampler, the ternary coefficients are stored in
+    /// the low bits of random words, which are sorted by a sorting network to
+    /// shuffle them. A second sort moves the non-zero coefficients to the front,
+    /// ordered by position.
+    fn random_balanced_ternary(mut rng: impl RngCore, half_weight: usize) -> Self {
+        assert!(
+            2 * half_weight <= C::DIM,
+            "the weight {} is larger than the dimension",
+            2 * half_weight
+        );
+        let len = C::DIM.next_power_of_two();
+
+        // random words tagged with 1 for a coefficient 1, 2 for -1 and 0 otherwise;
+        // the padding words are sorted last
+        let mut words: Vec<u64> = (0..len)
+            .map(|i| {
+                let tag =
+                    (i < half_weight) as u64 + 2 * (half_weight <= i && i < 2 * half_weight) as u64;
+                if i < C::DIM {
+                    (rng.next_u64() & !3) | tag
+                } else {
+                    u64::MAX
+                }
+            })
+            .collect();
+        ct_sort(&mut words);
+
+        // move the non-zero tags to the front, ordered by position
+        for (i, word) in words.iter_mut().enumerate().take(C::DIM) {
+            let tag = *word & 3;
+            let is_zero = ((tag | tag.wrapping_neg()) >> 63) ^ 1;
+            *word = (is_zero << 63) | ((i as u64) << 2) | tag;
+        }
+        ct_sort(&mut words);
+
+        let (indices, signs) = words[..2 * half_weight]
+            .iter()
+            .map(|&word| ((word >> 2) as usize, word & 3 == 2))
+            .unzip();
+        Self {
+            indices,
+            signs,
+            _phantom: PhantomData,
+        }
+    }
+
+    /// Number of non-zero coefficients
+    fn weight(&self) -> usize {
+        self.indices.len()
+    }
+
+    /// Negacyclic product with a dense polynomial, i.e., modulo `x^DIM + 1`.
+    ///
+    /// Each non-zero coefficient adds a rotation of `poly`. The rotations go through a
+    /// barrel shifter, so that neither the operations nor the memory accesses depend
+    /// on the positions and signs, at the cost of about `2 weight DIM log(DIM)`
+    /// conditional assignments. This is slower than an NTT product: for `DIM = 256`
+    /// and a weight of 60, about 4 times as slow as a product in `Ring8380417_256`
+    /// (see `bench_mul_dense`). Use it for secret sparse polynomials, and
+    /// [`Self::mul_dense_vartime`] for public ones.
+    fn mul_dense(&self, poly: &Self::Dense) -> Self::Dense {
+        let n = C::DIM;
+        // x^n = -1, so rotating (poly, -poly) gives the negacyclic shifts of poly
+        let mut extended = poly.coeffs.clone();
+        extended.extend(poly.coeffs.iter().map(|x| -*x));
+        let mut res = vec![ZZp::zero(); n];
+        for (&index, &negative) in self.indices.iter().zip(self.signs.iter()) {
+            let rotated = ct_rotate(&extended, index, Choice::from(negative as u8));
+            for (x, y) in res.iter_mut().zip(rotated.iter()) {
+                *x += y;
+            }
+        }
+        ZZpX { coeffs: res }
+    }
+
+    /// Negacyclic product with a dense polynomial, with `weight * DIM` additions
+    /// whose positions depend on self. For `DIM = 256` and a weight of 60, it is
+    /// about 5 times as fast as [`Self::mul_dense`], and a bit faster than an NTT
+    /// product.
+    fn mul_dense_vartime(&self, poly: &Self::Dense) -> Self::Dense {
+        let n = C::DIM;
+        let mut res = vec![ZZp::zero(); n];
+        for (&index, &negative) in self.indices.iter().zip(self.signs.iter()) {
+            // x^index * poly, where the coefficients wrapping around x^n = -1 are negated
+            let (low, high) = poly.coeffs.split_at(n - index);
+            let (res_low, res_high) = res.split_at_mut(index);
+            if negative {
+                res_high.iter_mut().zip(low).for_each(|(x, y)| *x -= y);
+                res_low.iter_mut().zip(high).for_each(|(x, y)| *x += y);
+            } else {
+                res_high.iter_mut().zip(low).for_each(|(x, y)| *x += y);
+                res_low.iter_mut().zip(high).for_each(|(x, y)| *x -= y);
+            }
+        }
+        ZZpX { coeffs: res }
+    }
+}
+
+/// Rotate `v = (poly, -poly)` to the right by `shift < v.len() / 2`, and by another
+/// `v.len() / 2`, i.e., negate it, if `negative` is set.
+///
+/// This is a barrel shifter: one conditional rotation per bit of `shift`, so that the
+/// memory accesses only depend on the length.
+fn ct_rotate<F: Field>(v: &[F], shift: usize, negative: Choice) -> Vec<F> {
+    let n = v.len() / 2;
+    let mut res = v.to_vec();
+    let mut rotated = vec![F::zero(); v.len()];
+    let mut step = 1;
+    let mut bits = shift;
+    while step < n {
+        rotate_select(&mut res, &mut rotated, step, Choice::from((bits & 1) as u8));
+        bits >>= 1;
+        step <<= 1;
+    }
+    rotate_select(&mut res, &mut rotated, n, negative);
+    res
+}
+
+/// Replace `v` by its rotation to the right by `step` if `choice` is set, with
+/// `buffer` as scratch space
+#[inline(always)]
+fn rotate_select<F: Field>(v: &mut [F], buffer: &mut [F], step: usize, choice: Choice) {
+    let len = v.len();
+    buffer[step..].copy_from_slice(&v[..len - step]);
+    buffer[..step].copy_from_slice(&v[len - step..]);
+    for (x, y) in v.iter_mut().zip(buffer.iter()) {
+        x.conditional_assign(y, choice);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rand::{RngCore, SeedableRng};
+    use rand_xorshift::XorShiftRng;
+
+    use super::{ct_sort, SparseZZpX};
+    use crate::tests::test_rng;
+    use crate::tests::timing::assert_constant_time;
+    use crate::{
+        ConfigZZpX12289_512, ConfigZZpX3329_256, ConfigZZpX8380417_256, Poly3329_256, Polynomial,
+        PrimeField, Ring12289_512, Ring3329_256, Ring8380417_256, SparseError, SparsePolynomial,
+        F3329,
+    };
+
+    /// SampleInBall from FIPS 204, Algorithm 29, with the SHAKE256 output given as bytes
+    fn sample_in_ball_reference(bytes: &[u8], tau: usize) -> Vec<i64> {
+        let mut c = vec![0i64; 256];
+        let h: Vec<u64> = (0..64)
+            .map(|k| (bytes[k / 8] as u64 >> (k % 8)) & 1)
+            .collect();
+        let mut offset = 8;
+        for i in 256 - tau..256 {
+            let mut j = bytes[offset] as usize;
+            offset += 1;
+            while j > i {
+                j = bytes[offset] as usize;
+                offset += 1;
+            }
+            c[i] = c[j];
+            c[j] = 1 - 2 * h[i + tau - 256] as i64;
+        }
+        c
+    }
+
+    #[test]
+    fn test_sample_in_ball() {
+        let mut rng = test_rng();
+        // tau for ML-DSA-44, 65 and 87
+        for tau in [39, 49, 60] {
+            for _ in 0..100 {
+                let mut bytes = [0u8; 1024];
+                rng.fill_bytes(&mut bytes);
+                let c = SparseZZpX::<ConfigZZpX8380417_256>::sample_in_ball(bytes, tau).unwrap();
+                assert_eq!(c.weight(), tau);
+                let expected = sample_in_ball_reference(&bytes, tau);
+                let lifted: Vec<i64> = c.into_poly().coefficients().map(|x| x.lift()).collect();
+                assert_eq!(lifted, expected);
+            }
+        }
+
+        // the stream is too short
+        assert!(SparseZZpX::<ConfigZZpX8380417_256>::sample_in_ball([0u8; 40], 39).is_none());
+
+        // two bytes per position for DIM = 512
+        let mut bytes = [0u8; 1024];
+        rng.fill_bytes(&mut bytes);
+        let c = SparseZZpX::<ConfigZZpX12289_512>::sample_in_ball(bytes, 64).unwrap();
+        assert_eq!(c.weight(), 64);
+    }
+
+    #[test]
+    fn test_random_balanced_ternary() {
+        let mut rng = test_rng();
+        for half_weight in [0, 1, 30, 64, 128] {
+            let s =
+                SparseZZpX::<ConfigZZpX3329_256>::random_balanced_ternary(&mut rng, half_weight);
+            assert_eq!(s.weight(), 2 * half_weight);
+            assert!(s.indices().windows(2).all(|w| w[0] < w[1]));
+            assert_eq!(s.signs().iter().filter(|&&x| x).count(), half_weight);
+
+            let poly = s.clone().into_poly();
+            assert!(poly.is_ternary());
+            assert_eq!(poly.l2_norm_square(), 2 * half_weight as u128);
+            assert_eq!(SparseZZpX::from_poly(&poly).unwrap(), s);
+        }
+        let s = SparseZZpX::<ConfigZZpX12289_512>::random_balanced_ternary(&mut rng, 100);
+        assert_eq!(s.weight(), 200);
+        assert!(s.indices().iter().all(|&i| i < 512));
+
+        // each position is non-zero with probability 2 * half_weight / DIM
+        let num_samples = 2000;
+        let mut counts = [[0f64; 256]; 2];
+        for _ in 0..num_samples {
+            let s = SparseZZpX::<ConfigZZpX3329_256>::random_balanced_ternary(&mut rng, 32);
+            for (&i, &negative) in s.indices().iter().zip(s.signs()) {
+                counts[negative as usize][i] += 1.0;
+            }
+        }
+        let expected = num_samples as f64 * 32.0 / 256.0;
+        let chi_square: f64 = counts
+            .iter()
+            .flatten()
+            .map(|c| (c - expected) * (c - expected) / expected)
+            .sum();
+        // 99.9% quantile of chi-square with 511 degrees of freedom
+        assert!(chi_square < 617.0, "chi square: {}", chi_square);
+
+        // not ternary
+        let poly = Poly3329_256::from_coefficients_vec_unchecked(vec![F3329::from(2u64); 256]);
+        assert_eq!(SparseZZpX::from_poly(&poly), Err(SparseError::NotTernary));
+    }
+
+    #[test]
+    #[cfg_attr(
+        not(feature = "ct-tests"),
+        ignore = "timing test, needs the ct-tests feature"
+    )]
+    fn test_random_balanced_ternary_constant_time() {
+        let mut rng = test_rng();
+        // a fixed seed versus random seeds
+        assert_constant_time(
+            "random_balanced_ternary",
+            &mut rng,
+            |rng: &mut XorShiftRng, class| {
+                let mut seed = [0u8; 16];
+                if class {
+                    rng.fill_bytes(&mut seed);
+                }
+                seed[0] = 1;
+                seed
+            },
+            |seed| {
+                SparseZZpX::<ConfigZZpX3329_256>::random_balanced_ternary(
+                    XorShiftRng::from_seed(seed),
+                    32,
+                )
+            },
+        );
+    }
+
+    #[test]
+    #[cfg_attr(
+        not(feature = "ct-tests"),
+        ignore = "timing test, needs the ct-tests feature"
+    )]
+    fn test_mul_dense_constant_time() {
+        let mut rng = test_rng();
+        let a = Ring3329_256::random(&mut rng, None);
+        let fixed = SparseZZpX::<ConfigZZpX3329_256>::random_balanced_ternary(&mut rng, 32);
+        let secrets: Vec<_> = (0..16)
+            .map(|_| SparseZZpX::random_balanced_ternary(&mut rng, 32))
+            .collect();
+        // a fixed secret versus random secrets of the same weight
+        assert_constant_time(
+            "mul_dense",
+            &mut rng,
+            |rng: &mut XorShiftRng, class| {
+                if class {
+                    (rng.next_u32() % 16) as usize
+                } else {
+                    16
+                }
+            },
+            |i| secrets.get(i).unwrap_or(&fixed).mul_dense(&a),
+        );
+    }
+
+    #[test]
+    fn test_ct_sort() {
+        let mut rng = test_rng();
+        let mut v: Vec<u64> = (0..256).map(|_| rng.next_u64()).collect();
+        let mut expected = v.clone();
+        expected.sort_unstable();
+        ct_sort(&mut v);
+        assert_eq!(v, expected);
+    }
+
+    #[test]
+    fn test_mul_dense() {
+        let mut rng = test_rng();
+        for half_weight in [0, 1, 20, 128] {
+            let s = SparseZZpX::random_balanced_ternary(&mut rng, half_weight);
+            let a = Ring3329_256::random(&mut rng, None);
+            assert_eq!(s.mul_dense(&a), s.clone().into_poly() * &a);
+            assert_eq!(s.mul_dense_vartime(&a), s.clone().into_poly() * a);
+
+            let s = SparseZZpX::random_balanced_ternary(&mut rng, half_weight);
+            let a = Ring8380417_256::random(&mut rng, None);
+            assert_eq!(s.mul_dense(&a), s.clone().into_poly() * &a);
+            assert_eq!(s.mul_dense_vartime(&a), s.clone().into_poly() * a);
+
+            let s = SparseZZpX::random_balanced_ternary(&mut rng, half_weight);
+            let a = Ring12289_512::random(&mut rng, None);
+            assert_eq!(s.mul_dense(&a), s.clone().into_poly() * &a);
+            assert_eq!(s.mul_dense_vartime(&a), s.clone().into_poly() * a);
+        }
+
+        // x^255 * x = -1
+        let mut bytes = [0u8; 16];
+        bytes[8] = 255;
+        let s = SparseZZpX::<ConfigZZpX3329_256>::sample_in_ball(bytes, 1).unwrap();
+        assert_eq!(s.indices(), &[255]);
+        let mut x = Ring3329_256::zero();
+        x.coeffs[1] = F3329::from(1u64);
+        assert_eq!(s.mul_dense(&x), -Ring3329_256::one());
+        assert_eq!(s.mul_dense_vartime(&x), -Ring3329_256::one());
+    }
+
+    /// Compare the sparse products with an NTT product, for an ML-DSA challenge; run it
+    /// with `cargo test --release bench_mul_dense -- --ignored --nocapture`.
+    #[test]
+    #[ignore = "benchmark"]
+    fn bench_mul_dense() {
+        use std::time::Instant;
+
+        let mut rng = test_rng();
+        let repeat = 1000;
+        let s = SparseZZpX::<ConfigZZpX8380417_256>::random_balanced_ternary(&mut rng, 30);
+        let a = Ring8380417_256::random(&mut rng, None);
+        let c = s.clone().into_poly();
+
+        let start = Instant::now();
+        for _ in 0..repeat {
+            s.mul_dense(&a);
+        }
+        println!("mul_dense, weight 60: {:?}", start.elapsed() / repeat);
+
+        let start = Instant::now();
+        for _ in 0..repeat {
+            s.mul_dense_vartime(&a);
+        }
+        println!(
+            "mul_dense_vartime, weight 60: {:?}",
+            start.elapsed() / repeat
+        );
+
+        let start = Instant::now();
+        for _ in 0..repeat {
+            let _ = c.clone() * &a;
+        }
+        println!("Ring8380417_256 mul: {:?}", start.elapsed() / repeat);
+    }
+}
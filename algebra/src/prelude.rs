@@ -1,6 +1,14 @@
 pub use crate::domain::*;
+pub use crate::encoding::*;
+pub use crate::expand::*;
 pub use crate::field::*;
+pub use crate::gadget::*;
+pub use crate::lattice::*;
+pub use crate::matrix::*;
+pub use crate::module::*;
 pub use crate::polynomial::*;
 pub use crate::ring::*;
+pub use crate::sampler::*;
+pub use crate::serialize::*;
 pub use crate::vector::*;
 // pub use crate::vector::{LatticeVector, NTTVector, Vector};
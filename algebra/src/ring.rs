@@ -4,7 +4,8 @@
 mod definition;
 mod instances;
 
-pub use definition::PolynomialRing;
+pub use definition::{ConfigNTTRing, PolynomialRing};
 pub use instances::{
-    ConfigRing12289_512, ConfigRingGoldilocks256, Ring12289_512, RingGoldilock256,
+    ConfigRing12289_512, ConfigRing3329_256, ConfigRing8380417_256, ConfigRingGoldilocks256,
+    Ring12289_512, Ring3329_256, Ring8380417_256, RingGoldilock256,
 };
@@ -3,7 +3,7 @@ use std::{
     ops::{Mul, MulAssign},
 };
 
-use crate::Polynomial;
+use crate::{ConfigNTTDomain, ConfigZZpX, Polynomial};
 
 /// A ring element is a polynomial that also allows for multiplication.
 // Although in theory a ring can work on non-NTT friendly field,
@@ -18,3 +18,13 @@ pub trait PolynomialRing<ConfigPoly, ConfigVec>:
     + for<'a> MulAssign<&'a Self>
 {
 }
+
+/// Configuration of a polynomial ring with an NTT domain, where multiplication is
+/// coefficient-wise.
+///
+/// Rings with an incomplete NTT, such as [`Ring3329_256`](crate::Ring3329_256), do
+/// not implement it.
+pub trait ConfigNTTRing: ConfigZZpX {
+    /// Config of the ring elements in the NTT domain
+    type NTTConfig: ConfigNTTDomain<PolyConfig = Self, BaseConfig = Self::BaseConfig>;
+}
@@ -1,5 +1,9 @@
 mod ring12289;
+mod ring3329;
+mod ring8380417;
 mod ringgoldilocks;
 
 pub use ring12289::{ConfigRing12289_512, Ring12289_512};
+pub use ring3329::{ConfigRing3329_256, Ring3329_256};
+pub use ring8380417::{ConfigRing8380417_256, Ring8380417_256};
 pub use ringgoldilocks::{ConfigRingGoldilocks256, RingGoldilock256};
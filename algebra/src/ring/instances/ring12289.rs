@@ -2,7 +2,7 @@ use core::iter::Product;
 use std::ops::{Mul, MulAssign};
 
 use crate::{
-    ConfigZZVec12289_512, ConfigZZpX12289_512, NTTDomain, Poly12289_512, Polynomial,
+    ConfigNTTRing, ConfigZZVec12289_512, ConfigZZpX12289_512, NTTDomain, Poly12289_512, Polynomial,
     PolynomialRing, ZZVec,
 };
 
@@ -45,10 +45,7 @@ impl<'b> MulAssign<&'b Ring12289_512> for Ring12289_512 {
     fn mul_assign(&mut self, rhs: &'b Ring12289_512) {
         let a: ZZVec<ConfigZZVec12289_512> = NTTDomain::forward_ntt(self);
         let b: ZZVec<ConfigZZVec12289_512> = NTTDomain::forward_ntt(rhs);
-        println!("a: {}", a);
-        println!("b: {}", b);
         let c = a * b;
-        println!("c: {}", c);
         *self = c.reverse_ntt();
     }
 }
@@ -64,6 +61,10 @@ where
 
 impl PolynomialRing<ConfigRing12289_512, ConfigZZVec12289_512> for Ring12289_512 {}
 
+impl ConfigNTTRing for ConfigRing12289_512 {
+    type NTTConfig = ConfigZZVec12289_512;
+}
+
 #[cfg(test)]
 impl Ring12289_512 {
     /// school book multiplication
@@ -96,16 +97,128 @@ impl Ring12289_512 {
 
 #[test]
 fn test_ring_mul() {
-    use rand::SeedableRng;
-    use rand_xorshift::XorShiftRng;
+    use crate::tests::test_rng;
 
-    let mut rng = XorShiftRng::from_seed([
-        0x59, 0x62, 0xbe, 0x5d, 0x76, 0x3d, 0x31, 0x8d, 0x17, 0xdb, 0x37, 0x32, 0x54, 0x06, 0xbc,
-        0xe5,
-    ]);
+    let mut rng = test_rng();
     let a = Ring12289_512::random(&mut rng, None);
     let b = Ring12289_512::random(&mut rng, None);
     let c = Ring12289_512::schoolbook_mul(&a, &b);
     let d = a * b;
     assert_eq!(c, d)
 }
+
+/// Compare the NTT multiplication with `%`, Barrett and Montgomery reductions; run it
+/// with `cargo test --release bench_ring_mul -- --ignored --nocapture`.
+#[test]
+#[ignore = "benchmark"]
+fn bench_ring_mul() {
+    use std::time::Instant;
+
+    use crate::tests::test_rng;
+    use crate::{ConfigZZp, F12289Montgomery, NTTTable, ZZp, F12289};
+
+    /// ZZ mod 12289 with the hardware `%` reduction, as a baseline
+    #[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+    struct ConfigZZp12289Rem;
+
+    impl ConfigZZp for ConfigZZp12289Rem {
+        type PrimitiveType = u16;
+        type ProductType = u32;
+        const MODULUS: Self::PrimitiveType = 12289;
+        const TWO_ADICITY: u32 = 12;
+        const NON_RESIDUE: Self::PrimitiveType = 11;
+        const GENERATOR: Self::PrimitiveType = 11;
+
+        fn mul_internal(a: &Self::PrimitiveType, b: &Self::PrimitiveType) -> Self::PrimitiveType {
+            (*a as u32 * *b as u32 % Self::MODULUS as u32) as u16
+        }
+
+        fn add_internal(a: &Self::PrimitiveType, b: &Self::PrimitiveType) -> Self::PrimitiveType {
+            let mut tmp = a + b;
+            if tmp >= Self::MODULUS {
+                tmp -= Self::MODULUS
+            }
+            tmp
+        }
+
+        fn sub_internal(a: &Self::PrimitiveType, b: &Self::PrimitiveType) -> Self::PrimitiveType {
+            if a >= b {
+                a - b
+            } else {
+                a + Self::MODULUS - b
+            }
+        }
+
+        fn eq_internal(a: &Self::PrimitiveType, b: &Self::PrimitiveType) -> bool {
+            a % Self::MODULUS == b % Self::MODULUS
+        }
+    }
+
+    /// negacyclic multiplication via the NTT
+    fn ntt_mul<C: ConfigZZp>(a: &[ZZp<C>], b: &[ZZp<C>], table: &NTTTable<C>) -> Vec<ZZp<C>> {
+        let mut a = a.to_vec();
+        let mut b = b.to_vec();
+        table.forward(&mut a);
+        table.forward(&mut b);
+        a.iter_mut().zip(b.iter()).for_each(|(x, y)| *x *= y);
+        table.inverse(&mut a);
+        a
+    }
+
+    let mut rng = test_rng();
+    let repeat = 10000;
+    let a = Ring12289_512::random(&mut rng, None);
+    let b = Ring12289_512::random(&mut rng, None);
+    let c = a.clone() * &b;
+
+    // hardware `%`
+    let a_rem = a
+        .coeffs
+        .iter()
+        .map(|x| ZZp::<ConfigZZp12289Rem>::from(u64::from(*x)));
+    let a_rem = a_rem.collect::<Vec<_>>();
+    let b_rem = b
+        .coeffs
+        .iter()
+        .map(|x| ZZp::<ConfigZZp12289Rem>::from(u64::from(*x)));
+    let b_rem = b_rem.collect::<Vec<_>>();
+    let table_rem = NTTTable::get(512, ZZp::<ConfigZZp12289Rem>::from(49));
+    let start = Instant::now();
+    for _ in 0..repeat {
+        ntt_mul(&a_rem, &b_rem, table_rem);
+    }
+    println!("Ring12289_512 mul with %: {:?}", start.elapsed() / repeat);
+
+    // Barrett
+    let table = NTTTable::get(512, F12289::from(49));
+    let start = Instant::now();
+    for _ in 0..repeat {
+        ntt_mul(&a.coeffs, &b.coeffs, table);
+    }
+    println!(
+        "Ring12289_512 mul with Barrett: {:?}",
+        start.elapsed() / repeat
+    );
+
+    // Montgomery
+    let a_mont = a.coeffs.iter().map(|x| F12289Montgomery::from(*x));
+    let a_mont = a_mont.collect::<Vec<_>>();
+    let b_mont = b.coeffs.iter().map(|x| F12289Montgomery::from(*x));
+    let b_mont = b_mont.collect::<Vec<_>>();
+    let table_mont = NTTTable::get(512, F12289Montgomery::from(49));
+    let start = Instant::now();
+    for _ in 0..repeat {
+        ntt_mul(&a_mont, &b_mont, table_mont);
+    }
+    println!(
+        "Ring12289_512 mul with Montgomery: {:?}",
+        start.elapsed() / repeat
+    );
+
+    let c_mont = ntt_mul(&a_mont, &b_mont, table_mont);
+    let c_rem = ntt_mul(&a_rem, &b_rem, table_rem);
+    for ((x, y), z) in c.coeffs.iter().zip(c_mont.iter()).zip(c_rem.iter()) {
+        assert_eq!(*x, F12289::from(*y));
+        assert_eq!(u64::from(*x), u64::from(*z));
+    }
+}
@@ -0,0 +1,149 @@
+use core::iter::Product;
+use std::ops::{Mul, MulAssign};
+use std::sync::OnceLock;
+
+use crate::{
+    ConfigZZVec3329_256, ConfigZZp3329, ConfigZZpX3329_256, Field, NTTTable, Poly3329_256,
+    Polynomial, PolynomialRing, F3329,
+};
+
+/// Ring over ZZ_q/(x^256+1) with q = 3329, as used in Kyber/ML-KEM.
+///
+/// 3329 has no 512-th root of unity, so the NTT of this ring is incomplete and
+/// products in the NTT domain are not coefficient-wise. The ring therefore does not
+/// implement [`ConfigNTTRing`](crate::ConfigNTTRing): it cannot be used in a
+/// [`ModuleVector`](crate::ModuleVector), nor with the NTT domain of
+/// [`DenseMatrix`](crate::DenseMatrix). Matrices of ring elements in the coefficient
+/// domain, and the gadget decomposition of single elements, work as for any ring.
+pub type Ring3329_256 = Poly3329_256;
+/// Configuration for ring over ZZ_q/(x^256+1)
+pub type ConfigRing3329_256 = ConfigZZpX3329_256;
+
+/// 17 is a primitive 256-th root of unity mod 3329.
+/// There is no 512-th root of unity, so the NTT stops after 7 layers,
+/// leaving 128 polynomials of degree 1.
+const ZETA: u64 = 17;
+
+/// Number of points of the incomplete NTT
+const NUM_POINTS: usize = 128;
+
+impl Ring3329_256 {
+    /// The cached table for the 7-layer incomplete NTT
+    fn ntt_table() -> &'static NTTTable<ConfigZZp3329> {
+        static TABLE: OnceLock<NTTTable<ConfigZZp3329>> = OnceLock::new();
+        TABLE.get_or_init(|| NTTTable::new(NUM_POINTS, F3329::from(ZETA)))
+    }
+
+    /// Multiply two polynomials in the NTT domain (`MultiplyNTTs` in FIPS 203).
+    ///
+    /// The i-th pair of coefficients is a polynomial modulo `x^2 - gamma_i`
+    /// with `gamma_i = zeta^{2 brv(i) + 1}`.
+    fn base_case_mul(a: &mut [F3329], b: &[F3329]) {
+        let zeta = F3329::from(ZETA);
+        let table = Self::ntt_table().forward_table();
+        for (i, (x, y)) in a.chunks_exact_mut(2).zip(b.chunks_exact(2)).enumerate() {
+            // gamma_i = zeta * (zeta^{brv(i)})^2
+            let gamma = zeta * table[i].square();
+            let c0 = x[0] * y[0] + x[1] * y[1] * gamma;
+            let c1 = x[0] * y[1] + x[1] * y[0];
+            x[0] = c0;
+            x[1] = c1;
+        }
+    }
+}
+
+// ========================
+// multiplications
+// ========================
+impl Mul for Ring3329_256 {
+    type Output = Self;
+
+    fn mul(self, rhs: Self) -> Self::Output {
+        self.mul(&rhs)
+    }
+}
+
+impl<'b> Mul<&'b Ring3329_256> for Ring3329_256 {
+    type Output = Ring3329_256;
+
+    #[inline]
+    fn mul(self, rhs: &'b Ring3329_256) -> Ring3329_256 {
+        let mut res = self;
+        res.mul_assign(rhs);
+        res
+    }
+}
+
+impl MulAssign for Ring3329_256 {
+    #[inline]
+    fn mul_assign(&mut self, rhs: Ring3329_256) {
+        self.mul_assign(&rhs)
+    }
+}
+
+impl<'b> MulAssign<&'b Ring3329_256> for Ring3329_256 {
+    #[inline]
+    fn mul_assign(&mut self, rhs: &'b Ring3329_256) {
+        let table = Self::ntt_table();
+        let mut b = rhs.coeffs.clone();
+        table.forward(&mut self.coeffs);
+        table.forward(&mut b);
+        Self::base_case_mul(&mut self.coeffs, &b);
+        table.inverse(&mut self.coeffs);
+    }
+}
+
+impl<T> Product<T> for Ring3329_256
+where
+    T: core::borrow::Borrow<Self>,
+{
+    fn product<I: Iterator<Item = T>>(iter: I) -> Self {
+        iter.fold(Self::one(), |acc, item| acc * item.borrow())
+    }
+}
+
+impl PolynomialRing<ConfigRing3329_256, ConfigZZVec3329_256> for Ring3329_256 {}
+
+#[cfg(test)]
+impl Ring3329_256 {
+    /// school book multiplication
+    /// output = a(x) * b(x) mod x^N +1 mod MODULUS
+    /// using school-book multiplications
+    pub fn schoolbook_mul(a: &Self, b: &Self) -> Self {
+        use crate::ConfigZZp;
+        use crate::ConfigZZpX;
+
+        let a = &a.coeffs;
+        let b = &b.coeffs;
+        let modulus = <ConfigRing3329_256 as ConfigZZpX>::BaseConfig::MODULUS;
+        const N: usize = <ConfigRing3329_256 as ConfigZZpX>::DIM;
+
+        let mut buf = [0u32; N << 1];
+        let mut c = [0; N];
+        for i in 0..N {
+            for j in 0..N {
+                buf[i + j] += (a[i].0 as u32 * b[j].0 as u32) % modulus as u32;
+            }
+        }
+
+        for i in 0..N {
+            c[i] =
+                ((buf[i] + modulus as u32 - (buf[i + N] % modulus as u32)) % modulus as u32) as u16;
+        }
+        Self::from_primitive_types(&c)
+    }
+}
+
+#[test]
+fn test_ring_mul() {
+    use crate::tests::test_rng;
+
+    let mut rng = test_rng();
+    for _ in 0..10 {
+        let a = Ring3329_256::random(&mut rng, None);
+        let b = Ring3329_256::random(&mut rng, None);
+        let c = Ring3329_256::schoolbook_mul(&a, &b);
+        let d = a * b;
+        assert_eq!(c, d)
+    }
+}
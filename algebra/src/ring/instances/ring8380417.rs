@@ -0,0 +1,108 @@
+use core::iter::Product;
+use std::ops::{Mul, MulAssign};
+
+use crate::{
+    ConfigNTTRing, ConfigZZVec8380417_256, ConfigZZpX8380417_256, NTTDomain, Poly8380417_256,
+    Polynomial, PolynomialRing, ZZVec,
+};
+
+/// Ring over ZZ_q/(x^256+1) with q = 8380417, as used in Dilithium/ML-DSA
+pub type Ring8380417_256 = Poly8380417_256;
+/// Configuration for ring over ZZ_q/(x^256+1)
+pub type ConfigRing8380417_256 = ConfigZZpX8380417_256;
+
+// ========================
+// multiplications
+// ========================
+impl Mul for Ring8380417_256 {
+    type Output = Self;
+
+    fn mul(self, rhs: Self) -> Self::Output {
+        self.mul(&rhs)
+    }
+}
+
+impl<'b> Mul<&'b Ring8380417_256> for Ring8380417_256 {
+    type Output = Ring8380417_256;
+
+    #[inline]
+    fn mul(self, rhs: &'b Ring8380417_256) -> Ring8380417_256 {
+        let mut res = self;
+        res.mul_assign(rhs);
+        res
+    }
+}
+
+impl MulAssign for Ring8380417_256 {
+    #[inline]
+    fn mul_assign(&mut self, rhs: Ring8380417_256) {
+        self.mul_assign(&rhs)
+    }
+}
+
+impl<'b> MulAssign<&'b Ring8380417_256> for Ring8380417_256 {
+    #[inline]
+    fn mul_assign(&mut self, rhs: &'b Ring8380417_256) {
+        let a: ZZVec<ConfigZZVec8380417_256> = NTTDomain::forward_ntt(self);
+        let b: ZZVec<ConfigZZVec8380417_256> = NTTDomain::forward_ntt(rhs);
+        let c = a * b;
+        *self = c.reverse_ntt();
+    }
+}
+
+impl<T> Product<T> for Ring8380417_256
+where
+    T: core::borrow::Borrow<Self>,
+{
+    fn product<I: Iterator<Item = T>>(iter: I) -> Self {
+        iter.fold(Self::one(), |acc, item| acc * item.borrow())
+    }
+}
+
+impl PolynomialRing<ConfigRing8380417_256, ConfigZZVec8380417_256> for Ring8380417_256 {}
+
+impl ConfigNTTRing for ConfigRing8380417_256 {
+    type NTTConfig = ConfigZZVec8380417_256;
+}
+
+#[cfg(test)]
+impl Ring8380417_256 {
+    /// school book multiplication
+    /// output = a(x) * b(x) mod x^N +1 mod MODULUS
+    /// using school-book multiplications
+    pub fn schoolbook_mul(a: &Self, b: &Self) -> Self {
+        use crate::ConfigZZp;
+        use crate::ConfigZZpX;
+
+        let a = &a.coeffs;
+        let b = &b.coeffs;
+        let modulus = <ConfigRing8380417_256 as ConfigZZpX>::BaseConfig::MODULUS;
+        const N: usize = <ConfigRing8380417_256 as ConfigZZpX>::DIM;
+
+        let mut buf = [0u64; N << 1];
+        let mut c = [0; N];
+        for i in 0..N {
+            for j in 0..N {
+                buf[i + j] += (a[i].0 as u64 * b[j].0 as u64) % modulus as u64;
+            }
+        }
+
+        for i in 0..N {
+            c[i] =
+                ((buf[i] + modulus as u64 - (buf[i + N] % modulus as u64)) % modulus as u64) as u32;
+        }
+        Self::from_primitive_types(&c)
+    }
+}
+
+#[test]
+fn test_ring_mul() {
+    use crate::tests::test_rng;
+
+    let mut rng = test_rng();
+    let a = Ring8380417_256::random(&mut rng, None);
+    let b = Ring8380417_256::random(&mut rng, None);
+    let c = Ring8380417_256::schoolbook_mul(&a, &b);
+    let d = a * b;
+    assert_eq!(c, d)
+}
@@ -2,8 +2,8 @@ use core::iter::Product;
 use std::ops::{Mul, MulAssign};
 
 use crate::{
-    ConfigZZVecGoldilocks256, ConfigZZpXGoldilocks256, NTTDomain, PolyGoldilock256, Polynomial,
-    PolynomialRing, ZZVec,
+    ConfigNTTRing, ConfigZZVecGoldilocks256, ConfigZZpXGoldilocks256, NTTDomain, PolyGoldilock256,
+    Polynomial, PolynomialRing, ZZVec,
 };
 
 /// Ring over ZZ_q/(x^512+1)
@@ -45,10 +45,7 @@ impl<'b> MulAssign<&'b RingGoldilock256> for RingGoldilock256 {
     fn mul_assign(&mut self, rhs: &'b RingGoldilock256) {
         let a: ZZVec<ConfigZZVecGoldilocks256> = NTTDomain::forward_ntt(self);
         let b: ZZVec<ConfigZZVecGoldilocks256> = NTTDomain::forward_ntt(rhs);
-        println!("a: {}", a);
-        println!("b: {}", b);
         let c = a * b;
-        println!("c: {}", c);
         *self = c.reverse_ntt();
     }
 }
@@ -64,6 +61,10 @@ where
 
 impl PolynomialRing<ConfigRingGoldilocks256, ConfigZZpXGoldilocks256> for RingGoldilock256 {}
 
+impl ConfigNTTRing for ConfigRingGoldilocks256 {
+    type NTTConfig = ConfigZZVecGoldilocks256;
+}
+
 #[cfg(test)]
 impl RingGoldilock256 {
     /// school book multiplication
@@ -96,13 +97,9 @@ impl RingGoldilock256 {
 
 #[test]
 fn test_ring_mul() {
-    use rand::SeedableRng;
-    use rand_xorshift::XorShiftRng;
+    use crate::tests::test_rng;
 
-    let mut rng = XorShiftRng::from_seed([
-        0x59, 0x62, 0xbe, 0x5d, 0x76, 0x3d, 0x31, 0x8d, 0x17, 0xdb, 0x37, 0x32, 0x54, 0x06, 0xbc,
-        0xe5,
-    ]);
+    let mut rng = test_rng();
     let a = RingGoldilock256::random(&mut rng, None);
     let b = RingGoldilock256::random(&mut rng, None);
     let c = RingGoldilock256::schoolbook_mul(&a, &b);
@@ -0,0 +1,19 @@
+//! Samplers of non-uniform distributions.
+
+use crate::field::value_barrier;
+use crate::{ConfigZZp, PrimeField, ZZp};
+
+/// Centered binomial sampling
+mod cbd;
+/// Discrete Gaussian sampling
+mod gaussian;
+
+pub use gaussian::{CdtSampler, DiscreteGaussian, KarneySampler, CDT_MAX_SIGMA};
+
+/// Reduce an integer `x` with `|x| < MODULUS` into a field element, in constant time.
+pub(crate) fn small_to_field<C: ConfigZZp>(x: i64) -> ZZp<C> {
+    debug_assert!(x.unsigned_abs() < ZZp::<C>::modulus());
+    // add the modulus to negative integers without branching
+    let mask = value_barrier((x >> 63) as u64);
+    (x as u64).wrapping_add(ZZp::<C>::modulus() & mask).into()
+}
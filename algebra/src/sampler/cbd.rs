@@ -0,0 +1,132 @@
+//! Centered binomial distribution, following `SamplePolyCBD` of FIPS 203 (Algorithm 8).
+//!
+//! A coefficient is `x - y` where `x` and `y` are sums of `eta` bits, read from a
+//! PRF output as a little-endian bit string. Each coefficient consumes `2 eta` bits,
+//! so that a polynomial of dimension `n` takes `eta * n / 4` bytes.
+
+use super::small_to_field;
+use crate::{ConfigZZVec, ConfigZZp, ConfigZZpX, ZZVec, ZZp, ZZpX};
+
+/// Number of bytes to sample `n` coefficients with parameter `eta`
+fn cbd_len(eta: usize, n: usize) -> usize {
+    (2 * eta * n).div_ceil(8)
+}
+
+/// Sample `n` coefficients from the centered binomial distribution with parameter
+/// `eta`, in constant time.
+fn sample_cbd<C: ConfigZZp>(eta: usize, bytes: &[u8], n: usize) -> Vec<ZZp<C>> {
+    assert!(eta > 0, "eta must be positive");
+    assert_eq!(
+        bytes.len(),
+        cbd_len(eta, n),
+        "CBD with eta = {} takes {} bytes",
+        eta,
+        cbd_len(eta, n)
+    );
+
+    let bit = |k: usize| ((bytes[k / 8] >> (k % 8)) & 1) as i64;
+    (0..n)
+        .map(|i| {
+            let x: i64 = (0..eta).map(|j| bit(2 * i * eta + j)).sum();
+            let y: i64 = (0..eta).map(|j| bit(2 * i * eta + eta + j)).sum();
+            small_to_field(x - y)
+        })
+        .collect()
+}
+
+impl<C: ConfigZZpX> ZZpX<C> {
+    /// `SamplePolyCBD_eta`: sample a polynomial from the centered binomial distribution
+    /// with parameter `eta`, stored modulo `q`.
+    ///
+    /// The bytes, e.g., the output of `PRF_eta`, must be `eta * DIM / 4` bytes long.
+    pub fn random_cbd(eta: usize, bytes: &[u8]) -> Self {
+        Self {
+            coeffs: sample_cbd(eta, bytes, C::DIM),
+        }
+    }
+}
+
+impl<C: ConfigZZVec> ZZVec<C> {
+    /// Sample a vector from the centered binomial distribution with parameter `eta`,
+    /// stored modulo `q`.
+    ///
+    /// The bytes, e.g., the output of a PRF, must be `eta * MAX_DIM / 4` bytes long.
+    pub fn random_cbd(eta: usize, bytes: &[u8]) -> Self {
+        Self {
+            coeffs: sample_cbd(eta, bytes, C::MAX_DIM),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rand::RngCore;
+
+    use crate::tests::test_rng;
+    use crate::{Poly3329_256, Polynomial, PrimeField, Vec8380417_256, Vector};
+
+    /// SamplePolyCBD_eta from FIPS 203, Algorithm 8
+    fn sample_poly_cbd_reference(bytes: &[u8], eta: usize) -> Vec<u64> {
+        // BytesToBits, Algorithm 4
+        let mut b = vec![];
+        for byte in bytes {
+            let mut c = *byte;
+            for _ in 0..8 {
+                b.push((c % 2) as u64);
+                c /= 2;
+            }
+        }
+        (0..256)
+            .map(|i| {
+                let x: u64 = (0..eta).map(|j| b[2 * i * eta + j]).sum();
+                let y: u64 = (0..eta).map(|j| b[2 * i * eta + eta + j]).sum();
+                (x + 3329 - y) % 3329
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_sample_poly_cbd() {
+        let mut rng = test_rng();
+        for eta in [2, 3] {
+            for _ in 0..100 {
+                let mut bytes = vec![0u8; 64 * eta];
+                rng.fill_bytes(&mut bytes);
+                let poly = Poly3329_256::random_cbd(eta, &bytes);
+                let coeffs: Vec<u64> = poly.coefficients().map(|x| u64::from(*x)).collect();
+                assert_eq!(coeffs, sample_poly_cbd_reference(&bytes, eta));
+                assert!(poly.infinity_norm() <= eta as u64);
+            }
+        }
+
+        // with eta = 2, the byte 0x03 gives the coefficients 2 and 0,
+        // and the byte 0x0c gives -2 and 0
+        let poly = Poly3329_256::random_cbd(2, &[0x03; 128]);
+        assert!(poly.coefficients().step_by(2).all(|x| x.lift() == 2));
+        assert!(poly
+            .coefficients()
+            .skip(1)
+            .step_by(2)
+            .all(|x| x.lift() == 0));
+        let poly = Poly3329_256::random_cbd(2, &[0x0c; 128]);
+        assert!(poly.coefficients().step_by(2).all(|x| x.lift() == -2));
+        assert_eq!(
+            Poly3329_256::random_cbd(3, &[0xff; 192]),
+            Poly3329_256::zero()
+        );
+
+        // the coefficients follow Binomial(2 eta, 1/2) - eta, of variance eta / 2
+        let eta = 4;
+        let mut bytes = vec![0u8; 256 * eta / 4];
+        rng.fill_bytes(&mut bytes);
+        let vec = Vec8380417_256::random_cbd(eta, &bytes);
+        assert!(vec.infinity_norm() <= eta as u64);
+        assert!((384..640).contains(&vec.l2_norm_square()));
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_sample_poly_cbd_length() {
+        Poly3329_256::random_cbd(2, &[0; 127]);
+    }
+}
@@ -0,0 +1,481 @@
+//! Discrete Gaussian sampling over the integers.
+//!
+//! The discrete Gaussian `D_{Z, sigma, c}` outputs an integer `x` with probability
+//! proportional to `exp(-(x - c)^2 / (2 sigma^2))`. Two samplers are provided:
+//! - [`CdtSampler`], a constant time cumulative distribution table sampler for
+//!   small `sigma`, e.g., the secrets and errors of LWE-based schemes;
+//! - [`KarneySampler`], Karney's rejection sampler for arbitrary `sigma`, e.g.,
+//!   the preimages of trapdoor sampling. Its running time depends on the output.
+//!
+//! [`DiscreteGaussian`] picks one of them depending on `sigma`.
+
+use rand::RngCore;
+
+use super::small_to_field;
+use crate::field::{random_below, value_barrier};
+use crate::{ConfigZZVec, ConfigZZp, ConfigZZpX, PrimeField, ZZVec, ZZp, ZZpX};
+
+/// Number of standard deviations kept on each side of the center by the CDT sampler.
+/// The probability of the tail is below `2^-100`.
+const TAIL_CUT: f64 = 12.0;
+
+/// Largest `sigma` handled by the CDT sampler; the table has about `24 sigma` entries.
+pub const CDT_MAX_SIGMA: f64 = 32.0;
+
+/// Number of bits of the probabilities in the CDT
+const CDT_PRECISION: u32 = 63;
+
+/// Check that `sigma` and `center` are valid parameters
+fn check_parameters(sigma: f64, center: f64) {
+    assert!(
+        sigma.is_finite() && sigma > 0.0,
+        "sigma = {} is not a positive number",
+        sigma
+    );
+    assert!(center.is_finite(), "center = {} is not finite", center);
+}
+
+// ========================
+// CDT sampler
+// ========================
+
+/// Constant time sampler for `D_{Z, sigma, c}` with `sigma <= CDT_MAX_SIGMA`.
+///
+/// The table holds the cumulative distribution over `[c - 12 sigma, c + 12 sigma]`
+/// with 63 bits of precision. A sample draws a 63-bit integer and counts the table
+/// entries below it, always scanning the whole table.
+#[derive(Debug, Clone, PartialEq)]
+pub struct CdtSampler {
+    sigma: f64,
+    center: f64,
+    /// Smallest integer of the support
+    min: i64,
+    /// `table[i] = 2^63 * Pr[x <= min + i]`, for all but the largest integer of the support
+    table: Vec<u64>,
+}
+
+impl CdtSampler {
+    /// Build the table for `D_{Z, sigma, center}`.
+    pub fn new(sigma: f64, center: f64) -> Self {
+        check_parameters(sigma, center);
+        assert!(
+            sigma <= CDT_MAX_SIGMA,
+            "sigma = {} is too large for a CDT sampler",
+            sigma
+        );
+
+        let min = (center - TAIL_CUT * sigma).floor() as i64;
+        let max = (center + TAIL_CUT * sigma).ceil() as i64;
+        let weights: Vec<f64> = (min..=max)
+            .map(|x| {
+                let d = x as f64 - center;
+                (-d * d / (2.0 * sigma * sigma)).exp()
+            })
+            .collect();
+        let total: f64 = weights.iter().sum();
+
+        // Pr[x <= min + i] is close to 1 on the right side, where it has fewer bits of
+        // precision than the probability of the tail; it is computed from the tail there.
+        let scale = (1u64 << CDT_PRECISION) as f64;
+        let mut tails = vec![0.0; weights.len()];
+        for i in (0..weights.len() - 1).rev() {
+            tails[i] = tails[i + 1] + weights[i + 1];
+        }
+        let mut cumulative = 0.0;
+        let table = weights[..weights.len() - 1]
+            .iter()
+            .zip(tails.iter())
+            .map(|(w, tail)| {
+                cumulative += w;
+                if cumulative <= *tail {
+                    (cumulative / total * scale).round() as u64
+                } else {
+                    (1u64 << CDT_PRECISION) - (tail / total * scale).round() as u64
+                }
+            })
+            .collect();
+
+        Self {
+            sigma,
+            center,
+            min,
+            table,
+        }
+    }
+
+    /// Standard deviation of the distribution
+    pub fn sigma(&self) -> f64 {
+        self.sigma
+    }
+
+    /// Center of the distribution
+    pub fn center(&self) -> f64 {
+        self.center
+    }
+
+    /// Draw a sample, in constant time.
+    pub fn sample(&self, mut rng: impl RngCore) -> i64 {
+        self.sample_from_bits(rng.next_u64() >> (u64::BITS - CDT_PRECISION))
+    }
+
+    /// Map a uniform 63-bit integer to a sample.
+    fn sample_from_bits(&self, r: u64) -> i64 {
+        // table[i] <= r iff table[i] - (r + 1) underflows, as both are at most 2^63
+        let count: u64 = self
+            .table
+            .iter()
+            .map(|&t| value_barrier(t.wrapping_sub(r + 1) >> 63))
+            .sum();
+        self.min + count as i64
+    }
+}
+
+// ========================
+// Karney's sampler
+// ========================
+
+/// Sampler for `D_{Z, sigma, c}` with arbitrary `sigma`, following Algorithm D of
+/// Karney, "Sampling exactly from the normal distribution"
+/// <https://arxiv.org/abs/1303.6257>.
+///
+/// The Bernoulli trials use double precision floating point numbers instead of
+/// the exact arithmetic of the paper. The sampler is not constant time.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct KarneySampler {
+    sigma: f64,
+    center: f64,
+}
+
+impl KarneySampler {
+    /// Sampler for `D_{Z, sigma, center}`.
+    pub fn new(sigma: f64, center: f64) -> Self {
+        check_parameters(sigma, center);
+        Self { sigma, center }
+    }
+
+    /// Standard deviation of the distribution
+    pub fn sigma(&self) -> f64 {
+        self.sigma
+    }
+
+    /// Center of the distribution
+    pub fn center(&self) -> f64 {
+        self.center
+    }
+
+    /// Draw a sample.
+    pub fn sample(&self, mut rng: impl RngCore) -> i64 {
+        let width = self.sigma.ceil() as u64;
+        loop {
+            let k = sample_half_normal_index(&mut rng);
+            let sign = if rng.next_u32() & 1 == 0 { 1.0 } else { -1.0 };
+
+            // the sample is sign * (i0 + j), at (x + k) sigma from the center
+            let shifted = k as f64 * self.sigma + sign * self.center;
+            let i0 = shifted.ceil();
+            let x0 = (i0 - shifted) / self.sigma;
+            let j = random_below(&mut rng, width);
+            let x = x0 + j as f64 / self.sigma;
+            if x >= 1.0 || (x == 0.0 && k == 0 && sign < 0.0) {
+                continue;
+            }
+            if bernoulli(&mut rng, -x * (2.0 * k as f64 + x) / 2.0) {
+                return (sign * (i0 + j as f64)) as i64;
+            }
+        }
+    }
+}
+
+/// Returns true with probability `exp(t)`, for `t <= 0`.
+fn bernoulli(mut rng: impl RngCore, t: f64) -> bool {
+    // a uniform double in [0, 1)
+    let u = (rng.next_u64() >> 11) as f64 / (1u64 << 53) as f64;
+    u < t.exp()
+}
+
+/// Returns `k >= 0` with probability proportional to `exp(-k^2 / 2)`:
+/// `k` is drawn with probability proportional to `exp(-k / 2)`,
+/// and accepted with probability `exp(-k (k - 1) / 2)`.
+fn sample_half_normal_index(mut rng: impl RngCore) -> u64 {
+    loop {
+        let mut k = 0u64;
+        while bernoulli(&mut rng, -0.5) {
+            k += 1;
+        }
+        if bernoulli(&mut rng, -((k * k.saturating_sub(1)) as f64) / 2.0) {
+            return k;
+        }
+    }
+}
+
+// ========================
+// discrete Gaussian
+// ========================
+
+/// Discrete Gaussian distribution `D_{Z, sigma, c}`, backed by a [`CdtSampler`]
+/// for `sigma <= CDT_MAX_SIGMA` and by a [`KarneySampler`] otherwise.
+#[derive(Debug, Clone, PartialEq)]
+pub enum DiscreteGaussian {
+    /// Constant time sampler for small sigma
+    Cdt(CdtSampler),
+    /// Sampler for large sigma
+    Karney(KarneySampler),
+}
+
+impl DiscreteGaussian {
+    /// Discrete Gaussian with standard deviation `sigma` centered at `center`.
+    pub fn new(sigma: f64, center: f64) -> Self {
+        if sigma <= CDT_MAX_SIGMA {
+            Self::Cdt(CdtSampler::new(sigma, center))
+        } else {
+            Self::Karney(KarneySampler::new(sigma, center))
+        }
+    }
+
+    /// Standard deviation of the distribution
+    pub fn sigma(&self) -> f64 {
+        match self {
+            Self::Cdt(sampler) => sampler.sigma(),
+            Self::Karney(sampler) => sampler.sigma(),
+        }
+    }
+
+    /// Center of the distribution
+    pub fn center(&self) -> f64 {
+        match self {
+            Self::Cdt(sampler) => sampler.center(),
+            Self::Karney(sampler) => sampler.center(),
+        }
+    }
+
+    /// Draw a sample.
+    pub fn sample(&self, rng: impl RngCore) -> i64 {
+        match self {
+            Self::Cdt(sampler) => sampler.sample(rng),
+            Self::Karney(sampler) => sampler.sample(rng),
+        }
+    }
+
+    /// Draw a sample, reduced modulo the field modulus.
+    pub fn sample_field_element<C: ConfigZZp>(&self, rng: impl RngCore) -> ZZp<C> {
+        let x = self.sample(rng);
+        if x.unsigned_abs() < ZZp::<C>::modulus() {
+            small_to_field(x)
+        } else {
+            ZZp::from_lift(x)
+        }
+    }
+}
+
+impl<C: ConfigZZpX> ZZpX<C> {
+    /// Sample a polynomial with coefficients drawn independently from `gaussian`,
+    /// stored modulo `q`.
+    pub fn random_gaussian(mut rng: impl RngCore, gaussian: &DiscreteGaussian) -> Self {
+        let coeffs = (0..C::DIM)
+            .map(|_| gaussian.sample_field_element(&mut rng))
+            .collect();
+        Self { coeffs }
+    }
+}
+
+impl<C: ConfigZZVec> ZZVec<C> {
+    /// Sample a vector with coefficients drawn independently from `gaussian`,
+    /// stored modulo `q`.
+    pub fn random_gaussian(mut rng: impl RngCore, gaussian: &DiscreteGaussian) -> Self {
+        let coeffs = (0..C::MAX_DIM)
+            .map(|_| gaussian.sample_field_element(&mut rng))
+            .collect();
+        Self { coeffs }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rand::RngCore;
+    use rand_xorshift::XorShiftRng;
+
+    use super::{CdtSampler, DiscreteGaussian, KarneySampler};
+    use crate::tests::test_rng;
+    use crate::tests::timing::assert_constant_time;
+    use crate::{Poly3329_256, Polynomial, PrimeField, Vec8380417_256, VecGoldilocks256, Vector};
+
+    const NUM_SAMPLES: usize = 100000;
+
+    /// Probabilities of `D_{Z, sigma, center}` gathered into buckets: one per integer
+    /// in `[min, max]`, with the tails merged into the first and last buckets
+    fn bucket_probabilities(sigma: f64, center: f64, min: i64, max: i64) -> Vec<f64> {
+        let rho = |x: i64| {
+            let d = x as f64 - center;
+            (-d * d / (2.0 * sigma * sigma)).exp()
+        };
+        let range = (25.0 * sigma) as i64 + 1;
+        let c = center.round() as i64;
+        let total: f64 = (c - range..=c + range).map(rho).sum();
+        let mut res = vec![0f64; (max - min + 1) as usize];
+        for x in c - range..=c + range {
+            res[(x.clamp(min, max) - min) as usize] += rho(x) / total;
+        }
+        res
+    }
+
+    /// Chi-square test against `D_{Z, sigma, center}`, with a bucket per integer
+    /// within `2.5 sigma` of the center
+    fn chi_square_test(samples: &[i64], sigma: f64, center: f64) {
+        let min = (center - 2.5 * sigma).floor() as i64;
+        let max = (center + 2.5 * sigma).ceil() as i64;
+        let expected = bucket_probabilities(sigma, center, min, max);
+        let mut counts = vec![0f64; expected.len()];
+        for &x in samples {
+            counts[(x.clamp(min, max) - min) as usize] += 1.0;
+        }
+        let n = samples.len() as f64;
+        let chi_square: f64 = counts
+            .iter()
+            .zip(expected.iter())
+            .map(|(c, p)| (c - n * p) * (c - n * p) / (n * p))
+            .sum();
+
+        // 99.9% quantile of chi-square, with the Wilson-Hilferty approximation
+        let df = (counts.len() - 1) as f64;
+        let h = 2.0 / (9.0 * df);
+        let quantile = df * (1.0 - h + 3.09 * h.sqrt()).powi(3);
+        assert!(
+            chi_square < quantile,
+            "sigma {}, center {}: chi square {} >= {}",
+            sigma,
+            center,
+            chi_square,
+            quantile
+        );
+    }
+
+    /// Kolmogorov-Smirnov test against `D_{Z, sigma, center}`
+    fn ks_test(samples: &[i64], sigma: f64, center: f64) {
+        let mut sorted = samples.to_vec();
+        sorted.sort_unstable();
+        let min = sorted[0];
+        let max = sorted[sorted.len() - 1];
+        let n = sorted.len() as f64;
+
+        // the first bucket holds the mass of the left tail, which is the CDF at min
+        let mut cdf = 0.0;
+        let mut index = 0;
+        let mut statistic: f64 = 0.0;
+        for (x, p) in (min..=max).zip(bucket_probabilities(sigma, center, min, max)) {
+            cdf += p;
+            while index < sorted.len() && sorted[index] <= x {
+                index += 1;
+            }
+            statistic = statistic.max((index as f64 / n - cdf).abs());
+        }
+        // 99.9% quantile of the Kolmogorov distribution
+        assert!(
+            statistic * n.sqrt() < 1.95,
+            "sigma {}, center {}: KS statistic {}",
+            sigma,
+            center,
+            statistic
+        );
+    }
+
+    #[test]
+    fn test_cdt() {
+        let mut rng = test_rng();
+        for (sigma, center) in [(1.0, 0.0), (3.19, 0.0), (2.0, 0.5), (17.3, -4.25)] {
+            let sampler = CdtSampler::new(sigma, center);
+            let samples: Vec<i64> = (0..NUM_SAMPLES).map(|_| sampler.sample(&mut rng)).collect();
+            assert!(samples
+                .iter()
+                .all(|&x| (x as f64 - center).abs() <= 12.0 * sigma + 1.0));
+            chi_square_test(&samples, sigma, center);
+            ks_test(&samples, sigma, center);
+        }
+
+        // extreme inputs: the table has 63 bits of precision, so that the support
+        // is effectively cut at about 9 sigma, on both sides
+        let sampler = CdtSampler::new(3.19, 0.0);
+        assert_eq!(sampler.sample_from_bits(0), -29);
+        assert_eq!(sampler.sample_from_bits((1 << 63) - 1), 29);
+        assert_eq!(sampler.sample_from_bits(1 << 62), 0);
+    }
+
+    #[test]
+    #[cfg_attr(
+        not(feature = "ct-tests"),
+        ignore = "timing test, needs the ct-tests feature"
+    )]
+    fn test_cdt_constant_time() {
+        let mut rng = test_rng();
+        let sampler = CdtSampler::new(3.19, 0.0);
+        // the median versus uniform inputs
+        assert_constant_time(
+            "cdt sampler",
+            &mut rng,
+            |rng: &mut XorShiftRng, class| {
+                if class {
+                    rng.next_u64() >> 1
+                } else {
+                    1 << 62
+                }
+            },
+            |r| sampler.sample_from_bits(r),
+        );
+    }
+
+    #[test]
+    fn test_karney() {
+        let mut rng = test_rng();
+        for (sigma, center) in [(0.8, 0.0), (2.0, 0.5), (33.3, 10.3)] {
+            let sampler = KarneySampler::new(sigma, center);
+            let samples: Vec<i64> = (0..NUM_SAMPLES).map(|_| sampler.sample(&mut rng)).collect();
+            chi_square_test(&samples, sigma, center);
+            ks_test(&samples, sigma, center);
+        }
+        // too many buckets for a chi-square test
+        let sampler = KarneySampler::new(1e4, -123.45);
+        let samples: Vec<i64> = (0..NUM_SAMPLES).map(|_| sampler.sample(&mut rng)).collect();
+        ks_test(&samples, 1e4, -123.45);
+
+        // large sigma
+        let sampler = KarneySampler::new(1e12, 0.0);
+        let samples: Vec<f64> = (0..10000)
+            .map(|_| sampler.sample(&mut rng) as f64)
+            .collect();
+        let mean = samples.iter().sum::<f64>() / 10000.0;
+        let std = (samples.iter().map(|x| x * x).sum::<f64>() / 10000.0).sqrt();
+        assert!(mean.abs() < 5e10, "mean {}", mean);
+        assert!((std / 1e12 - 1.0).abs() < 0.05, "std {}", std);
+    }
+
+    #[test]
+    fn test_random_gaussian() {
+        let mut rng = test_rng();
+
+        let gaussian = DiscreteGaussian::new(3.19, 0.0);
+        assert!(matches!(gaussian, DiscreteGaussian::Cdt(_)));
+        let poly = Poly3329_256::random_gaussian(&mut rng, &gaussian);
+        assert!(poly.infinity_norm() <= 39);
+        let samples: Vec<i64> = (0..NUM_SAMPLES / 256)
+            .flat_map(|_| {
+                Poly3329_256::random_gaussian(&mut rng, &gaussian)
+                    .coefficients()
+                    .map(|x| x.lift())
+                    .collect::<Vec<_>>()
+            })
+            .collect();
+        chi_square_test(&samples, 3.19, 0.0);
+
+        let vec = Vec8380417_256::random_gaussian(&mut rng, &gaussian);
+        assert!(vec.infinity_norm() <= 39);
+
+        // samples larger than the modulus are reduced
+        let gaussian = DiscreteGaussian::new(1e6, 0.0);
+        assert!(matches!(gaussian, DiscreteGaussian::Karney(_)));
+        let poly = Poly3329_256::random_gaussian(&mut rng, &gaussian);
+        assert!(poly.coefficients().all(|x| u64::from(*x) < 3329));
+        let vec = VecGoldilocks256::random_gaussian(&mut rng, &gaussian);
+        let samples: Vec<i64> = vec.coefficients().map(|x| x.lift()).collect();
+        assert!(samples.iter().any(|&x| x < -100000));
+        assert!(samples.iter().any(|&x| x > 100000));
+    }
+}
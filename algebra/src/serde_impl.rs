@@ -0,0 +1,318 @@
+//! Serde support, behind the `serde` feature.
+//!
+//! The encoding depends on the format:
+//! - human-readable formats (e.g., JSON) get the canonical values in `[0, MODULUS)`
+//!   as decimal integers: a number for a field element, an array of numbers for a
+//!   polynomial or a vector;
+//! - binary formats (e.g., bincode) get the compressed [`CanonicalSerialize`]
+//!   encoding, as a fixed-size tuple of bytes without a length prefix.
+//!
+//! Deserialization always validates the data: values must be less than `MODULUS`,
+//! and polynomials and vectors must have the dimension of their config.
+
+use std::fmt::Formatter;
+
+use serde::de::{Error, SeqAccess, Visitor};
+use serde::ser::SerializeTuple;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+use crate::serialize::coeffs_serialized_size;
+use crate::{
+    CanonicalDeserialize, CanonicalSerialize, Compress, ConfigZZVec, ConfigZZp, ConfigZZpX,
+    PrimeField, Validate, ZZVec, ZZp, ZZpX,
+};
+
+// ========================
+// binary formats
+// ========================
+
+/// Serialize the compressed canonical encoding as a tuple of bytes
+fn serialize_canonical<T: CanonicalSerialize, S: Serializer>(
+    value: &T,
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    let mut bytes = Vec::with_capacity(value.compressed_size());
+    value
+        .serialize_compressed(&mut bytes)
+        .map_err(serde::ser::Error::custom)?;
+    let mut tuple = serializer.serialize_tuple(bytes.len())?;
+    for byte in bytes.iter() {
+        tuple.serialize_element(byte)?;
+    }
+    tuple.end()
+}
+
+/// Visitor of a tuple of `len` bytes
+struct BytesVisitor {
+    len: usize,
+}
+
+impl<'de> Visitor<'de> for BytesVisitor {
+    type Value = Vec<u8>;
+
+    fn expecting(&self, formatter: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(formatter, "{} bytes", self.len)
+    }
+
+    fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<Self::Value, A::Error> {
+        let mut bytes = Vec::with_capacity(self.len);
+        for i in 0..self.len {
+            match seq.next_element()? {
+                Some(byte) => bytes.push(byte),
+                None => return Err(A::Error::invalid_length(i, &self)),
+            }
+        }
+        if seq.next_element::<u8>()?.is_some() {
+            return Err(A::Error::invalid_length(self.len + 1, &self));
+        }
+        Ok(bytes)
+    }
+}
+
+/// Deserialize and validate a compressed canonical encoding of `len` bytes
+fn deserialize_canonical<'de, T: CanonicalDeserialize, D: Deserializer<'de>>(
+    len: usize,
+    deserializer: D,
+) -> Result<T, D::Error> {
+    let bytes = deserializer.deserialize_tuple(len, BytesVisitor { len })?;
+    T::deserialize_compressed(&bytes[..]).map_err(D::Error::custom)
+}
+
+// ========================
+// human-readable formats
+// ========================
+
+/// Validate a canonical value
+fn field_element<C: ConfigZZp, E: Error>(value: u64) -> Result<ZZp<C>, E> {
+    ZZp::from_u64_with_validation(value, Validate::Yes).map_err(|_| {
+        E::custom(format!(
+            "{} is not less than the modulus {}",
+            value,
+            ZZp::<C>::modulus()
+        ))
+    })
+}
+
+/// Serialize the canonical values of the coefficients as an array
+fn serialize_values<C: ConfigZZp, S: Serializer>(
+    coeffs: &[ZZp<C>],
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    serializer.collect_seq(coeffs.iter().map(|x| u64::from(*x)))
+}
+
+/// Deserialize and validate an array of `n` canonical values
+fn deserialize_values<'de, C: ConfigZZp, D: Deserializer<'de>>(
+    n: usize,
+    deserializer: D,
+) -> Result<Vec<ZZp<C>>, D::Error> {
+    let values = Vec::<u64>::deserialize(deserializer)?;
+    if values.len() != n {
+        return Err(D::Error::custom(format!(
+            "expected {} coefficients, got {}",
+            n,
+            values.len()
+        )));
+    }
+    values.into_iter().map(field_element).collect()
+}
+
+// ========================
+// instances
+// ========================
+
+impl<C: ConfigZZp> Serialize for ZZp<C> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        if serializer.is_human_readable() {
+            serializer.serialize_u64(u64::from(*self))
+        } else {
+            serialize_canonical(self, serializer)
+        }
+    }
+}
+
+impl<'de, C: ConfigZZp> Deserialize<'de> for ZZp<C> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        if deserializer.is_human_readable() {
+            field_element(u64::deserialize(deserializer)?)
+        } else {
+            deserialize_canonical(Self::encoded_len(), deserializer)
+        }
+    }
+}
+
+impl<C: ConfigZZpX> Serialize for ZZpX<C> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        if serializer.is_human_readable() {
+            serialize_values(&self.coeffs, serializer)
+        } else {
+            serialize_canonical(self, serializer)
+        }
+    }
+}
+
+impl<'de, C: ConfigZZpX> Deserialize<'de> for ZZpX<C> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        if deserializer.is_human_readable() {
+            let coeffs = deserialize_values(C::DIM, deserializer)?;
+            Ok(Self { coeffs })
+        } else {
+            let len = coeffs_serialized_size::<C::BaseConfig>(C::DIM, Compress::Yes);
+            deserialize_canonical(len, deserializer)
+        }
+    }
+}
+
+/// As with [`CanonicalSerialize`], only vectors of `MAX_DIM` coefficients are encoded
+impl<C: ConfigZZVec> Serialize for ZZVec<C> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        if self.coeffs.len() != C::MAX_DIM {
+            return Err(serde::ser::Error::custom(format!(
+                "expected {} coefficients, got {}",
+                C::MAX_DIM,
+                self.coeffs.len()
+            )));
+        }
+        if serializer.is_human_readable() {
+            serialize_values(&self.coeffs, serializer)
+        } else {
+            serialize_canonical(self, serializer)
+        }
+    }
+}
+
+impl<'de, C: ConfigZZVec> Deserialize<'de> for ZZVec<C> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        if deserializer.is_human_readable() {
+            let coeffs = deserialize_values(C::MAX_DIM, deserializer)?;
+            Ok(Self { coeffs })
+        } else {
+            let len = coeffs_serialized_size::<C::BaseConfig>(C::MAX_DIM, Compress::Yes);
+            deserialize_canonical(len, deserializer)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::tests::test_rng;
+    use crate::{
+        F3329Montgomery, Field, Goldilocks, Poly3329_256, Polynomial, Ring12289_512,
+        Vec8380417_256, Vector, F3329,
+    };
+
+    #[test]
+    fn test_human_readable() {
+        let mut rng = test_rng();
+
+        // field elements are decimal integers
+        assert_eq!(serde_json::to_string(&F3329::from(17u64)).unwrap(), "17");
+        assert_eq!(
+            serde_json::to_string(&F3329Montgomery::from(3328u64)).unwrap(),
+            "3328"
+        );
+        let x = -Goldilocks::one();
+        assert_eq!(serde_json::to_string(&x).unwrap(), "18446744069414584320");
+        assert_eq!(
+            serde_json::from_str::<Goldilocks>("18446744069414584320").unwrap(),
+            x
+        );
+        assert!(serde_json::from_str::<Goldilocks>("18446744069414584321").is_err());
+        assert_eq!(
+            serde_json::from_str::<F3329>("3328").unwrap(),
+            -F3329::from(1u64)
+        );
+        assert!(serde_json::from_str::<F3329>("3329").is_err());
+        assert!(serde_json::from_str::<F3329Montgomery>("4000").is_err());
+
+        // polynomials and vectors are arrays of decimal integers
+        let poly = Poly3329_256::random(&mut rng, None);
+        let json = serde_json::to_string(&poly).unwrap();
+        let values: Vec<u64> = serde_json::from_str(&json).unwrap();
+        assert_eq!(
+            values,
+            poly.coefficients()
+                .map(|x| u64::from(*x))
+                .collect::<Vec<_>>()
+        );
+        assert_eq!(serde_json::from_str::<Poly3329_256>(&json).unwrap(), poly);
+
+        let ring = Ring12289_512::random(&mut rng, None);
+        let json = serde_json::to_string(&ring).unwrap();
+        assert_eq!(serde_json::from_str::<Ring12289_512>(&json).unwrap(), ring);
+
+        let vec = Vec8380417_256::random(&mut rng, None);
+        let json = serde_json::to_string(&vec).unwrap();
+        assert_eq!(serde_json::from_str::<Vec8380417_256>(&json).unwrap(), vec);
+
+        // a vector shorter than MAX_DIM could not be read back
+        let short = Vec8380417_256::from_coefficients_vec_unchecked(vec.coeffs[..3].to_vec());
+        assert!(serde_json::to_string(&short).is_err());
+
+        // wrong dimension
+        assert!(serde_json::from_str::<Poly3329_256>(
+            &serde_json::to_string(&vec![0u64; 255]).unwrap()
+        )
+        .is_err());
+        assert!(serde_json::from_str::<Poly3329_256>(
+            &serde_json::to_string(&vec![0u64; 257]).unwrap()
+        )
+        .is_err());
+        // out of range coefficient
+        let mut values = vec![0u64; 256];
+        values[100] = 3329;
+        assert!(
+            serde_json::from_str::<Poly3329_256>(&serde_json::to_string(&values).unwrap()).is_err()
+        );
+    }
+
+    #[test]
+    fn test_binary() {
+        let mut rng = test_rng();
+
+        // field elements use the bytes of the modulus, without a length prefix
+        let bytes = bincode::serialize(&F3329::from(0x123u64)).unwrap();
+        assert_eq!(bytes, vec![0x23, 0x01]);
+        assert_eq!(
+            bincode::deserialize::<F3329>(&bytes).unwrap(),
+            F3329::from(0x123u64)
+        );
+        assert!(bincode::deserialize::<F3329>(&[0x01, 0x0d]).is_err());
+        assert!(bincode::deserialize::<F3329>(&[0x01]).is_err());
+        let x = Goldilocks::random(&mut rng);
+        let bytes = bincode::serialize(&x).unwrap();
+        assert_eq!(bytes.len(), 8);
+        assert_eq!(bincode::deserialize::<Goldilocks>(&bytes).unwrap(), x);
+
+        // polynomials and vectors use the compressed canonical encoding
+        let poly = Poly3329_256::random(&mut rng, None);
+        let bytes = bincode::serialize(&poly).unwrap();
+        assert_eq!(bytes.len(), 384);
+        assert_eq!(bytes, poly.byte_encode(12));
+        assert_eq!(bincode::deserialize::<Poly3329_256>(&bytes).unwrap(), poly);
+        assert!(bincode::deserialize::<Poly3329_256>(&bytes[1..]).is_err());
+        // 0xfff is out of range
+        assert!(bincode::deserialize::<Poly3329_256>(&[0xff; 384]).is_err());
+
+        let ring = Ring12289_512::random(&mut rng, None);
+        let bytes = bincode::serialize(&ring).unwrap();
+        assert_eq!(bytes.len(), 512 * 14 / 8);
+        assert_eq!(bincode::deserialize::<Ring12289_512>(&bytes).unwrap(), ring);
+
+        let vec = Vec8380417_256::random(&mut rng, None);
+        let bytes = bincode::serialize(&vec).unwrap();
+        assert_eq!(bytes.len(), 256 * 23 / 8);
+        assert_eq!(bincode::deserialize::<Vec8380417_256>(&bytes).unwrap(), vec);
+        let short = Vec8380417_256::from_coefficients_vec_unchecked(vec.coeffs[..3].to_vec());
+        assert!(bincode::serialize(&short).is_err());
+
+        // containers
+        let polys = vec![poly.clone(), poly];
+        let bytes = bincode::serialize(&polys).unwrap();
+        assert_eq!(bytes.len(), 8 + 2 * 384);
+        assert_eq!(
+            bincode::deserialize::<Vec<Poly3329_256>>(&bytes).unwrap(),
+            polys
+        );
+    }
+}
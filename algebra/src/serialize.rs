@@ -0,0 +1,384 @@
+//! Canonical serialization APIs.
+//!
+//! All types use a fixed little-endian layout. Field elements are written as their
+//! canonical value in `[0, MODULUS)` with as many bytes as the modulus takes.
+//! Polynomials and vectors have a dimension fixed by their config, so they are
+//! written without a length prefix:
+//! - uncompressed: the coefficients one after the other, each as a field element;
+//! - compressed: the coefficients packed into a little-endian bit string, each with
+//!   as many bits as the modulus takes.
+//!
+//! Deserialization with [`Validate::Yes`] rejects out-of-range coefficients and
+//! non-zero padding bits, so that every object has a single valid encoding.
+
+use std::fmt::Display;
+use std::io::{Read, Write};
+
+use crate::{ConfigZZp, PrimeField, ZZp};
+
+/// Whether to use the compressed encoding
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum Compress {
+    /// Compressed encoding
+    Yes,
+    /// Uncompressed encoding
+    No,
+}
+
+/// Whether to validate the deserialized data
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum Validate {
+    /// Validate the data
+    Yes,
+    /// Skip the validation
+    No,
+}
+
+/// Errors of (de)serialization
+#[derive(Debug)]
+pub enum SerializationError {
+    /// The data is not a valid encoding, e.g., a coefficient is out of range
+    InvalidData,
+    /// An IO error
+    IoError(std::io::Error),
+}
+
+impl Display for SerializationError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::InvalidData => write!(f, "invalid data"),
+            Self::IoError(e) => write!(f, "io error: {}", e),
+        }
+    }
+}
+
+impl std::error::Error for SerializationError {}
+
+impl From<std::io::Error> for SerializationError {
+    fn from(e: std::io::Error) -> Self {
+        Self::IoError(e)
+    }
+}
+
+/// Serialize an object into bytes, in a canonical form
+pub trait CanonicalSerialize {
+    /// Serialize self into the writer
+    fn serialize_with_mode<W: Write>(
+        &self,
+        writer: W,
+        compress: Compress,
+    ) -> Result<(), SerializationError>;
+
+    /// Number of bytes of the serialization
+    fn serialized_size(&self, compress: Compress) -> usize;
+
+    /// Serialize self into the writer, in the compressed form
+    fn serialize_compressed<W: Write>(&self, writer: W) -> Result<(), SerializationError> {
+        self.serialize_with_mode(writer, Compress::Yes)
+    }
+
+    /// Number of bytes of the compressed serialization
+    fn compressed_size(&self) -> usize {
+        self.serialized_size(Compress::Yes)
+    }
+
+    /// Serialize self into the writer, in the uncompressed form
+    fn serialize_uncompressed<W: Write>(&self, writer: W) -> Result<(), SerializationError> {
+        self.serialize_with_mode(writer, Compress::No)
+    }
+
+    /// Number of bytes of the uncompressed serialization
+    fn uncompressed_size(&self) -> usize {
+        self.serialized_size(Compress::No)
+    }
+}
+
+/// Deserialize an object from bytes produced by [`CanonicalSerialize`]
+pub trait CanonicalDeserialize: Sized {
+    /// Deserialize an object from the reader
+    fn deserialize_with_mode<R: Read>(
+        reader: R,
+        compress: Compress,
+        validate: Validate,
+    ) -> Result<Self, SerializationError>;
+
+    /// Deserialize an object in the compressed form, and validate it
+    fn deserialize_compressed<R: Read>(reader: R) -> Result<Self, SerializationError> {
+        Self::deserialize_with_mode(reader, Compress::Yes, Validate::Yes)
+    }
+
+    /// Deserialize an object in the compressed form, without validation
+    fn deserialize_compressed_unchecked<R: Read>(reader: R) -> Result<Self, SerializationError> {
+        Self::deserialize_with_mode(reader, Compress::Yes, Validate::No)
+    }
+
+    /// Deserialize an object in the uncompressed form, and validate it
+    fn deserialize_uncompressed<R: Read>(reader: R) -> Result<Self, SerializationError> {
+        Self::deserialize_with_mode(reader, Compress::No, Validate::Yes)
+    }
+
+    /// Deserialize an object in the uncompressed form, without validation
+    fn deserialize_uncompressed_unchecked<R: Read>(reader: R) -> Result<Self, SerializationError> {
+        Self::deserialize_with_mode(reader, Compress::No, Validate::No)
+    }
+}
+
+// ========================
+// bit packing
+// ========================
+
+/// Number of bytes to pack `n` values of `bits` bits
+pub(crate) fn packed_len(n: usize, bits: u32) -> usize {
+    (n * bits as usize).div_ceil(8)
+}
+
+/// Pack values of `bits <= 64` bits each into a little-endian bit string.
+/// The higher bits of the values are ignored.
+pub(crate) fn write_bits<W: Write>(
+    mut writer: W,
+    values: impl IntoIterator<Item = u64>,
+    bits: u32,
+) -> Result<(), SerializationError> {
+    let mask = u64::MAX >> (64 - bits);
+    let mut bytes = vec![];
+    let mut buffer = 0u128;
+    let mut num_bits = 0;
+    for value in values {
+        buffer |= ((value & mask) as u128) << num_bits;
+        num_bits += bits;
+        while num_bits >= 8 {
+            bytes.push(buffer as u8);
+            buffer >>= 8;
+            num_bits -= 8;
+        }
+    }
+    if num_bits > 0 {
+        bytes.push(buffer as u8);
+    }
+    writer.write_all(&bytes)?;
+    Ok(())
+}
+
+/// Unpack `n` values of `bits <= 64` bits each from a little-endian bit string.
+/// With [`Validate::Yes`], the padding bits must be zero.
+pub(crate) fn read_bits<R: Read>(
+    mut reader: R,
+    n: usize,
+    bits: u32,
+    validate: Validate,
+) -> Result<Vec<u64>, SerializationError> {
+    let mut bytes = vec![0u8; packed_len(n, bits)];
+    reader.read_exact(&mut bytes)?;
+
+    let mask = u64::MAX >> (64 - bits);
+    let mut values = Vec::with_capacity(n);
+    let mut bytes_iter = bytes.into_iter();
+    let mut buffer = 0u128;
+    let mut num_bits = 0;
+    for _ in 0..n {
+        while num_bits < bits {
+            // cannot fail as there are enough bytes for n values
+            buffer |= (bytes_iter.next().unwrap() as u128) << num_bits;
+            num_bits += 8;
+        }
+        values.push(buffer as u64 & mask);
+        buffer >>= bits;
+        num_bits -= bits;
+    }
+    if validate == Validate::Yes && buffer != 0 {
+        return Err(SerializationError::InvalidData);
+    }
+    Ok(values)
+}
+
+// ========================
+// coefficients
+// ========================
+
+/// Number of bits of a coefficient in the compressed form
+fn coeff_bits<C: ConfigZZp>() -> u32 {
+    u64::BITS - ZZp::<C>::modulus().leading_zeros()
+}
+
+/// Number of bytes of `n` serialized coefficients
+pub(crate) fn coeffs_serialized_size<C: ConfigZZp>(n: usize, compress: Compress) -> usize {
+    match compress {
+        Compress::Yes => packed_len(n, coeff_bits::<C>()),
+        Compress::No => n * ZZp::<C>::encoded_len(),
+    }
+}
+
+/// Serialize a list of coefficients, without a length prefix
+pub(crate) fn serialize_coeffs<C: ConfigZZp, W: Write>(
+    coeffs: &[ZZp<C>],
+    mut writer: W,
+    compress: Compress,
+) -> Result<(), SerializationError> {
+    match compress {
+        Compress::Yes => write_bits(
+            writer,
+            coeffs.iter().map(|x| u64::from(*x)),
+            coeff_bits::<C>(),
+        ),
+        Compress::No => {
+            for coeff in coeffs {
+                coeff.serialize_with_mode(&mut writer, compress)?;
+            }
+            Ok(())
+        }
+    }
+}
+
+/// Deserialize a list of `n` coefficients
+pub(crate) fn deserialize_coeffs<C: ConfigZZp, R: Read>(
+    mut reader: R,
+    n: usize,
+    compress: Compress,
+    validate: Validate,
+) -> Result<Vec<ZZp<C>>, SerializationError> {
+    match compress {
+        Compress::Yes => read_bits(reader, n, coeff_bits::<C>(), validate)?
+            .into_iter()
+            .map(|x| ZZp::from_u64_with_validation(x, validate))
+            .collect(),
+        Compress::No => (0..n)
+            .map(|_| ZZp::deserialize_with_mode(&mut reader, compress, validate))
+            .collect(),
+    }
+}
+
+// ========================
+// containers
+// ========================
+impl<T: CanonicalSerialize, const N: usize> CanonicalSerialize for [T; N] {
+    fn serialize_with_mode<W: Write>(
+        &self,
+        mut writer: W,
+        compress: Compress,
+    ) -> Result<(), SerializationError> {
+        for item in self.iter() {
+            item.serialize_with_mode(&mut writer, compress)?;
+        }
+        Ok(())
+    }
+
+    fn serialized_size(&self, compress: Compress) -> usize {
+        self.iter().map(|x| x.serialized_size(compress)).sum()
+    }
+}
+
+impl<T: CanonicalDeserialize, const N: usize> CanonicalDeserialize for [T; N] {
+    fn deserialize_with_mode<R: Read>(
+        mut reader: R,
+        compress: Compress,
+        validate: Validate,
+    ) -> Result<Self, SerializationError> {
+        let items = (0..N)
+            .map(|_| T::deserialize_with_mode(&mut reader, compress, validate))
+            .collect::<Result<Vec<_>, _>>()?;
+        // cannot fail as there are exactly N items
+        Ok(items.try_into().ok().unwrap())
+    }
+}
+
+/// A vector is prefixed with its length, as a u64 in little-endian
+impl<T: CanonicalSerialize> CanonicalSerialize for Vec<T> {
+    fn serialize_with_mode<W: Write>(
+        &self,
+        mut writer: W,
+        compress: Compress,
+    ) -> Result<(), SerializationError> {
+        writer.write_all(&(self.len() as u64).to_le_bytes())?;
+        for item in self.iter() {
+            item.serialize_with_mode(&mut writer, compress)?;
+        }
+        Ok(())
+    }
+
+    fn serialized_size(&self, compress: Compress) -> usize {
+        8 + self
+            .iter()
+            .map(|x| x.serialized_size(compress))
+            .sum::<usize>()
+    }
+}
+
+impl<T: CanonicalDeserialize> CanonicalDeserialize for Vec<T> {
+    fn deserialize_with_mode<R: Read>(
+        mut reader: R,
+        compress: Compress,
+        validate: Validate,
+    ) -> Result<Self, SerializationError> {
+        let mut len = [0u8; 8];
+        reader.read_exact(&mut len)?;
+        let len = u64::from_le_bytes(len);
+        // do not trust the length for the allocation
+        let mut items = vec![];
+        for _ in 0..len {
+            items.push(T::deserialize_with_mode(&mut reader, compress, validate)?);
+        }
+        Ok(items)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        read_bits, write_bits, CanonicalDeserialize, CanonicalSerialize, Compress,
+        SerializationError, Validate,
+    };
+    use crate::{Vec3329_256, Vector, F3329};
+
+    #[test]
+    fn test_bit_packing() {
+        // FIPS 203 ByteEncode_12 of [0xabc, 0x123] is [0xbc, 0x3a, 0x12]
+        let mut bytes = vec![];
+        write_bits(&mut bytes, [0xabc, 0x123], 12).unwrap();
+        assert_eq!(bytes, vec![0xbc, 0x3a, 0x12]);
+        assert_eq!(
+            read_bits(&bytes[..], 2, 12, Validate::Yes).unwrap(),
+            vec![0xabc, 0x123]
+        );
+
+        // non-zero padding
+        let mut bytes = vec![];
+        write_bits(&mut bytes, [0x7f], 7).unwrap();
+        assert_eq!(bytes, vec![0x7f]);
+        assert!(matches!(
+            read_bits(&[0xff][..], 1, 7, Validate::Yes),
+            Err(SerializationError::InvalidData)
+        ));
+        assert_eq!(
+            read_bits(&[0xff][..], 1, 7, Validate::No).unwrap(),
+            vec![0x7f]
+        );
+
+        // not enough bytes
+        assert!(matches!(
+            read_bits(&[0xff][..], 2, 7, Validate::No),
+            Err(SerializationError::IoError(_))
+        ));
+    }
+
+    #[test]
+    fn test_short_vector() {
+        // a vector is encoded as MAX_DIM coefficients, so a shorter one cannot be
+        // read back and is rejected
+        let a = Vec3329_256::from_coefficients_vec_unchecked(vec![F3329::from(1); 3]);
+        let mut b = a.clone();
+        b.coeffs.resize(256, F3329::from(0));
+        for compress in [Compress::Yes, Compress::No] {
+            let mut bytes = vec![];
+            assert!(matches!(
+                a.serialize_with_mode(&mut bytes, compress),
+                Err(SerializationError::InvalidData)
+            ));
+            assert!(bytes.is_empty());
+
+            b.serialize_with_mode(&mut bytes, compress).unwrap();
+            assert_eq!(b.serialized_size(compress), bytes.len());
+            let c = Vec3329_256::deserialize_with_mode(&bytes[..], compress, Validate::Yes);
+            assert_eq!(c.unwrap(), b);
+        }
+    }
+}
@@ -1,14 +1,13 @@
 use ark_std::{end_timer, start_timer};
-use rand::{RngCore, SeedableRng};
+use rand::RngCore;
 use rand_xorshift::XorShiftRng;
 
-use crate::Field;
+use super::test_rng;
+use super::timing::assert_constant_time;
+use crate::{Field, NTTField, PrimeField};
 
 pub(crate) fn random_field_tests<F: Field>(type_name: String) {
-    let mut rng = XorShiftRng::from_seed([
-        0x59, 0x62, 0xbe, 0x5d, 0x76, 0x3d, 0x31, 0x8d, 0x17, 0xdb, 0x37, 0x32, 0x54, 0x06, 0xbc,
-        0xe5,
-    ]);
+    let mut rng = test_rng();
 
     random_multiplication_tests::<F, _>(&mut rng, type_name.clone());
     random_addition_tests::<F, _>(&mut rng, type_name.clone());
@@ -18,6 +17,7 @@ pub(crate) fn random_field_tests<F: Field>(type_name: String) {
     random_squaring_tests::<F, _>(&mut rng, type_name.clone());
     random_inversion_tests::<F, _>(&mut rng, type_name.clone());
     random_expansion_tests::<F, _>(&mut rng, type_name.clone());
+    random_sqrt_tests::<F, _>(&mut rng, type_name.clone());
     random_conversion_test::<F, _>(&mut rng, type_name);
 
     assert_eq!(F::zero().is_zero().unwrap_u8(), 1);
@@ -214,6 +214,46 @@ fn random_expansion_tests<F: Field, R: RngCore>(mut rng: R, type_name: String) {
     end_timer!(start);
 }
 
+fn random_sqrt_tests<F: Field, R: RngCore>(mut rng: R, type_name: String) {
+    assert_eq!(F::zero().sqrt().unwrap(), F::zero());
+    assert_eq!(F::one().sqrt().unwrap().square(), F::one());
+
+    let _message = format!("sqrt {}", type_name);
+    let start = start_timer!(|| _message);
+    let mut num_squares = 0;
+    for _ in 0..10000 {
+        // a^2 always has a square root
+        let a = F::random(&mut rng);
+        let b = a.square();
+        let root = b.sqrt().unwrap();
+        assert_eq!(root.square(), b);
+
+        // sqrt(num/div)
+        let num = F::random(&mut rng);
+        let div = F::random(&mut rng);
+        let (is_square, root) = F::sqrt_ratio(&num, &div);
+        if bool::from(is_square) {
+            num_squares += 1;
+            assert_eq!(root.square() * div, num);
+        } else if div != F::zero() {
+            assert!(bool::from((num * div.invert().unwrap()).sqrt().is_none()));
+        }
+
+        // edge cases
+        let (is_square, root) = F::sqrt_ratio(&F::zero(), &div);
+        assert!(bool::from(is_square));
+        assert_eq!(root, F::zero());
+        if num != F::zero() {
+            let (is_square, root) = F::sqrt_ratio(&num, &F::zero());
+            assert!(!bool::from(is_square));
+            assert_eq!(root, F::zero());
+        }
+    }
+    // roughly half of the elements are quadratic residues
+    assert!(num_squares > 4500 && num_squares < 5500);
+    end_timer!(start);
+}
+
 fn random_conversion_test<F: Field, R: RngCore>(mut rng: R, type_name: String) {
     let _message = format!("conversion {}", type_name);
     let start = start_timer!(|| _message);
@@ -225,3 +265,87 @@ fn random_conversion_test<F: Field, R: RngCore>(mut rng: R, type_name: String) {
     }
     end_timer!(start);
 }
+
+pub(crate) fn random_ntt_field_tests<F: NTTField>(type_name: String) {
+    let mut rng = test_rng();
+
+    random_lift_tests::<F, _>(&mut rng, type_name.clone());
+    root_of_unity_tests::<F>(type_name);
+}
+
+fn random_lift_tests<F: PrimeField, R: RngCore>(mut rng: R, type_name: String) {
+    let _message = format!("lift {}", type_name);
+    let start = start_timer!(|| _message);
+    let half = (F::modulus() / 2) as i64;
+
+    assert_eq!(F::zero().lift(), 0);
+    assert_eq!(F::one().lift(), 1);
+    assert_eq!((-F::one()).lift(), -1);
+    assert_eq!(F::from_lift(half).lift(), half);
+    assert_eq!(F::from_lift(-half).lift(), -half);
+
+    for _ in 0..100000 {
+        let a = F::random(&mut rng);
+        let lifted = a.lift();
+        assert!(-half <= lifted && lifted <= half);
+        assert_eq!(F::from_lift(lifted), a);
+        assert_eq!((-a).lift(), -lifted);
+        assert_eq!(a.normalize(), a);
+    }
+    end_timer!(start);
+}
+
+fn root_of_unity_tests<F: NTTField>(type_name: String) {
+    let _message = format!("root of unity {}", type_name);
+    let start = start_timer!(|| _message);
+    let order = F::modulus() - 1;
+
+    // the generator is a non-residue of order MODULUS - 1
+    assert_eq!(F::GENERATOR.pow([order]), F::one());
+    assert_eq!(F::GENERATOR.pow([order / 2]), -F::one());
+    assert!(bool::from(F::GENERATOR.sqrt().is_none()));
+
+    assert!(F::get_root_of_unity(0).is_none());
+    assert!(F::get_root_of_unity(1u64 << (F::TWO_ADICITY + 1)).is_none());
+    assert_eq!(F::get_root_of_unity(1), Some(F::one()));
+
+    for log_n in 1..=F::TWO_ADICITY {
+        let n = 1u64 << log_n;
+        let root = F::get_root_of_unity(n).unwrap();
+        assert_eq!(root.pow([n]), F::one());
+        assert_eq!(root.pow([n / 2]), -F::one());
+    }
+
+    for log_n in 0..F::TWO_ADICITY {
+        let n = 1u64 << log_n;
+        let psi = F::get_primitive_2nth_root_of_unity(n).unwrap();
+        assert_eq!(psi.pow([n]), -F::one());
+    }
+    end_timer!(start);
+}
+
+/// dudect-style timing tests: the arithmetic on the fixed input zero must not be
+/// distinguishable from the arithmetic on uniformly random inputs.
+///
+/// These are slow and fail on a loaded machine, so the tests calling them only run
+/// with the `ct-tests` feature.
+pub(crate) fn constant_time_tests<F: Field>(type_name: String) {
+    let mut rng = test_rng();
+    let sample = |rng: &mut XorShiftRng, class: bool| {
+        if class {
+            (F::random(&mut *rng), F::random(&mut *rng))
+        } else {
+            (F::zero(), F::zero())
+        }
+    };
+
+    let name = |op: &str| format!("{} {}", op, type_name);
+    assert_constant_time(&name("addition"), &mut rng, sample, |(a, b)| a + b);
+    assert_constant_time(&name("subtraction"), &mut rng, sample, |(a, b)| a - b);
+    assert_constant_time(&name("multiplication"), &mut rng, sample, |(a, b)| a * b);
+    assert_constant_time(&name("negation"), &mut rng, sample, |(a, _)| -a);
+    assert_constant_time(&name("inversion"), &mut rng, sample, |(a, _)| a.invert());
+    assert_constant_time(&name("selection"), &mut rng, sample, |(a, b)| {
+        F::conditional_select(&a, &b, a.is_zero())
+    });
+}
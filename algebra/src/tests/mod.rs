@@ -1 +1,40 @@
 pub(crate) mod field;
+pub(crate) mod serialize;
+pub(crate) mod timing;
+
+use num::BigInt;
+use rand::SeedableRng;
+use rand_xorshift::XorShiftRng;
+
+use crate::{ConfigZZp12289, ConfigZZpX, DenseMatrix, Matrix};
+
+/// The deterministic rng shared by the unit tests.
+pub(crate) fn test_rng() -> XorShiftRng {
+    XorShiftRng::from_seed([
+        0x59, 0x62, 0xbe, 0x5d, 0x76, 0x3d, 0x31, 0x8d, 0x17, 0xdb, 0x37, 0x32, 0x54, 0x06, 0xbc,
+        0xe5,
+    ])
+}
+
+/// An integer matrix from its rows.
+pub(crate) fn int_matrix(rows: &[&[i64]]) -> DenseMatrix<BigInt> {
+    DenseMatrix::from_row_vectors(
+        rows.iter()
+            .map(|row| row.iter().map(|&x| BigInt::from(x)).collect())
+            .collect(),
+    )
+}
+
+/// An integer vector.
+pub(crate) fn int_vec(v: &[i64]) -> Vec<BigInt> {
+    v.iter().map(|&x| BigInt::from(x)).collect()
+}
+
+/// A small ring, for exact lattice computations
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub(crate) struct ConfigZZpX12289_8;
+
+impl ConfigZZpX for ConfigZZpX12289_8 {
+    type BaseConfig = ConfigZZp12289;
+    const DIM: usize = 8;
+}
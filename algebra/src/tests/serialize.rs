@@ -0,0 +1,57 @@
+use std::fmt::Debug;
+
+use rand_xorshift::XorShiftRng;
+
+use super::test_rng;
+use crate::{CanonicalDeserialize, CanonicalSerialize, Compress, SerializationError, Validate};
+
+/// Round trip tests for both encodings, and validation of out of range coefficients.
+/// The first coefficient of `T`, or the element itself for a field, must come first
+/// in the encoding.
+pub(crate) fn serialization_tests<T, S>(type_name: String, modulus: u64, mut sample: S)
+where
+    T: CanonicalSerialize + CanonicalDeserialize + PartialEq + Debug,
+    S: FnMut(&mut XorShiftRng) -> T,
+{
+    let mut rng = test_rng();
+
+    for _ in 0..100 {
+        let a = sample(&mut rng);
+        for compress in [Compress::Yes, Compress::No] {
+            let mut bytes = vec![];
+            a.serialize_with_mode(&mut bytes, compress).unwrap();
+            assert_eq!(bytes.len(), a.serialized_size(compress), "{}", type_name);
+
+            for validate in [Validate::Yes, Validate::No] {
+                let b = T::deserialize_with_mode(&bytes[..], compress, validate).unwrap();
+                assert_eq!(a, b, "{}", type_name);
+            }
+
+            let truncated = &bytes[..bytes.len() - 1];
+            assert!(matches!(
+                T::deserialize_with_mode(truncated, compress, Validate::Yes),
+                Err(SerializationError::IoError(_))
+            ));
+        }
+    }
+
+    // set the first coefficient to 2^k - 1 >= modulus, where k is the bit length of the modulus
+    let bits = u64::BITS - modulus.leading_zeros();
+    let a = sample(&mut rng);
+    for compress in [Compress::Yes, Compress::No] {
+        let mut bytes = vec![];
+        a.serialize_with_mode(&mut bytes, compress).unwrap();
+        for i in 0..bits as usize {
+            bytes[i / 8] |= 1 << (i % 8);
+        }
+        assert!(
+            matches!(
+                T::deserialize_with_mode(&bytes[..], compress, Validate::Yes),
+                Err(SerializationError::InvalidData)
+            ),
+            "{}",
+            type_name
+        );
+        assert!(T::deserialize_with_mode(&bytes[..], compress, Validate::No).is_ok());
+    }
+}
@@ -0,0 +1,119 @@
+//! A dudect-style timing leakage test, following
+//! "Dude, is my code constant time?" <https://eprint.iacr.org/2016/1123.pdf>.
+//!
+//! The inputs are split into two classes, typically a fixed input and uniformly
+//! random inputs, which are interleaved at random. The execution times of the two
+//! classes are compared with Welch's t-test; a large |t| means that the execution
+//! time depends on the input.
+
+use std::hint::black_box;
+use std::time::Instant;
+
+use rand::RngCore;
+
+/// Number of measurements per test
+const NUM_MEASUREMENTS: usize = 10000;
+
+/// Number of operations timed in a single measurement, to amortize the timer resolution
+const BATCH_SIZE: usize = 32;
+
+/// Measurements above this percentile are discarded, as they are dominated
+/// by interrupts and context switches
+const CROP_PERCENTILE: f64 = 0.9;
+
+/// Threshold on |t| above which the timing leakage is considered definite,
+/// as in dudect
+pub(crate) const T_THRESHOLD: f64 = 10.0;
+
+/// Welch's t statistic of two samples
+fn welch_t(a: &[f64], b: &[f64]) -> f64 {
+    let mean_var = |x: &[f64]| {
+        let n = x.len() as f64;
+        let mean = x.iter().sum::<f64>() / n;
+        let var = x.iter().map(|v| (v - mean) * (v - mean)).sum::<f64>() / (n - 1.0);
+        (mean, var, n)
+    };
+    let (mean_a, var_a, n_a) = mean_var(a);
+    let (mean_b, var_b, n_b) = mean_var(b);
+    let denominator = (var_a / n_a + var_b / n_b).sqrt();
+    if denominator == 0.0 {
+        return 0.0;
+    }
+    (mean_a - mean_b) / denominator
+}
+
+/// Measure |t| between the execution times of `op` on inputs of the two classes.
+/// `sample(rng, class)` returns an input of the given class.
+pub(crate) fn timing_leakage<I, O, R, S, F>(rng: &mut R, mut sample: S, op: F) -> f64
+where
+    I: Copy,
+    R: RngCore,
+    S: FnMut(&mut R, bool) -> I,
+    F: Fn(I) -> O,
+{
+    // prepare all inputs beforehand, so that the sampling does not interfere
+    // with the measurements
+    let classes: Vec<bool> = (0..NUM_MEASUREMENTS)
+        .map(|_| rng.next_u32() & 1 == 1)
+        .collect();
+    let inputs: Vec<Vec<I>> = classes
+        .iter()
+        .map(|&class| (0..BATCH_SIZE).map(|_| sample(rng, class)).collect())
+        .collect();
+
+    let mut times = [vec![], vec![]];
+    for (&class, batch) in classes.iter().zip(inputs.iter()) {
+        let start = Instant::now();
+        for input in batch.iter() {
+            black_box(op(black_box(*input)));
+        }
+        times[class as usize].push(start.elapsed().as_nanos() as f64);
+    }
+
+    let mut sorted: Vec<f64> = times.iter().flatten().copied().collect();
+    sorted.sort_by(|a, b| a.partial_cmp(b).unwrap());
+    let cutoff = sorted[(sorted.len() as f64 * CROP_PERCENTILE) as usize];
+    let [fixed, random] = times.map(|t| t.into_iter().filter(|&x| x <= cutoff).collect::<Vec<_>>());
+
+    welch_t(&fixed, &random).abs()
+}
+
+/// Assert that no timing leakage is detected for `op`.
+pub(crate) fn assert_constant_time<I, O, R, S, F>(name: &str, rng: &mut R, sample: S, op: F)
+where
+    I: Copy,
+    R: RngCore,
+    S: FnMut(&mut R, bool) -> I,
+    F: Fn(I) -> O,
+{
+    let t = timing_leakage(rng, sample, op);
+    assert!(
+        t < T_THRESHOLD,
+        "{}: timing leakage detected, |t| = {:.2}",
+        name,
+        t
+    );
+}
+
+#[test]
+#[cfg_attr(
+    not(feature = "ct-tests"),
+    ignore = "timing test, needs the ct-tests feature"
+)]
+fn test_timing_harness() {
+    use rand_xorshift::XorShiftRng;
+
+    use super::test_rng;
+    use crate::{Field, F12289};
+
+    let mut rng = test_rng();
+    let base = F12289::from(11);
+    // fixed exponent 1 versus random exponents
+    let sample = |rng: &mut XorShiftRng, class: bool| if class { rng.next_u64() } else { 1 };
+
+    // `pow_vartime` branches on the exponent and must be caught
+    let t = timing_leakage(&mut rng, sample, |e| base.pow_vartime([e]));
+    assert!(t > T_THRESHOLD, "pow_vartime: |t| = {:.2}", t);
+
+    assert_constant_time("pow", &mut rng, sample, |e| base.pow([e]));
+}
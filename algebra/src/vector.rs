@@ -4,8 +4,8 @@ mod zz_vec;
 
 pub use definition::{ConfigZZVec, Vector};
 pub use instances::{
-    ConfigZZVec12289_512, ConfigZZVec3329_256, ConfigZZVecGoldilocks256, Vec12289_512, Vec3329_256,
-    VecGoldilocks256,
+    ConfigZZVec12289_512, ConfigZZVec3329_256, ConfigZZVec8380417_256, ConfigZZVecGoldilocks256,
+    Vec12289_512, Vec3329_256, Vec8380417_256, VecGoldilocks256,
 };
 pub use zz_vec::ZZVec;
 
@@ -88,11 +88,17 @@ pub trait Vector<Config>:
     /// A 32 bytes digest of the Vector
     fn digest(&self) -> [u8; 32];
 
-    /// Infinity norm of the vector
-    fn infinity_norm(&self) -> u32;
+    /// Infinity norm of the vector, over the centered lifts of the coefficients
+    fn infinity_norm(&self) -> u64;
 
-    /// L2 norm of the vector
-    fn l2_norm(&self) -> u32;
+    /// L2 norm of the vector, over the centered lifts of the coefficients.
+    ///
+    /// This is an approximation; use [`Self::l2_norm_square`] for exact norm checks.
+    fn l2_norm(&self) -> f64;
+
+    /// Square of the L2 norm of the vector, over the centered lifts of the coefficients.
+    /// Saturates at `u128::MAX`, which is only reachable for 64-bit moduli.
+    fn l2_norm_square(&self) -> u128;
 
     /// Expose coefficients as a iter
     fn coefficients(&self) -> Iter<'_, Self::BaseField>;
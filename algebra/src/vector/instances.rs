@@ -1,7 +1,9 @@
 mod vec12289;
 mod vec3329;
+mod vec8380417;
 mod vecgoldilocks;
 
 pub use vec12289::{ConfigZZVec12289_512, Vec12289_512};
 pub use vec3329::{ConfigZZVec3329_256, Vec3329_256};
+pub use vec8380417::{ConfigZZVec8380417_256, Vec8380417_256};
 pub use vecgoldilocks::{ConfigZZVecGoldilocks256, VecGoldilocks256};
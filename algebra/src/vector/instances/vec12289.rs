@@ -8,7 +8,7 @@ impl ConfigZZVec for ConfigZZVec12289_512 {
     /// Config for the base field
     type BaseConfig = ConfigZZp12289;
     /// Number of coefficients in a vector
-    const MAX_DIM: usize = 256;
+    const MAX_DIM: usize = 512;
 }
 
 /// Vector with coefficient from ZZ^n mod q=12289.
@@ -19,9 +19,7 @@ fn test_vec() {
     use crate::F12289;
     let coeffs = (0..ConfigZZVec12289_512::MAX_DIM)
         .map(|x| F12289::from(x as u64))
-        .collect::<Vec<_>>()
-        .try_into()
-        .unwrap();
+        .collect::<Vec<_>>();
     let vec = Vec12289_512 { coeffs };
     println!("vec {}", vec);
 
@@ -29,3 +27,12 @@ fn test_vec() {
 
     // assert!(false)
 }
+
+#[test]
+fn test_serialization() {
+    use crate::tests::serialize::serialization_tests;
+    use crate::{PrimeField, Vector, F12289};
+    serialization_tests("Vec12289_512".to_string(), F12289::modulus(), |rng| {
+        Vec12289_512::random(rng, None)
+    });
+}
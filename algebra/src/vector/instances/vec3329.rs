@@ -19,9 +19,7 @@ fn test_vec() {
     use crate::F3329;
     let coeffs = (0..ConfigZZVec3329_256::MAX_DIM)
         .map(|x| F3329::from(x as u64))
-        .collect::<Vec<_>>()
-        .try_into()
-        .unwrap();
+        .collect::<Vec<_>>();
     let vec = Vec3329_256 { coeffs };
     println!("vec {}", vec);
 
@@ -29,3 +27,32 @@ fn test_vec() {
 
     // assert!(false)
 }
+
+#[test]
+fn test_sample_ntt() {
+    use rand::RngCore;
+
+    use crate::tests::test_rng;
+
+    let mut rng = test_rng();
+    // 168 blocks of 3 bytes, as many as the first 3 SHAKE128 blocks in FIPS 203
+    let mut bytes = [0u8; 504];
+    rng.fill_bytes(&mut bytes);
+    let vec = Vec3329_256::from_uniform_bytes(bytes).unwrap();
+    assert!(vec.coeffs.iter().all(|x| u64::from(*x) < 3329));
+
+    // the same stream always gives the same vector
+    assert_eq!(Vec3329_256::from_uniform_bytes(bytes), Some(vec));
+
+    // too short a stream
+    assert_eq!(Vec3329_256::from_uniform_bytes(bytes[..300].to_vec()), None);
+}
+
+#[test]
+fn test_serialization() {
+    use crate::tests::serialize::serialization_tests;
+    use crate::{PrimeField, Vector, F3329};
+    serialization_tests("Vec3329_256".to_string(), F3329::modulus(), |rng| {
+        Vec3329_256::random(rng, None)
+    });
+}
@@ -0,0 +1,36 @@
+use crate::{ConfigZZVec, ConfigZZp8380417, ZZVec};
+
+/// Configuration for ZZ^n mod 8380417
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub struct ConfigZZVec8380417_256;
+
+impl ConfigZZVec for ConfigZZVec8380417_256 {
+    /// Config for the base field
+    type BaseConfig = ConfigZZp8380417;
+    /// Number of coefficients in a Vector
+    const MAX_DIM: usize = 256;
+}
+
+/// Vector with coefficient from ZZ^n mod q=8380417.
+pub type Vec8380417_256 = ZZVec<ConfigZZVec8380417_256>;
+
+#[test]
+fn test_vec() {
+    use crate::F8380417;
+    let coeffs = (0..ConfigZZVec8380417_256::MAX_DIM)
+        .map(|x| F8380417::from(x as u64))
+        .collect::<Vec<_>>();
+    let vec = Vec8380417_256 { coeffs };
+    println!("vec {}", vec);
+
+    println!("vec {}", vec.clone() + vec);
+}
+
+#[test]
+fn test_serialization() {
+    use crate::tests::serialize::serialization_tests;
+    use crate::{PrimeField, Vector, F8380417};
+    serialization_tests("Vec8380417_256".to_string(), F8380417::modulus(), |rng| {
+        Vec8380417_256::random(rng, None)
+    });
+}
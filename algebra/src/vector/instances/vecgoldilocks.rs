@@ -19,9 +19,7 @@ fn test_vec() {
     use crate::Goldilocks;
     let coeffs = (0..ConfigZZVecGoldilocks256::MAX_DIM)
         .map(|x| Goldilocks::from(x as u64))
-        .collect::<Vec<_>>()
-        .try_into()
-        .unwrap();
+        .collect::<Vec<_>>();
     let vec = VecGoldilocks256 { coeffs };
     println!("vec {}", vec);
 
@@ -29,3 +27,36 @@ fn test_vec() {
 
     // assert!(false)
 }
+
+#[test]
+fn test_norms() {
+    use crate::{Field, Goldilocks, PrimeField, Vector};
+    // all coefficients are -(p-1)/2, the largest centered lift in absolute value
+    let half = (Goldilocks::modulus() - 1) / 2;
+    let coeffs = vec![Goldilocks::from(half + 1); ConfigZZVecGoldilocks256::MAX_DIM];
+    let vec = VecGoldilocks256 { coeffs };
+    assert_eq!(vec.infinity_norm(), half);
+    // 256 * half^2 overflows u128
+    assert_eq!(vec.l2_norm_square(), u128::MAX);
+    assert!((vec.l2_norm() / (16.0 * half as f64) - 1.0).abs() < 1e-9);
+
+    let mut coeffs = vec![Goldilocks::zero(); ConfigZZVecGoldilocks256::MAX_DIM];
+    coeffs[0] = Goldilocks::from(half + 1);
+    coeffs[1] = Goldilocks::from(1 << 32);
+    let vec = VecGoldilocks256 { coeffs };
+    assert_eq!(
+        vec.l2_norm_square(),
+        half as u128 * half as u128 + (1 << 64)
+    );
+}
+
+#[test]
+fn test_serialization() {
+    use crate::tests::serialize::serialization_tests;
+    use crate::{Goldilocks, PrimeField, Vector};
+    serialization_tests(
+        "VecGoldilocks256".to_string(),
+        Goldilocks::modulus(),
+        |rng| VecGoldilocks256::random(rng, None),
+    );
+}
@@ -2,6 +2,7 @@ use core::slice::Iter;
 use std::fmt::Display;
 use std::fmt::Formatter;
 use std::fmt::Result;
+use std::io::{Read, Write};
 use std::iter::{Product, Sum};
 use std::ops::{Add, AddAssign, Mul, MulAssign, Neg, Sub, SubAssign};
 
@@ -10,10 +11,17 @@ use rayon::iter::IndexedParallelIterator;
 use rayon::iter::IntoParallelRefIterator;
 use rayon::iter::IntoParallelRefMutIterator;
 use rayon::iter::ParallelIterator;
+use sha2::{Digest, Sha256};
 
+use crate::field::random_below;
+use crate::serialize::{
+    coeffs_serialized_size, deserialize_coeffs, serialize_coeffs, CanonicalDeserialize,
+    CanonicalSerialize, Compress, SerializationError, Validate,
+};
 use crate::Field;
+use crate::PrimeField;
 use crate::Vector;
-use crate::{ConfigZZVec, ZZp};
+use crate::{ConfigZZVec, UniformParser, ZZp};
 
 /// ZZ_vec
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -218,7 +226,7 @@ impl<C: ConfigZZVec> Vector<C> for ZZVec<C> {
     fn random(mut rng: impl RngCore, modulus: Option<Self::BaseField>) -> Self {
         let coeff: Vec<Self::BaseField> = match modulus {
             Some(modulus) => (0..C::MAX_DIM)
-                .map(|_| Self::BaseField::from(rng.next_u64() % modulus.0.into()))
+                .map(|_| Self::BaseField::from(random_below(&mut rng, u64::from(modulus))))
                 .collect(),
             None => (0..C::MAX_DIM)
                 .map(|_| Self::BaseField::random(&mut rng))
@@ -236,19 +244,49 @@ impl<C: ConfigZZVec> Vector<C> for ZZVec<C> {
         Self { coeffs: coeff }
     }
 
-    /// A 32 bytes digest of the Vector
+    /// A 32 bytes digest of the Vector: SHA-256 over the coefficients,
+    /// each encoded with [`ZZp::to_le_bytes`]
     fn digest(&self) -> [u8; 32] {
-        todo!()
+        let mut hasher = Sha256::new();
+        for coeff in self.coeffs.iter() {
+            hasher.update(coeff.to_le_bytes());
+        }
+        hasher.finalize().into()
     }
 
-    /// Infinity norm of the Vector
-    fn infinity_norm(&self) -> u32 {
-        todo!()
+    /// Infinity norm of the Vector, over the centered lifts of the coefficients
+    fn infinity_norm(&self) -> u64 {
+        self.coeffs
+            .iter()
+            .map(|x| x.lift().unsigned_abs())
+            .max()
+            .unwrap_or_default()
     }
 
-    /// L2 norm of the Vector
-    fn l2_norm(&self) -> u32 {
-        todo!()
+    /// L2 norm of the Vector, over the centered lifts of the coefficients.
+    ///
+    /// This is an approximation; use [`Self::l2_norm_square`] for exact norm checks.
+    fn l2_norm(&self) -> f64 {
+        self.coeffs
+            .iter()
+            .map(|x| {
+                let x = x.lift() as f64;
+                x * x
+            })
+            .sum::<f64>()
+            .sqrt()
+    }
+
+    /// Square of the L2 norm of the Vector, over the centered lifts of the coefficients.
+    /// Saturates at `u128::MAX`, which is only reachable for 64-bit moduli.
+    fn l2_norm_square(&self) -> u128 {
+        self.coeffs
+            .iter()
+            .map(|x| {
+                let x = x.lift().unsigned_abs() as u128;
+                x * x
+            })
+            .fold(0, u128::saturating_add)
     }
 
     /// Max supported dimension
@@ -276,3 +314,47 @@ impl<C: ConfigZZVec> Vector<C> for ZZVec<C> {
         Self { coeffs }
     }
 }
+
+impl<C: ConfigZZVec> ZZVec<C> {
+    /// Parse a byte stream, e.g., the output of an XOF, into a uniformly random
+    /// vector, using [`UniformParser`] for rejection sampling.
+    /// For `Vec3329_256`, this is `SampleNTT` from FIPS 203.
+    /// Returns `None` if the stream ends before all coefficients are sampled.
+    pub fn from_uniform_bytes(bytes: impl IntoIterator<Item = u8>) -> Option<Self> {
+        let coeffs: Vec<_> = UniformParser::new(bytes).take(C::MAX_DIM).collect();
+        (coeffs.len() == C::MAX_DIM).then_some(Self { coeffs })
+    }
+}
+
+// ========================
+// serialization
+// ========================
+/// A vector is encoded as exactly `MAX_DIM` coefficients, without a length prefix,
+/// so vectors of any other length are rejected.
+impl<C: ConfigZZVec> CanonicalSerialize for ZZVec<C> {
+    fn serialize_with_mode<W: Write>(
+        &self,
+        writer: W,
+        compress: Compress,
+    ) -> std::result::Result<(), SerializationError> {
+        if self.coeffs.len() != C::MAX_DIM {
+            return Err(SerializationError::InvalidData);
+        }
+        serialize_coeffs(&self.coeffs, writer, compress)
+    }
+
+    fn serialized_size(&self, compress: Compress) -> usize {
+        coeffs_serialized_size::<C::BaseConfig>(C::MAX_DIM, compress)
+    }
+}
+
+impl<C: ConfigZZVec> CanonicalDeserialize for ZZVec<C> {
+    fn deserialize_with_mode<R: Read>(
+        reader: R,
+        compress: Compress,
+        validate: Validate,
+    ) -> std::result::Result<Self, SerializationError> {
+        let coeffs = deserialize_coeffs(reader, C::MAX_DIM, compress, validate)?;
+        Ok(Self { coeffs })
+    }
+}
@@ -2,4 +2,4 @@ mod definitions;
 mod instances;
 
 pub use definitions::AlgebraicHash;
-pub use instances::ajtai::AjtaiHash;
+pub use instances::ajtai::{AjtaiHash, AjtaiParameters};
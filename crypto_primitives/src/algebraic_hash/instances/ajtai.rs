@@ -1,5 +1,7 @@
+use std::sync::OnceLock;
+
 use ark_std::rand::RngCore;
-use lark_algebra::{ConfigZZVecGoldilocks256, NTTDomain, RingGoldilock256, Vector, ZZVec};
+use lark_algebra::{expand_a, ConfigRingGoldilocks256, ModuleVector, RingGoldilock256, SEED_LEN};
 
 use crate::algebraic_hash::definitions::AlgebraicHash;
 
@@ -8,15 +10,47 @@ const M: usize = 4;
 
 pub struct AjtaiHash;
 
+/// Public parameters of the Ajtai hash.
+///
+/// Only the seed is stored; the parameter vector is expanded from it with
+/// `expand_a(seed, 0, j)` on first use.
+#[derive(Debug, Clone)]
+pub struct AjtaiParameters {
+    seed: [u8; SEED_LEN],
+    vector: OnceLock<ModuleVector<ConfigRingGoldilocks256, M>>,
+}
+
+impl AjtaiParameters {
+    /// Parameters expanded from a seed
+    pub fn from_seed(seed: [u8; SEED_LEN]) -> Self {
+        Self {
+            seed,
+            vector: OnceLock::new(),
+        }
+    }
+
+    /// Seed of the parameters
+    pub fn seed(&self) -> &[u8; SEED_LEN] {
+        &self.seed
+    }
+
+    /// Parameter vector, expanded in its NTT form
+    pub fn vector(&self) -> &ModuleVector<ConfigRingGoldilocks256, M> {
+        self.vector.get_or_init(|| {
+            ModuleVector::from_ntt(core::array::from_fn(|j| expand_a(&self.seed, 0, j as u8)))
+        })
+    }
+}
+
 impl AlgebraicHash for AjtaiHash {
     /// Configuration of the hash function
     type Configuration = ();
 
-    /// public parameters, stored in their NTT form
-    type Parameters = [ZZVec<ConfigZZVecGoldilocks256>; M];
+    /// public parameters, stored as a seed
+    type Parameters = AjtaiParameters;
 
     /// Input to the hash
-    type Preimages = [RingGoldilock256; M];
+    type Preimages = ModuleVector<ConfigRingGoldilocks256, M>;
 
     /// Output to the hash
     type Digests = RingGoldilock256;
@@ -24,26 +58,15 @@ impl AlgebraicHash for AjtaiHash {
     /// Generate the config file
     fn config() -> Self::Configuration {}
 
-    /// Setup public parameters
+    /// Setup public parameters from a random seed
     fn setup(_config: &Self::Configuration, mut rng: impl RngCore) -> Self::Parameters {
-        (0..M)
-            .map(|_| ZZVec::random(&mut rng, None))
-            .collect::<Vec<_>>()
-            .try_into()
-            .unwrap()
+        let mut seed = [0u8; SEED_LEN];
+        rng.fill_bytes(&mut seed);
+        AjtaiParameters::from_seed(seed)
     }
 
     /// hash preimages to the digests
     fn hash(inputs: &Self::Preimages, param: &Self::Parameters) -> Self::Digests {
-        let digest_vec: ZZVec<ConfigZZVecGoldilocks256> = inputs
-            .iter()
-            .zip(param.iter())
-            .map(|(input_poly, param_vec)| {
-                let input_vec: ZZVec<ConfigZZVecGoldilocks256> = NTTDomain::forward_ntt(input_poly);
-                input_vec * param_vec
-            })
-            .sum();
-
-        NTTDomain::reverse_ntt(&digest_vec)
+        inputs.inner_product(param.vector())
     }
 }
@@ -2,10 +2,26 @@ use std::time::Instant;
 
 use ark_std::test_rng;
 use ff::Field;
-use lark_algebra::{Polynomial, RingGoldilock256};
+use lark_algebra::{expand_a, ModuleVector};
 use poseidon::Poseidon;
 
-use crate::{AjtaiHash, AlgebraicHash};
+use crate::{AjtaiHash, AjtaiParameters, AlgebraicHash};
+
+#[test]
+fn test_ajtai_seeded_parameters() {
+    let mut rng = test_rng();
+    let param = AjtaiHash::setup(&(), &mut rng);
+
+    // the parameters are expanded from the seed
+    for (j, vector) in param.vector().ntt().iter().enumerate() {
+        assert_eq!(*vector, expand_a(param.seed(), 0, j as u8));
+    }
+
+    // shipping the seed is enough to recompute the hash
+    let copy = AjtaiParameters::from_seed(*param.seed());
+    let msg = ModuleVector::random(&mut rng);
+    assert_eq!(AjtaiHash::hash(&msg, &param), AjtaiHash::hash(&msg, &copy));
+}
 
 #[test]
 fn bench_goat_hash() {
@@ -17,16 +33,13 @@ fn bench_goat_hash() {
     let mut res = vec![];
 
     for _ in 0..repeat {
-        hashers.push(AjtaiHash::setup(&(), &mut rng));
-        let mut messages: Vec<[lark_algebra::ZZpX<lark_algebra::ConfigZZpXGoldilocks256>; 4]> =
-            vec![];
+        let hasher = AjtaiHash::setup(&(), &mut rng);
+        // expand the parameters outside of the measurements
+        hasher.vector().ntt();
+        hashers.push(hasher);
+        let mut messages = vec![];
         for _ in 0..repeat_per_hash {
-            messages.push([
-                RingGoldilock256::random(&mut rng, None),
-                RingGoldilock256::random(&mut rng, None),
-                RingGoldilock256::random(&mut rng, None),
-                RingGoldilock256::random(&mut rng, None),
-            ])
+            messages.push(ModuleVector::random(&mut rng))
         }
         messages_vec.push(messages)
     }